@@ -0,0 +1,199 @@
+use std::{collections::BTreeMap, fmt};
+
+use bollard::secret::{ChangeType, FilesystemChange};
+
+use super::ContainerId;
+
+/// The three kinds of change reported by the `changes` endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    Added,
+    Deleted,
+    Modified,
+}
+
+impl From<ChangeType> for ChangeKind {
+    fn from(kind: ChangeType) -> Self {
+        match kind {
+            ChangeType::_0 => Self::Modified,
+            ChangeType::_1 => Self::Added,
+            ChangeType::_2 => Self::Deleted,
+        }
+    }
+}
+
+impl ChangeKind {
+    /// The same single character used by `docker diff`
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::Added => 'A',
+            Self::Deleted => 'D',
+            Self::Modified => 'C',
+        }
+    }
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let disp = match self {
+            Self::Added => "added",
+            Self::Deleted => "deleted",
+            Self::Modified => "modified",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// A single path component, and every changed path below it
+#[derive(Debug, Default)]
+struct Node {
+    kind: Option<ChangeKind>,
+    children: BTreeMap<String, Self>,
+}
+
+impl Node {
+    fn insert(&mut self, change: FilesystemChange) {
+        let node = change
+            .path
+            .split('/')
+            .filter(|i| !i.is_empty())
+            .fold(self, |node, i| {
+                node.children.entry(i.to_owned()).or_default()
+            });
+        node.kind = Some(ChangeKind::from(change.kind));
+    }
+
+    /// Depth first, children sorted by name, each line prefixed with the branches of its ancestors
+    fn flatten(&self, indent: &str, output: &mut Vec<ChangeLine>) {
+        for (index, (name, node)) in self.children.iter().enumerate() {
+            let is_last = index + 1 == self.children.len();
+            let (branch, continuation) = if is_last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            let suffix = if node.children.is_empty() { "" } else { "/" };
+            output.push(ChangeLine {
+                kind: node.kind,
+                text: format!("{indent}{branch}{name}{suffix}"),
+            });
+            node.flatten(&format!("{indent}{continuation}"), output);
+        }
+    }
+}
+
+/// A single line of the tree, directories that only contain changes, but haven't changed themselves, don't have a kind
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeLine {
+    pub kind: Option<ChangeKind>,
+    pub text: String,
+}
+
+impl fmt::Display for ChangeLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = self.kind.map_or(' ', ChangeKind::symbol);
+        write!(f, "{symbol} {}", self.text)
+    }
+}
+
+/// The changes to a container's filesystem, relative to its image, as a tree, and the dimensions of the tree
+#[derive(Debug, Clone)]
+pub struct ChangesData {
+    pub added: usize,
+    pub deleted: usize,
+    pub height: usize,
+    pub id: String,
+    pub lines: Vec<ChangeLine>,
+    pub modified: usize,
+    pub name: String,
+    pub width: usize,
+}
+
+impl ChangesData {
+    pub fn new(changes: Vec<FilesystemChange>, name: String, id: &ContainerId) -> Self {
+        let count = |kind: ChangeKind| {
+            changes
+                .iter()
+                .filter(|i| ChangeKind::from(i.kind) == kind)
+                .count()
+        };
+        let (added, deleted, modified) = (
+            count(ChangeKind::Added),
+            count(ChangeKind::Deleted),
+            count(ChangeKind::Modified),
+        );
+
+        let mut root = Node::default();
+        for change in changes {
+            root.insert(change);
+        }
+        let mut lines = vec![];
+        root.flatten("", &mut lines);
+
+        Self {
+            added,
+            deleted,
+            height: lines.len(),
+            id: id.get_short(),
+            modified,
+            name,
+            width: lines
+                .iter()
+                .map(|i| i.to_string().chars().count())
+                .max()
+                .unwrap_or_default(),
+            lines,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::secret::{ChangeType, FilesystemChange};
+
+    use super::{ChangeKind, ChangesData};
+    use crate::app_data::ContainerId;
+
+    #[test]
+    /// Changes are displayed as a tree, sorted by name, with parent directories included even if they haven't changed
+    fn test_changes_state_tree() {
+        let change = |path: &str, kind: ChangeType| FilesystemChange {
+            path: path.to_owned(),
+            kind,
+        };
+        let result = ChangesData::new(
+            vec![
+                change("/var/log/nginx/access.log", ChangeType::_1),
+                change("/etc", ChangeType::_0),
+                change("/etc/nginx.conf", ChangeType::_0),
+                change("/etc/hosts.old", ChangeType::_2),
+                change("/tmp", ChangeType::_0),
+            ],
+            "container_1".to_owned(),
+            &ContainerId::from("1"),
+        );
+
+        assert_eq!((result.added, result.deleted, result.modified), (1, 1, 3));
+        assert_eq!(
+            result
+                .lines
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "C ├── etc/",
+                "D │   ├── hosts.old",
+                "C │   └── nginx.conf",
+                "C ├── tmp",
+                "  └── var/",
+                "      └── log/",
+                "          └── nginx/",
+                "A             └── access.log",
+            ]
+        );
+        assert_eq!(result.lines[4].kind, None);
+        assert_eq!(result.lines[7].kind, Some(ChangeKind::Added));
+        assert_eq!(result.height, 8);
+        assert_eq!(result.width, 28);
+    }
+}
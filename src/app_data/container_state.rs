@@ -1,6 +1,6 @@
 use std::{
     cmp::Ordering,
-    collections::{HashSet, VecDeque},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
     fmt,
     net::IpAddr,
 };
@@ -22,6 +22,8 @@ const ONE_KB: f64 = 1000.0;
 const ONE_MB: f64 = ONE_KB * 1000.0;
 const ONE_GB: f64 = ONE_MB * 1000.0;
 
+pub const COMPOSE_PROJECT: &str = "com.docker.compose.project";
+
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum ScrollDirection {
     // Next,
@@ -32,30 +34,47 @@ pub enum ScrollDirection {
     Right,
 }
 
+/// A container id, along with the host the container belongs to, so that the same daemon listed under two host spellings doesn't collide
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
-pub struct ContainerId(String);
+pub struct ContainerId {
+    host: ContainerHost,
+    id: String,
+}
 
 impl From<&str> for ContainerId {
     fn from(x: &str) -> Self {
-        Self(x.to_owned())
+        Self::new(&ContainerHost::default(), x)
     }
 }
 
 impl ContainerId {
+    pub fn new(host: &ContainerHost, id: &str) -> Self {
+        Self {
+            host: host.clone(),
+            id: id.to_owned(),
+        }
+    }
+
     pub const fn get(&self) -> &str {
-        self.0.as_str()
+        self.id.as_str()
+    }
+
+    pub const fn host(&self) -> &ContainerHost {
+        &self.host
     }
 
     /// Only return first 8 chars of id, is usually more than enough for uniqueness
     /// need to update tests to use real ids, or atleast strings of the correct-ish length
     pub fn get_short(&self) -> String {
-        self.0.chars().take(8).collect::<String>()
+        self.id.chars().take(8).collect::<String>()
     }
 }
 
 impl Ord for ContainerId {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.0.cmp(&other.0)
+        self.id
+            .cmp(&other.id)
+            .then_with(|| self.host.cmp(&other.host))
     }
 }
 
@@ -118,6 +137,51 @@ macro_rules! unit_struct {
 unit_struct!(ContainerName);
 unit_struct!(ContainerImage);
 
+/// The Docker host that a container belongs to, None is the default connection, i.e. `DOCKER_HOST` or the default socket
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ContainerHost(Option<String>);
+
+impl From<Option<String>> for ContainerHost {
+    fn from(value: Option<String>) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+impl From<&str> for ContainerHost {
+    fn from(value: &str) -> Self {
+        Self(Some(value.to_owned()))
+    }
+}
+
+impl ContainerHost {
+    pub fn get(&self) -> Option<&str> {
+        self.0.as_deref()
+    }
+}
+
+impl Contains for ContainerHost {
+    /// Check the full host, rather than the Display output, which is truncated
+    fn contains(&self, input: &str) -> bool {
+        self.0
+            .as_deref()
+            .unwrap_or("local")
+            .to_lowercase()
+            .contains(input)
+    }
+}
+
+impl fmt::Display for ContainerHost {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let host = self.0.as_deref().unwrap_or("local");
+        if host.chars().count() >= 30 {
+            write!(f, "{}…", host.chars().take(29).collect::<String>())
+        } else {
+            write!(f, "{host}")
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ContainerPorts {
     pub ip: Option<IpAddr>,
@@ -219,6 +283,34 @@ impl<T> StatefulList<T> {
         }
     }
 
+    /// Replace every item that matches `replace` with the given items, and then sort
+    /// The selected item, matched by `key`, stays selected, if it has been removed then the item now at the same position is selected
+    pub fn replace_items<K: PartialEq>(
+        &mut self,
+        items: Vec<T>,
+        replace: impl Fn(&T) -> bool,
+        key: impl Fn(&T) -> K,
+        compare: impl FnMut(&T, &T) -> std::cmp::Ordering,
+    ) {
+        let index = self.state.selected();
+        let selected = index.and_then(|i| self.items.get(i)).map(&key);
+
+        self.items.retain(|i| !replace(i));
+        self.items.extend(items);
+        self.items.sort_by(compare);
+
+        let len = self.items.len();
+        if len == 0 {
+            self.state.select(None);
+        } else {
+            let position =
+                selected.and_then(|selected| self.items.iter().position(|i| key(i) == selected));
+            self.state.select(Some(
+                position.unwrap_or_else(|| index.unwrap_or_default().min(len - 1)),
+            ));
+        }
+    }
+
     /// Return the current status of the select list, e.g. 2/5,
     /// MAYBE add up down arrows, check if at start or end etc
     pub fn get_state_title(&self) -> String {
@@ -427,10 +519,24 @@ pub enum DockerCommand {
     Start,
     Stop,
     Resume,
+    Kill,
     Delete,
+    Down,
 }
 
 impl DockerCommand {
+    /// Commands that can be applied to every container in a Docker Compose project
+    pub const PROJECT: [Self; 4] = [Self::Start, Self::Stop, Self::Restart, Self::Down];
+
+    /// Commands that can be applied to every container in a filter result
+    pub const BULK: [Self; 5] = [
+        Self::Stop,
+        Self::Start,
+        Self::Restart,
+        Self::Pause,
+        Self::Delete,
+    ];
+
     pub const fn get_color(self, colors: AppColors) -> Color {
         match self {
             Self::Pause => colors.commands.pause,
@@ -439,6 +545,8 @@ impl DockerCommand {
             Self::Stop => colors.commands.stop,
             Self::Delete => colors.commands.delete,
             Self::Resume => colors.commands.resume,
+            Self::Kill => colors.commands.kill,
+            Self::Down => colors.commands.delete,
         }
     }
 
@@ -446,9 +554,15 @@ impl DockerCommand {
     pub fn gen_vec(state: State) -> Vec<Self> {
         match state {
             State::Dead | State::Exited => vec![Self::Start, Self::Restart, Self::Delete],
-            State::Paused => vec![Self::Resume, Self::Stop, Self::Delete],
-            State::Restarting => vec![Self::Stop, Self::Delete],
-            State::Running(_) => vec![Self::Pause, Self::Restart, Self::Stop, Self::Delete],
+            State::Paused => vec![Self::Resume, Self::Stop, Self::Kill, Self::Delete],
+            State::Restarting => vec![Self::Stop, Self::Kill, Self::Delete],
+            State::Running(_) => vec![
+                Self::Pause,
+                Self::Restart,
+                Self::Stop,
+                Self::Kill,
+                Self::Delete,
+            ],
             _ => vec![Self::Delete],
         }
     }
@@ -463,11 +577,52 @@ impl fmt::Display for DockerCommand {
             Self::Start => "start",
             Self::Stop => "stop",
             Self::Resume => "resume",
+            Self::Kill => "kill",
+            Self::Down => "down",
         };
         write!(f, "{disp}")
     }
 }
 
+/// Signals that can be sent to a container via the kill command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Signal {
+    Term,
+    Kill,
+    Hup,
+    Usr1,
+    Usr2,
+    Int,
+}
+
+impl Signal {
+    pub const ALL: [Self; 6] = [
+        Self::Term,
+        Self::Kill,
+        Self::Hup,
+        Self::Usr1,
+        Self::Usr2,
+        Self::Int,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Term => "SIGTERM",
+            Self::Kill => "SIGKILL",
+            Self::Hup => "SIGHUP",
+            Self::Usr1 => "SIGUSR1",
+            Self::Usr2 => "SIGUSR2",
+            Self::Int => "SIGINT",
+        }
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 pub trait Stats {
     fn get_value(&self) -> f64;
 }
@@ -484,6 +639,13 @@ impl CpuStats {
     }
 }
 
+/// Folded from 0.0, as summing an empty iterator of f64 results in -0.0
+impl std::iter::Sum for CpuStats {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.fold(0.0, |acc, i| acc + i.0))
+    }
+}
+
 impl Eq for CpuStats {}
 
 impl PartialEq for CpuStats {
@@ -556,6 +718,12 @@ impl ByteStats {
     }
 }
 
+impl std::iter::Sum for ByteStats {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|i| i.0).sum())
+    }
+}
+
 #[allow(clippy::cast_precision_loss)]
 impl Stats for ByteStats {
     fn get_value(&self) -> f64 {
@@ -624,10 +792,11 @@ impl fmt::Display for BandwidthStat {
     }
 }
 
+/// A history of byte rates, used for both the network rx & tx, and the disk read & write, of a container
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct NetworkBandwidth(VecDeque<BandwidthStat>);
+pub struct Bandwidth(VecDeque<BandwidthStat>);
 
-impl NetworkBandwidth {
+impl Bandwidth {
     pub fn new() -> Self {
         Self(VecDeque::with_capacity(60))
     }
@@ -660,6 +829,15 @@ impl NetworkBandwidth {
             .map_or(ByteStats::default(), |i| ByteStats::new(i.0))
     }
 
+    /// Get the most recent speed, the difference between the last two totals
+    pub fn current(&self) -> BandwidthStat {
+        let mut iter = self.0.iter().rev();
+        match (iter.next(), iter.next()) {
+            (Some(current), Some(prev)) => BandwidthStat(current.0.saturating_sub(prev.0)),
+            _ => BandwidthStat(0),
+        }
+    }
+
     /// Convert to f64 for use in the network graph
     pub fn to_vec_f64(&self) -> Vec<(f64, f64)> {
         self.0
@@ -671,12 +849,73 @@ impl NetworkBandwidth {
     }
 }
 
+/// Network traffic for a single network interface of a container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterfaceBandwidth {
+    pub rx: Bandwidth,
+    pub tx: Bandwidth,
+}
+
+impl InterfaceBandwidth {
+    pub fn new() -> Self {
+        Self {
+            rx: Bandwidth::new(),
+            tx: Bandwidth::new(),
+        }
+    }
+}
+
+/// The current speed of a single network interface, used in the bandwidth chart breakdown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterfaceRate {
+    pub name: String,
+    pub rx: BandwidthStat,
+    pub tx: BandwidthStat,
+}
+
+/// CPU throttling caused by the containers cgroup CPU quota
+/// throttled_periods & throttled_time (ns) are totals since the container started,
+/// the recent values are the difference between the two most recent docker stats samples
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CpuThrottling {
+    pub throttled_periods: u64,
+    pub throttled_time: u64,
+    pub recent_periods: u64,
+    pub recent_throttled_periods: u64,
+}
+
+impl CpuThrottling {
+    /// Percentage of the recent CPU periods which were throttled, None if the container has no CPU quota
+    pub fn recent_percentage(&self) -> Option<u64> {
+        (self.recent_throttled_periods.min(self.recent_periods) * 100)
+            .checked_div(self.recent_periods)
+    }
+}
+
+/// Total throttled time in seconds, followed by the number of throttled periods
+#[allow(clippy::cast_precision_loss)]
+impl fmt::Display for CpuThrottling {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let p = format!(
+            "{:.2}s/{}",
+            self.throttled_time as f64 / 1_000_000_000.0,
+            self.throttled_periods
+        );
+        write!(f, "{p:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ChartsData {
     pub memory: ChartSeries<ByteStats>,
     pub cpu: ChartSeries<CpuStats>,
+    pub interfaces: Vec<InterfaceRate>,
     pub rx: ChartSeries<BandwidthStat>,
     pub tx: ChartSeries<BandwidthStat>,
+    pub disk_read: ChartSeries<BandwidthStat>,
+    pub disk_write: ChartSeries<BandwidthStat>,
+    pub pids: u64,
+    pub throttling: CpuThrottling,
     pub state: State,
 }
 
@@ -727,6 +966,7 @@ impl LogsTz {
 pub struct Logs {
     lines: StatefulList<Text<'static>>,
     tz: HashSet<LogsTz>,
+    latest: Option<Timestamp>,
     search_results: Vec<usize>,
     search_term: Option<String>,
     offset: usize,
@@ -743,6 +983,7 @@ impl Default for Logs {
         Self {
             lines,
             tz: HashSet::new(),
+            latest: None,
             offset: 0,
             max_offset: 0,
             search_term: None,
@@ -925,6 +1166,9 @@ impl Logs {
 
     /// Only allow a new log line to be inserted if the log timestamp isn't in the tz HashSet
     pub fn insert(&mut self, line: Text<'static>, tz: LogsTz, case_sensitive: bool) {
+        if let Ok(timestamp) = tz.0.parse::<Timestamp>() {
+            self.latest = Some(self.latest.map_or(timestamp, |i| i.max(timestamp)));
+        }
         if self.tz.insert(tz) {
             self.max_log_len = self.max_log_len.max(line.width());
             self.lines.items.push(line);
@@ -935,6 +1179,14 @@ impl Logs {
         }
     }
 
+    /// The unix timestamp, in seconds, of the most recent log line, to be used as the `since` value of a logs request
+    /// Only used when a log stream has to be re-opened, after a container restart or a reconnection, as a running container's stream is never closed
+    /// Docker only accepts whole seconds, so any lines from this second will be re-sent, but then ignored by the tz HashSet
+    pub fn since(&self) -> i32 {
+        self.latest
+            .map_or(0, |i| i32::try_from(i.as_second()).unwrap_or_default())
+    }
+
     /// If scrolling horizontally along the logs, display a counter of the position in the in the scroll, `x/y`
     pub fn get_scroll_title(&mut self, width: u16) -> Option<String> {
         if self.horizontal_scroll_able(width) {
@@ -1089,21 +1341,28 @@ impl Logs {
 pub struct ContainerItem {
     pub cpu_stats: VecDeque<CpuStats>,
     pub created: u64,
+    pub disk_read: Bandwidth,
+    pub disk_write: Bandwidth,
     pub docker_controls: StatefulList<DockerCommand>,
     pub health: Option<ContainerSummaryHealthStatusEnum>,
+    pub host: ContainerHost,
     pub id: ContainerId,
     pub image: ContainerImage,
+    pub interfaces: BTreeMap<String, InterfaceBandwidth>,
     pub is_oxker: bool,
-    pub last_updated: u64,
+    pub labels: HashMap<String, String>,
     pub logs: Logs,
+    pub marked: bool,
     pub mem_limit: ByteStats,
     pub mem_stats: VecDeque<ByteStats>,
     pub name: ContainerName,
+    pub pids: u64,
     pub ports: Vec<ContainerPorts>,
-    pub rx: NetworkBandwidth,
+    pub rx: Bandwidth,
     pub state: State,
     pub status: ContainerStatus,
-    pub tx: NetworkBandwidth,
+    pub throttling: CpuThrottling,
+    pub tx: Bandwidth,
 }
 
 /// Basic display information, for when running in debug mode
@@ -1139,24 +1398,36 @@ impl ContainerItem {
         Self {
             cpu_stats: VecDeque::with_capacity(60),
             created,
+            disk_read: Bandwidth::new(),
+            disk_write: Bandwidth::new(),
             docker_controls,
             health: None,
+            host: ContainerHost::default(),
             id,
             image: image.into(),
+            interfaces: BTreeMap::new(),
             is_oxker,
-            last_updated: 0,
+            labels: HashMap::new(),
             logs: Logs::default(),
+            marked: false,
             mem_limit: ByteStats::default(),
             mem_stats: VecDeque::with_capacity(60),
             name: name.into(),
+            pids: 0,
             ports,
-            rx: NetworkBandwidth::new(),
+            rx: Bandwidth::new(),
             state,
             status,
-            tx: NetworkBandwidth::new(),
+            throttling: CpuThrottling::default(),
+            tx: Bandwidth::new(),
         }
     }
 
+    /// The Docker Compose project that the container belongs to, if it was started by Compose
+    pub fn get_project(&self) -> Option<&str> {
+        self.labels.get(COMPOSE_PROJECT).map(String::as_str)
+    }
+
     /// Find the max value in the cpu stats VecDeque
     fn max_cpu_stats(&self) -> CpuStats {
         self.cpu_stats
@@ -1238,14 +1509,51 @@ impl ContainerItem {
         }
     }
 
+    /// Get all disk read chart data
+    fn get_disk_chart_read_data(&self) -> ChartSeries<BandwidthStat> {
+        let data = self.disk_read.to_vec_f64();
+        ChartSeries {
+            current: BandwidthStat(data.last().map_or(0, |i| i.1 as u64)),
+            dataset: data,
+            max: self.disk_read.max(),
+        }
+    }
+
+    /// Get all disk write chart data
+    fn get_disk_chart_write_data(&self) -> ChartSeries<BandwidthStat> {
+        let data = self.disk_write.to_vec_f64();
+        ChartSeries {
+            current: BandwidthStat(data.last().map_or(0, |i| i.1 as u64)),
+            dataset: data,
+            max: self.disk_write.max(),
+        }
+    }
+
+    /// Get the current speed of each network interface
+    fn get_interface_rates(&self) -> Vec<InterfaceRate> {
+        self.interfaces
+            .iter()
+            .map(|(name, bandwidth)| InterfaceRate {
+                name: name.clone(),
+                rx: bandwidth.rx.current(),
+                tx: bandwidth.tx.current(),
+            })
+            .collect()
+    }
+
     /// Get chart info for cpu & memory in one function
     /// So only need to call .lock() once
     pub fn get_chart_data(&self) -> ChartsData {
         ChartsData {
             memory: self.get_mem_chart_data(),
             cpu: self.get_cpu_chart_data(),
+            interfaces: self.get_interface_rates(),
             rx: self.get_bandwidth_chart_rx_data(),
             tx: self.get_bandwidth_chart_tx_data(),
+            disk_read: self.get_disk_chart_read_data(),
+            disk_write: self.get_disk_chart_write_data(),
+            pids: self.pids,
+            throttling: self.throttling,
             state: self.state,
         }
     }
@@ -1263,6 +1571,11 @@ pub struct Columns {
     pub image: (Header, u8),
     pub net_rx: (Header, u8),
     pub net_tx: (Header, u8),
+    pub disk_read: Option<(Header, u8)>,
+    pub disk_write: Option<(Header, u8)>,
+    pub host: Option<(Header, u8)>,
+    pub pids: Option<(Header, u8)>,
+    pub throttled: Option<(Header, u8)>,
 }
 
 impl Columns {
@@ -1278,6 +1591,11 @@ impl Columns {
             image: (Header::Image, 5),
             net_rx: (Header::Rx, 4),
             net_tx: (Header::Tx, 4),
+            disk_read: None,
+            disk_write: None,
+            host: None,
+            pids: None,
+            throttled: None,
         }
     }
 }
@@ -1447,6 +1765,23 @@ mod tests {
         assert_eq!(logs.lines.items.len(), 2);
     }
 
+    #[test]
+    /// Logs since is the second of the most recent log timestamp, regardless of insert order
+    fn test_container_state_logs_since() {
+        let mut logs = Logs::default();
+        assert_eq!(logs.since(), 0);
+
+        for input in [
+            "2023-01-14T19:13:30.783138328Z Lorem ipsum dolor sit amet",
+            "2023-01-14T19:13:31.000000001Z Lorem ipsum dolor sit amet",
+            "2023-01-14T19:13:29.783138328Z Lorem ipsum dolor sit amet",
+        ] {
+            let (tz, _) = LogsTz::splitter(input);
+            logs.insert(Text::from(log_sanitizer::remove_ansi(input)), tz, true);
+        }
+        assert_eq!(logs.since(), 1_673_723_611);
+    }
+
     #[test]
     /// check ContainerStatus unhealthy state
     fn test_container_state_unhealthy() {
@@ -0,0 +1,335 @@
+use std::{cmp::Ordering, fmt};
+
+use bollard::secret::{
+    BuildCache, ContainerSummary, ContainerSummaryStateEnum, ImageSummary, SystemDataUsageResponse,
+    Volume,
+};
+use ratatui::widgets::ListState;
+use serde::de::DeserializeOwned;
+
+use super::{ByteStats, ContainerHost, ImageId, SortedOrder, VolumeItem};
+
+/// The four kinds of object that the daemon reports disk usage for, in the order that `docker system df` displays them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DiskUsageKind {
+    Images,
+    Containers,
+    Volumes,
+    BuildCache,
+}
+
+impl fmt::Display for DiskUsageKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let disp = match self {
+            Self::Images => "images",
+            Self::Containers => "containers",
+            Self::Volumes => "volumes",
+            Self::BuildCache => "build cache",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// The columns that the items of a category can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiskUsageHeader {
+    Size,
+    Name,
+}
+
+/// State of the items list, of the selected category, in the disk usage view
+#[derive(Debug, Clone, Default)]
+pub struct DiskUsageItems {
+    /// When focused, the scroll keys move through the items, rather than the categories
+    pub focused: bool,
+    pub sorted_by: Option<(DiskUsageHeader, SortedOrder)>,
+    pub state: ListState,
+}
+
+/// A single image, container, volume, or build cache record, and the space it uses
+/// Prunable matches the default behaviour of the relevant Docker CLI prune command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskUsageItem {
+    /// The image id, container id, volume name, or build cache id, used to remove the item when pruning
+    pub id: String,
+    pub in_use: bool,
+    pub name: String,
+    pub prunable: bool,
+    pub size: ByteStats,
+}
+
+impl DiskUsageItem {
+    /// The daemon reports a size of -1 if it is unable to calculate it
+    fn new(id: String, name: String, size: Option<i64>, in_use: bool, prunable: bool) -> Self {
+        Self {
+            id,
+            in_use,
+            name,
+            prunable,
+            size: ByteStats::new(size.and_then(|i| u64::try_from(i).ok()).unwrap_or_default()),
+        }
+    }
+
+    /// Compare by the given header, without a header items are ordered largest first, and then by name
+    pub fn compare(
+        &self,
+        other: &Self,
+        sorted_by: Option<(DiskUsageHeader, SortedOrder)>,
+    ) -> Ordering {
+        let largest_first = || {
+            other
+                .size
+                .cmp(&self.size)
+                .then_with(|| self.name.cmp(&other.name))
+        };
+        let Some((header, order)) = sorted_by else {
+            return largest_first();
+        };
+        let (a, b) = match order {
+            SortedOrder::Asc => (self, other),
+            SortedOrder::Desc => (other, self),
+        };
+        match header {
+            DiskUsageHeader::Size => a.size.cmp(&b.size),
+            DiskUsageHeader::Name => a.name.cmp(&b.name),
+        }
+        .then_with(largest_first)
+    }
+
+    /// Only dangling images, that aren't used by any container, are pruned
+    fn from_image(image: ImageSummary) -> Self {
+        let tag = image
+            .repo_tags
+            .iter()
+            .find(|i| *i != "<none>:<none>")
+            .cloned();
+        let in_use = image.containers > 0;
+        let prunable = tag.is_none() && !in_use;
+        let name = tag.unwrap_or_else(|| ImageId::from(image.id.as_str()).get_short());
+        Self::new(image.id, name, Some(image.size), in_use, prunable)
+    }
+
+    /// Only the writable layer of a container is counted, as the image layers are already counted in the images
+    /// Every container that isn't running, paused, or restarting, is pruned
+    fn from_container(container: ContainerSummary) -> Self {
+        let name = container
+            .names
+            .as_ref()
+            .and_then(|i| i.first())
+            .map_or_else(String::new, |i| i.trim_start_matches('/').to_owned());
+        let in_use = container.state == Some(ContainerSummaryStateEnum::RUNNING);
+        let prunable = matches!(
+            container.state,
+            Some(
+                ContainerSummaryStateEnum::CREATED
+                    | ContainerSummaryStateEnum::EXITED
+                    | ContainerSummaryStateEnum::DEAD
+            )
+        );
+        Self::new(
+            container.id.unwrap_or_default(),
+            name,
+            container.size_rw,
+            in_use,
+            prunable,
+        )
+    }
+
+    /// Only anonymous volumes, that aren't referenced by any container, are pruned
+    fn from_volume(volume: Volume, host: &ContainerHost) -> Self {
+        let (size, in_use) = volume
+            .usage_data
+            .as_ref()
+            .map_or((None, false), |i| (Some(i.size), i.ref_count > 0));
+        let anonymous = VolumeItem::new(volume.clone(), vec![], None, host).anonymous;
+        Self::new(
+            volume.name.clone(),
+            volume.name,
+            size,
+            in_use,
+            anonymous && !in_use,
+        )
+    }
+
+    /// Every build cache record that isn't in use is pruned
+    fn from_build_cache(cache: BuildCache) -> Self {
+        let id = cache.id.unwrap_or_default();
+        let name = cache
+            .description
+            .filter(|i| !i.is_empty())
+            .unwrap_or_else(|| id.clone());
+        let in_use = cache.in_use.unwrap_or_default();
+        Self::new(id, name, cache.size, in_use, !in_use)
+    }
+}
+
+/// The totals for a single kind of object, on a single host, alongside every item of that kind, largest first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskUsageCategory {
+    pub active: u64,
+    pub host: ContainerHost,
+    pub items: Vec<DiskUsageItem>,
+    pub kind: DiskUsageKind,
+    pub reclaimable: ByteStats,
+    /// The reclaimable space as a percentage of the total size, as displayed by `docker system df`
+    pub reclaimable_percent: u64,
+    pub size: ByteStats,
+    pub total: u64,
+}
+
+impl DiskUsageCategory {
+    fn new(
+        kind: DiskUsageKind,
+        host: &ContainerHost,
+        totals: [Option<i64>; 4],
+        mut items: Vec<DiskUsageItem>,
+    ) -> Self {
+        let [active, total, size, reclaimable] =
+            totals.map(|i| i.and_then(|i| u64::try_from(i).ok()).unwrap_or_default());
+        items.sort_by(|a, b| a.compare(b, None));
+        Self {
+            active,
+            host: host.clone(),
+            items,
+            kind,
+            reclaimable: ByteStats::new(reclaimable),
+            reclaimable_percent: reclaimable
+                .saturating_mul(100)
+                .checked_div(size)
+                .unwrap_or_default(),
+            size: ByteStats::new(size),
+            total,
+        }
+    }
+
+    /// Split a df response into a category for each kind of object
+    pub fn from_response(response: SystemDataUsageResponse, host: &ContainerHost) -> Vec<Self> {
+        /// Items are untyped json values, anything that fails to parse is skipped
+        fn parse<T: DeserializeOwned>(
+            items: Option<Vec<serde_json::Value>>,
+            f: impl Fn(T) -> DiskUsageItem,
+        ) -> Vec<DiskUsageItem> {
+            items
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|i| serde_json::from_value::<T>(i).ok())
+                .map(f)
+                .collect()
+        }
+
+        let mut output = vec![];
+        if let Some(i) = response.images_disk_usage {
+            output.push(Self::new(
+                DiskUsageKind::Images,
+                host,
+                [i.active_count, i.total_count, i.total_size, i.reclaimable],
+                parse(i.items, DiskUsageItem::from_image),
+            ));
+        }
+        if let Some(i) = response.containers_disk_usage {
+            output.push(Self::new(
+                DiskUsageKind::Containers,
+                host,
+                [i.active_count, i.total_count, i.total_size, i.reclaimable],
+                parse(i.items, DiskUsageItem::from_container),
+            ));
+        }
+        if let Some(i) = response.volumes_disk_usage {
+            output.push(Self::new(
+                DiskUsageKind::Volumes,
+                host,
+                [i.active_count, i.total_count, i.total_size, i.reclaimable],
+                parse(i.items, |i| DiskUsageItem::from_volume(i, host)),
+            ));
+        }
+        if let Some(i) = response.build_cache_disk_usage {
+            output.push(Self::new(
+                DiskUsageKind::BuildCache,
+                host,
+                [i.active_count, i.total_count, i.total_size, i.reclaimable],
+                parse(i.items, DiskUsageItem::from_build_cache),
+            ));
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::secret::{
+        ContainerSummary, ContainerSummaryStateEnum, ContainersDiskUsage, ImageSummary,
+        ImagesDiskUsage, SystemDataUsageResponse,
+    };
+
+    use super::{DiskUsageCategory, DiskUsageKind};
+    use crate::app_data::{ByteStats, ContainerHost};
+
+    #[test]
+    /// Each kind in the response is a category, with its items sorted by size, largest first
+    fn test_disk_usage_state_from_response() {
+        let image = |id: &str, tags: Vec<&str>, size: i64, containers: i64| {
+            serde_json::to_value(ImageSummary {
+                id: id.to_owned(),
+                repo_tags: tags.into_iter().map(ToOwned::to_owned).collect(),
+                size,
+                containers,
+                ..Default::default()
+            })
+            .unwrap_or_default()
+        };
+        let response = SystemDataUsageResponse {
+            images_disk_usage: Some(ImagesDiskUsage {
+                active_count: Some(1),
+                total_count: Some(2),
+                total_size: Some(400),
+                reclaimable: Some(100),
+                items: Some(vec![
+                    image("sha256:aaaaaaaa11111111", vec!["<none>:<none>"], 100, 0),
+                    image("sha256:bbbbbbbb22222222", vec!["nginx:latest"], 300, 1),
+                    serde_json::Value::Null,
+                ]),
+            }),
+            containers_disk_usage: Some(ContainersDiskUsage {
+                active_count: Some(1),
+                total_count: Some(1),
+                total_size: Some(-1),
+                reclaimable: None,
+                items: Some(vec![
+                    serde_json::to_value(ContainerSummary {
+                        names: Some(vec!["/nginx".to_owned()]),
+                        size_rw: Some(10),
+                        state: Some(ContainerSummaryStateEnum::RUNNING),
+                        ..Default::default()
+                    })
+                    .unwrap_or_default(),
+                ]),
+            }),
+            ..Default::default()
+        };
+
+        let result = DiskUsageCategory::from_response(response, &ContainerHost::default());
+        assert_eq!(result.len(), 2);
+
+        let images = &result[0];
+        assert_eq!(images.kind, DiskUsageKind::Images);
+        assert_eq!((images.active, images.total), (1, 2));
+        assert_eq!(images.size, ByteStats::new(400));
+        assert_eq!(images.reclaimable_percent, 25);
+        assert_eq!(images.items.len(), 2);
+        assert_eq!(images.items[0].name, "nginx:latest");
+        assert!(images.items[0].in_use);
+        assert!(!images.items[0].prunable);
+        assert_eq!(images.items[1].name, "aaaaaaaa");
+        assert!(!images.items[1].in_use);
+        assert!(images.items[1].prunable);
+
+        let containers = &result[1];
+        assert_eq!(containers.kind, DiskUsageKind::Containers);
+        assert_eq!(containers.size, ByteStats::new(0));
+        assert_eq!(containers.reclaimable_percent, 0);
+        assert_eq!(containers.items[0].name, "nginx");
+        assert_eq!(containers.items[0].size, ByteStats::new(10));
+        assert!(containers.items[0].in_use);
+        assert!(!containers.items[0].prunable);
+    }
+}
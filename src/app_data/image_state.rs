@@ -0,0 +1,149 @@
+use bollard::secret::ImageSummary;
+use jiff::{Timestamp, tz::TimeZone};
+
+use super::{ByteStats, ContainerHost};
+
+/// Format used to display when an image was created
+const CREATED_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Docker displays an image without any tags as `<none>:<none>`
+const DANGLING: &str = "<none>:<none>";
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct ImageId(String);
+
+impl From<&str> for ImageId {
+    fn from(x: &str) -> Self {
+        Self(x.to_owned())
+    }
+}
+
+impl ImageId {
+    pub const fn get(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Only return first 8 chars of id, ignoring the `sha256:` digest prefix
+    pub fn get_short(&self) -> String {
+        self.0
+            .trim_start_matches("sha256:")
+            .chars()
+            .take(8)
+            .collect::<String>()
+    }
+}
+
+/// Info for each image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageItem {
+    pub containers: usize,
+    pub created: i64,
+    pub host: ContainerHost,
+    pub id: ImageId,
+    pub repo_tags: Vec<String>,
+    pub size: ByteStats,
+}
+
+impl ImageItem {
+    /// Create a new image item, containers is the number of containers, of the same host, that use the image
+    pub fn new(summary: ImageSummary, containers: usize, host: &ContainerHost) -> Self {
+        Self {
+            containers,
+            created: summary.created,
+            host: host.clone(),
+            id: ImageId::from(summary.id.as_str()),
+            repo_tags: summary
+                .repo_tags
+                .into_iter()
+                .filter(|i| i != DANGLING)
+                .collect(),
+            size: ByteStats::new(u64::try_from(summary.size).unwrap_or_default()),
+        }
+    }
+
+    /// The first repo:tag of the image, an untagged image is displayed as Docker does, `<none>:<none>`
+    pub fn get_name(&self) -> &str {
+        self.repo_tags.first().map_or(DANGLING, String::as_str)
+    }
+
+    /// The reference used to remove the image, the repo:tag that is displayed, so that an image with several tags only loses that tag
+    /// An untagged image is referenced by its id
+    pub fn get_reference(&self) -> &str {
+        self.repo_tags.first().map_or(self.id.get(), String::as_str)
+    }
+
+    /// Display the created time, and if provided, with a timezone offset
+    pub fn get_created(&self, tz: Option<&TimeZone>) -> String {
+        Timestamp::from_second(self.created).map_or_else(
+            |_| String::new(),
+            |t| {
+                tz.map_or_else(
+                    || t.strftime(CREATED_FORMAT).to_string(),
+                    |tz| t.to_zoned(tz.clone()).strftime(CREATED_FORMAT).to_string(),
+                )
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::secret::ImageSummary;
+    use jiff::tz::TimeZone;
+
+    use super::{ImageId, ImageItem};
+    use crate::app_data::ContainerHost;
+
+    fn gen_summary(repo_tags: Vec<String>) -> ImageSummary {
+        ImageSummary {
+            id: "sha256:0123456789abcdef".to_owned(),
+            repo_tags,
+            created: 1_700_000_000,
+            size: 1_000_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// The short id ignores the digest prefix
+    fn test_image_state_id_short() {
+        assert_eq!(
+            ImageId::from("sha256:0123456789abcdef").get_short(),
+            "01234567"
+        );
+        assert_eq!(ImageId::from("0123456789abcdef").get_short(), "01234567");
+    }
+
+    #[test]
+    /// The name is the first repo:tag, and an untagged image is `<none>:<none>`
+    fn test_image_state_name() {
+        let host = ContainerHost::default();
+        let image = ImageItem::new(
+            gen_summary(vec!["nginx:latest".to_owned(), "nginx:1".to_owned()]),
+            2,
+            &host,
+        );
+        assert_eq!(image.get_name(), "nginx:latest");
+        assert_eq!(image.get_reference(), "nginx:latest");
+        assert_eq!(image.containers, 2);
+        assert_eq!(image.size.to_string(), "1.00 MB");
+
+        let image = ImageItem::new(gen_summary(vec!["<none>:<none>".to_owned()]), 0, &host);
+        assert!(image.repo_tags.is_empty());
+        assert_eq!(image.get_name(), "<none>:<none>");
+
+        let image = ImageItem::new(gen_summary(vec![]), 0, &host);
+        assert_eq!(image.get_name(), "<none>:<none>");
+        assert_eq!(image.get_reference(), "sha256:0123456789abcdef");
+    }
+
+    #[test]
+    /// The created time is displayed in UTC, or with a given timezone
+    fn test_image_state_created() {
+        let image = ImageItem::new(gen_summary(vec![]), 0, &ContainerHost::default());
+        assert_eq!(image.get_created(None), "2023-11-14 22:13:20");
+        let tz = TimeZone::get("Asia/Tokyo").unwrap();
+        assert_eq!(image.get_created(Some(&tz)), "2023-11-15 07:13:20");
+    }
+}
@@ -1,14 +1,24 @@
-use bollard::{models::ContainerSummary, secret::ContainerInspectResponse};
+use bollard::{
+    models::ContainerSummary,
+    secret::{ContainerInspectResponse, ImageInspect},
+};
 use core::fmt;
 use parking_lot::Mutex;
 use ratatui::{layout::Size, text::Text, widgets::ListState};
 use std::{
+    collections::{BTreeMap, HashSet},
     hash::Hash,
     sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
 };
 
+mod changes_state;
 mod container_state;
+mod disk_usage_state;
+mod image_state;
+mod network_state;
+mod process_state;
+mod prune_state;
+mod volume_state;
 
 use crate::{
     ENTRY_POINT,
@@ -16,7 +26,14 @@ use crate::{
     config::Config,
     ui::{GuiState, Rerender, Status, log_sanitizer},
 };
+pub use changes_state::*;
 pub use container_state::*;
+pub use disk_usage_state::*;
+pub use image_state::*;
+pub use network_state::*;
+pub use process_state::*;
+pub use prune_state::*;
+pub use volume_state::*;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum SortedOrder {
@@ -35,6 +52,11 @@ pub enum Header {
     Image,
     Rx,
     Tx,
+    DiskRead,
+    DiskWrite,
+    Host,
+    Pids,
+    Throttled,
 }
 
 /// Convert Header enum into strings to display
@@ -50,6 +72,11 @@ impl fmt::Display for Header {
             Self::Image => "image",
             Self::Rx => "↓ rx",
             Self::Tx => "↑ tx",
+            Self::DiskRead => "disk read",
+            Self::DiskWrite => "disk write",
+            Self::Host => "host",
+            Self::Pids => "pids",
+            Self::Throttled => "throttled",
         };
         write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
     }
@@ -61,6 +88,7 @@ pub enum FilterBy {
     Name,
     Image,
     Status,
+    Host,
     All,
 }
 
@@ -74,6 +102,7 @@ impl fmt::Display for FilterBy {
                 Self::Name => "Name",
                 Self::Image => "Image",
                 Self::Status => "Status",
+                Self::Host => "Host",
                 Self::All => "All",
             }
         )
@@ -85,7 +114,8 @@ impl FilterBy {
         match self {
             Self::Name => Some(Self::Image),
             Self::Image => Some(Self::Status),
-            Self::Status => Some(Self::All),
+            Self::Status => Some(Self::Host),
+            Self::Host => Some(Self::All),
             Self::All => None,
         }
     }
@@ -95,7 +125,8 @@ impl FilterBy {
             Self::Name => None,
             Self::Image => Some(Self::Name),
             Self::Status => Some(Self::Image),
-            Self::All => Some(Self::Status),
+            Self::Host => Some(Self::Status),
+            Self::All => Some(Self::Host),
         }
     }
 }
@@ -114,18 +145,51 @@ impl Filter {
     }
 }
 
+/// A row of the containers panel when grouped by Docker Compose project
+/// Either a project header, or the index of a container in the containers list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectRow {
+    Project(String),
+    Container(usize),
+}
+
+/// State of the Docker Compose project grouping of the containers panel
+#[derive(Debug, Clone)]
+pub struct Projects {
+    pub collapsed: HashSet<String>,
+    pub controls: StatefulList<DockerCommand>,
+    pub grouped: bool,
+    pub selected: Option<String>,
+    pub state: ListState,
+}
+
+impl Projects {
+    pub fn new() -> Self {
+        let mut controls = StatefulList::new(DockerCommand::PROJECT.to_vec());
+        controls.start();
+        Self {
+            collapsed: HashSet::new(),
+            controls,
+            grouped: false,
+            selected: None,
+            state: ListState::default(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct InspectData {
     pub width: usize,
     pub height: usize,
     pub as_string: String,
     pub name: String,
-    pub id: ContainerId, // pub as_lines: Vec<Line<'a>>,
+    pub id: String,
 }
 
-impl From<ContainerInspectResponse> for InspectData {
-    fn from(input: ContainerInspectResponse) -> Self {
-        let as_string = serde_json::to_string_pretty(&input)
+impl InspectData {
+    /// Pretty print the inspect response, without the outer braces, and calculate the dimensions of the output
+    fn new(value: &impl serde::Serialize, name: String, id: String) -> Self {
+        let as_string = serde_json::to_string_pretty(value)
             .unwrap_or_default()
             .lines()
             .skip(1)
@@ -143,9 +207,8 @@ impl From<ContainerInspectResponse> for InspectData {
         }
 
         Self {
-            name: input.name.unwrap_or_default(),
-            // TODO maybe make this an Option<Id>?
-            id: ContainerId::from(input.id.unwrap_or_default().as_str()),
+            name,
+            id,
             width,
             height,
             as_string,
@@ -153,72 +216,542 @@ impl From<ContainerInspectResponse> for InspectData {
     }
 }
 
+impl From<ContainerInspectResponse> for InspectData {
+    fn from(input: ContainerInspectResponse) -> Self {
+        let name = input.name.clone().unwrap_or_default();
+        let id = ContainerId::from(input.id.as_deref().unwrap_or_default()).get_short();
+        Self::new(&input, name, id)
+    }
+}
+
+impl From<ImageInspect> for InspectData {
+    fn from(input: ImageInspect) -> Self {
+        let name = input
+            .repo_tags
+            .as_ref()
+            .and_then(|i| i.first().cloned())
+            .unwrap_or_default();
+        let id = ImageId::from(input.id.as_deref().unwrap_or_default()).get_short();
+        Self::new(&input, name, id)
+    }
+}
+
 /// Global app_state, stored in an Arc<Mutex>
 #[derive(Debug, Clone)]
 #[cfg(not(test))]
 pub struct AppData {
+    changes_data: Option<ChangesData>,
     containers: StatefulList<ContainerItem>,
+    disconnected: HashSet<ContainerHost>,
+    disk_usage: StatefulList<DiskUsageCategory>,
+    disk_usage_items: DiskUsageItems,
     error: Option<AppError>,
     filter: Filter,
     hidden_containers: Vec<ContainerItem>,
+    images: StatefulList<ImageItem>,
     inspect_data: Option<InspectData>,
+    networks: StatefulList<NetworkItem>,
+    processes: StatefulList<ProcessItem>,
+    processes_container: Option<ContainerId>,
+    processes_sorted_by: Option<(ProcessHeader, SortedOrder)>,
+    projects: Projects,
     rerender: Arc<Rerender>,
     sorted_by: Option<(Header, SortedOrder)>,
     current_sorted_id: Vec<ContainerId>,
+    volumes: StatefulList<VolumeItem>,
     pub config: Config,
 }
 
 #[derive(Debug, Clone)]
 #[cfg(test)]
 pub struct AppData {
+    pub changes_data: Option<ChangesData>,
     pub config: Config,
     pub containers: StatefulList<ContainerItem>,
+    pub disconnected: HashSet<ContainerHost>,
+    pub disk_usage: StatefulList<DiskUsageCategory>,
+    pub disk_usage_items: DiskUsageItems,
     pub error: Option<AppError>,
     pub filter: Filter,
     pub hidden_containers: Vec<ContainerItem>,
+    pub images: StatefulList<ImageItem>,
     pub inspect_data: Option<InspectData>,
+    pub networks: StatefulList<NetworkItem>,
+    pub processes: StatefulList<ProcessItem>,
+    pub processes_container: Option<ContainerId>,
+    pub processes_sorted_by: Option<(ProcessHeader, SortedOrder)>,
+    pub projects: Projects,
     pub current_sorted_id: Vec<ContainerId>,
     pub rerender: Arc<Rerender>,
     pub sorted_by: Option<(Header, SortedOrder)>,
+    pub volumes: StatefulList<VolumeItem>,
 }
 
 impl AppData {
     /// Generate a default app_state
     pub fn new(config: Config, redraw: &Arc<Rerender>) -> Self {
         Self {
+            changes_data: None,
             config,
             containers: StatefulList::new(vec![]),
             current_sorted_id: vec![],
+            disconnected: HashSet::new(),
+            disk_usage: StatefulList::new(vec![]),
+            disk_usage_items: DiskUsageItems::default(),
             error: None,
             filter: Filter::new(),
             hidden_containers: vec![],
+            images: StatefulList::new(vec![]),
             inspect_data: None,
+            networks: StatefulList::new(vec![]),
+            processes: StatefulList::new(vec![]),
+            processes_container: None,
+            processes_sorted_by: None,
+            projects: Projects::new(),
             rerender: Arc::clone(redraw),
             sorted_by: None,
+            volumes: StatefulList::new(vec![]),
         }
     }
 
-    /// Current time as unix timestamp
-    #[allow(clippy::expect_used)]
-    fn get_systemtime() -> u64 {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("In our known reality, this error should never occur")
-            .as_secs()
-    }
-
     pub fn clear_inspect_data(&mut self) {
         self.inspect_data = None;
     }
 
-    pub fn set_inspect_data(&mut self, data: ContainerInspectResponse) {
-        self.inspect_data = Some(InspectData::from(data))
-        // self.inspect_data = Some(data)
+    pub fn set_inspect_data(&mut self, data: InspectData) {
+        self.inspect_data = Some(data);
     }
 
     pub fn get_inspect_data(&self) -> Option<InspectData> {
         self.inspect_data.clone()
     }
+
+    pub fn clear_changes_data(&mut self) {
+        self.changes_data = None;
+    }
+
+    pub fn set_changes_data(&mut self, data: ChangesData) {
+        self.changes_data = Some(data);
+    }
+
+    pub fn get_changes_data(&self) -> Option<ChangesData> {
+        self.changes_data.clone()
+    }
+
+    /// Image related methods
+    /// Replace every image of a given host, images are sorted by created time, newest first, as the Docker CLI does
+    /// The selected image stays selected, if it has been removed then the image now at the same position is selected
+    pub fn update_images(&mut self, host: &ContainerHost, images: Vec<ImageItem>) {
+        self.images.replace_items(
+            images,
+            |i| &i.host == host,
+            |i| (i.host.clone(), i.id.clone()),
+            |a, b| b.created.cmp(&a.created).then_with(|| a.id.cmp(&b.id)),
+        );
+        self.rerender.update_draw();
+    }
+
+    /// Get the currently selected image
+    pub fn get_selected_image(&self) -> Option<&ImageItem> {
+        self.images
+            .state
+            .selected()
+            .and_then(|i| self.images.items.get(i))
+    }
+
+    /// Get all the ImageItems
+    pub fn get_image_items(&self) -> &[ImageItem] {
+        &self.images.items
+    }
+
+    pub const fn get_image_state(&mut self) -> &mut ListState {
+        &mut self.images.state
+    }
+
+    /// Get title for the images view, the selected image and the total number of images, e.g. 2/5
+    pub fn get_image_title(&self) -> String {
+        self.images.get_state_title()
+    }
+
+    pub fn images_scroll(&mut self, scroll: &ScrollDirection) {
+        self.images.scroll(scroll);
+        self.rerender.update_draw();
+    }
+
+    pub fn images_start(&mut self) {
+        if !self.images.items.is_empty() {
+            self.images.start();
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn images_end(&mut self) {
+        self.images.end();
+        self.rerender.update_draw();
+    }
+
+    /// Disk usage related methods
+    /// Replace every disk usage category of a given host, categories are ordered by host, and then in the order used by `docker system df`
+    pub fn update_disk_usage(&mut self, host: &ContainerHost, categories: Vec<DiskUsageCategory>) {
+        self.disk_usage.replace_items(
+            categories,
+            |i| &i.host == host,
+            |i| (i.host.clone(), i.kind),
+            |a, b| a.host.cmp(&b.host).then_with(|| a.kind.cmp(&b.kind)),
+        );
+        self.rerender.update_draw();
+    }
+
+    /// Get the currently selected disk usage category
+    pub fn get_selected_disk_usage(&self) -> Option<&DiskUsageCategory> {
+        self.disk_usage
+            .state
+            .selected()
+            .and_then(|i| self.disk_usage.items.get(i))
+    }
+
+    /// Get all the DiskUsageCategories
+    pub fn get_disk_usage_items(&self) -> &[DiskUsageCategory] {
+        &self.disk_usage.items
+    }
+
+    pub const fn get_disk_usage_state(&mut self) -> &mut ListState {
+        &mut self.disk_usage.state
+    }
+
+    /// Get title for the disk usage view, the selected category and the total number of categories, e.g. 2/4
+    pub fn get_disk_usage_title(&self) -> String {
+        self.disk_usage.get_state_title()
+    }
+
+    /// Scroll the items of the selected category if they are focused, else scroll the categories, which resets the items list
+    pub fn disk_usage_scroll(&mut self, scroll: &ScrollDirection) {
+        if self.disk_usage_items.focused {
+            let last = self.get_disk_usage_item_count().saturating_sub(1);
+            let current = self.disk_usage_items.state.selected().unwrap_or_default();
+            let next = match scroll {
+                ScrollDirection::Down => (current + 1).min(last),
+                ScrollDirection::Up => current.saturating_sub(1),
+                _ => return,
+            };
+            self.disk_usage_items.state.select(Some(next));
+        } else {
+            self.disk_usage.scroll(scroll);
+            self.reset_disk_usage_items();
+        }
+        self.rerender.update_draw();
+    }
+
+    pub fn disk_usage_start(&mut self) {
+        if self.disk_usage_items.focused {
+            self.disk_usage_items.state.select_first();
+        } else if !self.disk_usage.items.is_empty() {
+            self.disk_usage.start();
+            self.reset_disk_usage_items();
+        }
+        self.rerender.update_draw();
+    }
+
+    pub fn disk_usage_end(&mut self) {
+        if self.disk_usage_items.focused {
+            let last = self.get_disk_usage_item_count().saturating_sub(1);
+            self.disk_usage_items.state.select(Some(last));
+        } else {
+            self.disk_usage.end();
+            self.reset_disk_usage_items();
+        }
+        self.rerender.update_draw();
+    }
+
+    /// The number of items in the selected category
+    fn get_disk_usage_item_count(&self) -> usize {
+        self.get_selected_disk_usage().map_or(0, |i| i.items.len())
+    }
+
+    /// Scroll the items list back to the top, only selecting the first item if the list is focused
+    fn reset_disk_usage_items(&mut self) {
+        let selected = self.disk_usage_items.focused.then_some(0);
+        self.disk_usage_items.state = ListState::default().with_selected(selected);
+    }
+
+    /// Switch the scroll keys between the categories, and the items of the selected category
+    pub fn toggle_disk_usage_focus(&mut self) {
+        self.disk_usage_items.focused = !self.disk_usage_items.focused;
+        self.reset_disk_usage_items();
+        self.rerender.update_draw();
+    }
+
+    pub const fn is_disk_usage_items_focused(&self) -> bool {
+        self.disk_usage_items.focused
+    }
+
+    /// Get the items of the selected category, in the current sort order
+    pub fn get_disk_usage_sorted_items(&self) -> Vec<DiskUsageItem> {
+        let sorted_by = self.disk_usage_items.sorted_by;
+        let mut items = self
+            .get_selected_disk_usage()
+            .map(|i| i.items.clone())
+            .unwrap_or_default();
+        items.sort_by(|a, b| a.compare(b, sorted_by));
+        items
+    }
+
+    pub const fn get_disk_usage_items_state(&mut self) -> &mut ListState {
+        &mut self.disk_usage_items.state
+    }
+
+    /// Sort the disk usage items based on a given header, if headings match, and already descending, remove sorting
+    pub fn set_disk_usage_sort_by_header(&mut self, selected_header: DiskUsageHeader) {
+        let mut output = Some((selected_header, SortedOrder::Asc));
+        if let Some((current_header, order)) = self.disk_usage_items.sorted_by
+            && current_header == selected_header
+        {
+            match order {
+                SortedOrder::Desc => output = None,
+                SortedOrder::Asc => output = Some((selected_header, SortedOrder::Desc)),
+            }
+        }
+        self.set_disk_usage_sorted(output);
+    }
+
+    /// Set, or remove, the disk usage items sort
+    pub fn set_disk_usage_sorted(&mut self, sorted_by: Option<(DiskUsageHeader, SortedOrder)>) {
+        self.disk_usage_items.sorted_by = sorted_by;
+        self.reset_disk_usage_items();
+        self.rerender.update_draw();
+    }
+
+    pub const fn get_disk_usage_sorted(&self) -> Option<(DiskUsageHeader, SortedOrder)> {
+        self.disk_usage_items.sorted_by
+    }
+
+    /// Network related methods
+    /// Replace every network of a given host, networks are sorted by name
+    pub fn update_networks(&mut self, host: &ContainerHost, networks: Vec<NetworkItem>) {
+        self.networks.replace_items(
+            networks,
+            |i| &i.host == host,
+            |i| (i.host.clone(), i.id.clone()),
+            |a, b| a.name.cmp(&b.name).then_with(|| a.host.cmp(&b.host)),
+        );
+        self.rerender.update_draw();
+    }
+
+    /// Get the currently selected network
+    pub fn get_selected_network(&self) -> Option<&NetworkItem> {
+        self.networks
+            .state
+            .selected()
+            .and_then(|i| self.networks.items.get(i))
+    }
+
+    /// Get the ids of every container of a given host, in display order, a containerised oxker is ignored
+    pub fn get_host_container_ids(&self, host: &ContainerHost) -> Vec<ContainerId> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| &i.host == host && !(i.is_oxker && self.config.in_container))
+            .map(|i| i.id.clone())
+            .collect()
+    }
+
+    /// Get the name of each given container, and if it is attached to the selected network, used by the network container picker
+    pub fn get_network_picker_items(&mut self, ids: &[ContainerId]) -> Vec<(String, bool)> {
+        let network = self.get_selected_network().cloned();
+        ids.iter()
+            .map(|id| {
+                let name = self
+                    .get_container_name_by_id(id)
+                    .map_or_else(|| id.get_short(), |i| i.get().to_owned());
+                (name, network.as_ref().is_some_and(|i| i.contains(id)))
+            })
+            .collect()
+    }
+
+    /// Get all the NetworkItems
+    pub fn get_network_items(&self) -> &[NetworkItem] {
+        &self.networks.items
+    }
+
+    pub const fn get_network_state(&mut self) -> &mut ListState {
+        &mut self.networks.state
+    }
+
+    /// Get title for the networks view, the selected network and the total number of networks, e.g. 2/5
+    pub fn get_network_title(&self) -> String {
+        self.networks.get_state_title()
+    }
+
+    pub fn networks_scroll(&mut self, scroll: &ScrollDirection) {
+        self.networks.scroll(scroll);
+        self.rerender.update_draw();
+    }
+
+    pub fn networks_start(&mut self) {
+        if !self.networks.items.is_empty() {
+            self.networks.start();
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn networks_end(&mut self) {
+        self.networks.end();
+        self.rerender.update_draw();
+    }
+
+    /// Process related methods
+    /// Set the container whose processes are shown in the processes view, any previous processes are cleared
+    pub fn set_processes_container(&mut self, id: Option<ContainerId>) {
+        self.processes_container = id;
+        self.processes = StatefulList::new(vec![]);
+        self.rerender.update_draw();
+    }
+
+    pub const fn get_processes_container(&self) -> Option<&ContainerId> {
+        self.processes_container.as_ref()
+    }
+
+    /// Replace the processes, if they belong to the container currently shown in the processes view
+    /// The selected process, matched by PID, stays selected
+    pub fn update_processes(&mut self, id: &ContainerId, processes: Vec<ProcessItem>) {
+        if self.processes_container.as_ref() != Some(id) {
+            return;
+        }
+        let sorted_by = self.processes_sorted_by;
+        self.processes.replace_items(
+            processes,
+            |_| true,
+            |i| i.pid,
+            |a, b| a.compare(b, sorted_by),
+        );
+        self.rerender.update_draw();
+    }
+
+    /// Sort processes based on a given header, if headings match, an ascending sort becomes descending, and a descending sort is removed
+    pub fn set_process_sort_by_header(&mut self, selected_header: ProcessHeader) {
+        let mut output = Some((selected_header, SortedOrder::Asc));
+        if let Some((current_header, order)) = self.processes_sorted_by
+            && current_header == selected_header
+        {
+            match order {
+                SortedOrder::Desc => output = None,
+                SortedOrder::Asc => output = Some((selected_header, SortedOrder::Desc)),
+            }
+        }
+        self.set_process_sorted(output);
+    }
+
+    /// Set, or remove, the process sort, and re-sort the current processes, keeping the selected process selected
+    pub fn set_process_sorted(&mut self, sorted_by: Option<(ProcessHeader, SortedOrder)>) {
+        self.processes_sorted_by = sorted_by;
+        let processes = self.processes.items.clone();
+        self.processes.replace_items(
+            processes,
+            |_| true,
+            |i| i.pid,
+            |a, b| a.compare(b, sorted_by),
+        );
+        self.rerender.update_draw();
+    }
+
+    pub const fn get_process_sorted(&self) -> Option<(ProcessHeader, SortedOrder)> {
+        self.processes_sorted_by
+    }
+
+    /// Get the currently selected process
+    pub fn get_selected_process(&self) -> Option<&ProcessItem> {
+        self.processes
+            .state
+            .selected()
+            .and_then(|i| self.processes.items.get(i))
+    }
+
+    /// Get all the ProcessItems
+    pub fn get_process_items(&self) -> &[ProcessItem] {
+        &self.processes.items
+    }
+
+    pub const fn get_process_state(&mut self) -> &mut ListState {
+        &mut self.processes.state
+    }
+
+    /// Get title for the processes view, the selected process and the total number of processes, e.g. 2/5
+    pub fn get_process_title(&self) -> String {
+        self.processes.get_state_title()
+    }
+
+    pub fn processes_scroll(&mut self, scroll: &ScrollDirection) {
+        self.processes.scroll(scroll);
+        self.rerender.update_draw();
+    }
+
+    pub fn processes_start(&mut self) {
+        if !self.processes.items.is_empty() {
+            self.processes.start();
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn processes_end(&mut self) {
+        self.processes.end();
+        self.rerender.update_draw();
+    }
+
+    /// Volume related methods
+    /// Replace every volume of a given host, volumes are sorted by name, with anonymous volumes last
+    pub fn update_volumes(&mut self, host: &ContainerHost, volumes: Vec<VolumeItem>) {
+        self.volumes.replace_items(
+            volumes,
+            |i| &i.host == host,
+            |i| (i.host.clone(), i.name.clone()),
+            |a, b| {
+                a.anonymous
+                    .cmp(&b.anonymous)
+                    .then_with(|| a.name.cmp(&b.name))
+                    .then_with(|| a.host.cmp(&b.host))
+            },
+        );
+        self.rerender.update_draw();
+    }
+
+    /// Get the currently selected volume
+    pub fn get_selected_volume(&self) -> Option<&VolumeItem> {
+        self.volumes
+            .state
+            .selected()
+            .and_then(|i| self.volumes.items.get(i))
+    }
+
+    /// Get all the VolumeItems
+    pub fn get_volume_items(&self) -> &[VolumeItem] {
+        &self.volumes.items
+    }
+
+    pub const fn get_volume_state(&mut self) -> &mut ListState {
+        &mut self.volumes.state
+    }
+
+    /// Get title for the volumes view, the selected volume and the total number of volumes, e.g. 2/5
+    pub fn get_volume_title(&self) -> String {
+        self.volumes.get_state_title()
+    }
+
+    pub fn volumes_scroll(&mut self, scroll: &ScrollDirection) {
+        self.volumes.scroll(scroll);
+        self.rerender.update_draw();
+    }
+
+    pub fn volumes_start(&mut self) {
+        if !self.volumes.items.is_empty() {
+            self.volumes.start();
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn volumes_end(&mut self) {
+        self.volumes.end();
+        self.rerender.update_draw();
+    }
     /// Filter related methods
     /// Get the filterby and filter_term
     pub const fn get_filter(&self) -> (FilterBy, Option<&String>) {
@@ -247,10 +780,12 @@ impl AppData {
                     container.name.contains(&term)
                         || container.image.contains(&term)
                         || container.status.contains(&term)
+                        || container.host.contains(&term)
                 }
                 FilterBy::Image => container.image.contains(&term),
                 FilterBy::Name => container.name.contains(&term),
                 FilterBy::Status => container.status.contains(&term),
+                FilterBy::Host => container.host.contains(&term),
             }
         })
     }
@@ -476,6 +1011,34 @@ impl AppData {
                         .current_total()
                         .cmp(&item_ord.1.tx.current_total())
                         .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::DiskRead => item_ord
+                        .0
+                        .disk_read
+                        .current_total()
+                        .cmp(&item_ord.1.disk_read.current_total())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::DiskWrite => item_ord
+                        .0
+                        .disk_write
+                        .current_total()
+                        .cmp(&item_ord.1.disk_write.current_total())
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Host => item_ord
+                        .0
+                        .host
+                        .cmp(&item_ord.1.host)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Pids => item_ord
+                        .0
+                        .pids
+                        .cmp(&item_ord.1.pids)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Throttled => item_ord
+                        .0
+                        .throttling
+                        .throttled_time
+                        .cmp(&item_ord.1.throttling.throttled_time)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
                     Header::Name => item_ord
                         .0
                         .name
@@ -506,14 +1069,69 @@ impl AppData {
         self.containers.items.len()
     }
 
-    pub fn get_all_id_state(&self) -> Vec<(State, ContainerId)> {
+    /// Get the id & state of every visible container that belongs to the given host
+    pub fn get_all_id_state(&self, host: &ContainerHost) -> Vec<(State, ContainerId)> {
         self.containers
             .items
             .iter()
+            .filter(|i| &i.host == host)
             .map(|i| (i.state, i.id.clone()))
             .collect::<Vec<_>>()
     }
 
+    /// Remove every container, visible or hidden, that belongs to the given host
+    pub fn remove_host(&mut self, host: &ContainerHost) {
+        let ids = self
+            .containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .filter(|i| &i.host == host)
+            .map(|i| i.id.clone())
+            .collect::<Vec<_>>();
+        for id in ids {
+            self.remove_container(&id);
+        }
+        self.update_disk_usage(host, vec![]);
+        self.update_images(host, vec![]);
+        self.update_networks(host, vec![]);
+        self.update_volumes(host, vec![]);
+        self.disconnected.remove(host);
+    }
+
+    /// Set the connection state of a host, the containers of a disconnected host are kept, but greyed out, until it reconnects
+    pub fn set_host_connected(&mut self, host: &ContainerHost, connected: bool) {
+        if connected {
+            self.disconnected.remove(host);
+        } else {
+            self.disconnected.insert(host.clone());
+        }
+        self.rerender.update_draw();
+    }
+
+    /// Get every host that has lost its connection, sorted
+    pub fn get_disconnected(&self) -> Vec<ContainerHost> {
+        let mut output = self.disconnected.iter().cloned().collect::<Vec<_>>();
+        output.sort();
+        output
+    }
+
+    /// The `since` value of a containers logs, visible or hidden, so that only new log lines need to be requested
+    pub fn get_log_since(&mut self, id: &ContainerId) -> i32 {
+        self.get_any_container_by_id(id)
+            .map_or(0, |i| i.logs.since())
+    }
+
+    /// Find the host of a container, visible or hidden, based on id
+    pub fn get_host_by_id(&self, id: &ContainerId) -> Option<ContainerHost> {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .find(|i| &i.id == id)
+            .map(|i| i.host.clone())
+    }
+
     /// Get all the ContainerItems
     /// Thnk this allow block can be removed with the 1.87 release of Clippy
     pub fn get_container_items(&self) -> &[ContainerItem] {
@@ -527,26 +1145,289 @@ impl AppData {
         } else {
             ""
         };
-        format!("{}{}", self.containers.get_state_title(), suffix)
+        let marked = self.containers.items.iter().filter(|i| i.marked).count();
+        if marked > 0 {
+            format!(
+                "{}{} - {marked} marked",
+                self.containers.get_state_title(),
+                suffix
+            )
+        } else {
+            format!("{}{}", self.containers.get_state_title(), suffix)
+        }
     }
 
-    /// Select the first container
-    pub fn containers_start(&mut self) {
-        self.containers.start();
+    /// Mark, or unmark, the selected container
+    /// If a project header is selected, every container in the project is marked, or unmarked if they are all already marked
+    pub fn toggle_marked(&mut self) {
+        if let Some(project) = self.get_selected_project().map(ToOwned::to_owned) {
+            let in_project = |i: &ContainerItem| i.get_project() == Some(project.as_str());
+            let marked = !self
+                .containers
+                .items
+                .iter()
+                .filter(|i| in_project(i))
+                .all(|i| i.marked);
+            for i in self.containers.items.iter_mut().filter(|i| in_project(i)) {
+                i.marked = marked;
+            }
+            self.rerender.update_draw();
+        } else if let Some(i) = self.get_mut_selected_container() {
+            i.marked = !i.marked;
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Unmark every container, including those hidden by a filter
+    pub fn clear_marked(&mut self) {
+        for i in self
+            .containers
+            .items
+            .iter_mut()
+            .chain(self.hidden_containers.iter_mut())
+        {
+            i.marked = false;
+        }
         self.rerender.update_draw();
     }
 
-    /// select the last container
+    /// Get the ids of every marked container, in display order
+    /// Containers hidden by a filter are ignored, as is a containerised oxker
+    pub fn get_marked(&self) -> Vec<ContainerId> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| i.marked && !(i.is_oxker && self.config.in_container))
+            .map(|i| i.id.clone())
+            .collect()
+    }
+
+    /// Get the ids of every container that matches the current filter, in display order
+    /// Empty if no filter term is set, a containerised oxker is ignored
+    pub fn get_filtered(&self) -> Vec<ContainerId> {
+        if self.filter.term.is_none() {
+            return vec![];
+        }
+        self.containers
+            .items
+            .iter()
+            .filter(|i| !(i.is_oxker && self.config.in_container))
+            .map(|i| i.id.clone())
+            .collect()
+    }
+
+    /// Select the first container, or the first row when grouped by project
+    pub fn containers_start(&mut self) {
+        if self.projects.grouped {
+            let rows = self.get_project_rows();
+            self.select_project_row(rows.first());
+        } else {
+            self.containers.start();
+            self.rerender.update_draw();
+        }
+    }
+
+    /// select the last container, or the last row when grouped by project
     pub fn containers_end(&mut self) {
-        self.containers.end();
-        self.rerender.update_draw();
+        if self.projects.grouped {
+            let rows = self.get_project_rows();
+            self.select_project_row(rows.last());
+        } else {
+            self.containers.end();
+            self.rerender.update_draw();
+        }
     }
 
     pub fn containers_scroll(&mut self, scroll: &ScrollDirection) {
-        self.containers.scroll(scroll);
+        if self.projects.grouped {
+            let rows = self.get_project_rows();
+            let current = self.get_project_row_index(&rows);
+            let next = match scroll {
+                ScrollDirection::Down => {
+                    current.map_or(0, |i| (i + 1).min(rows.len().saturating_sub(1)))
+                }
+                ScrollDirection::Up => current.map_or(0, |i| i.saturating_sub(1)),
+                _ => return,
+            };
+            self.select_project_row(rows.get(next));
+        } else {
+            self.containers.scroll(scroll);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Toggle grouping the containers panel by Docker Compose project
+    pub fn toggle_grouped(&mut self) {
+        self.projects.grouped = !self.projects.grouped;
+        self.projects.selected = None;
+        self.rerender.update_draw();
+    }
+
+    /// Check if the containers panel is grouped by Docker Compose project
+    pub const fn is_grouped(&self) -> bool {
+        self.projects.grouped
+    }
+
+    /// Get the ListState of the grouped containers panel, with the given row selected
+    /// The state is kept between frames, so that the scroll offset of the panel is retained
+    pub fn get_project_state(&mut self, selected: Option<usize>) -> &mut ListState {
+        self.projects.state.select(selected);
+        &mut self.projects.state
+    }
+
+    /// Get the rows of the containers panel when grouped by project
+    /// Projects are ordered by their first container, containers that aren't part of a project are listed after every project
+    pub fn get_project_rows(&self) -> Vec<ProjectRow> {
+        let mut projects: Vec<(&str, Vec<usize>)> = vec![];
+        let mut ungrouped = vec![];
+        for (index, item) in self.containers.items.iter().enumerate() {
+            if let Some(project) = item.get_project() {
+                if let Some((_, indexes)) = projects.iter_mut().find(|(name, _)| *name == project) {
+                    indexes.push(index);
+                } else {
+                    projects.push((project, vec![index]));
+                }
+            } else {
+                ungrouped.push(index);
+            }
+        }
+
+        let mut rows = vec![];
+        for (project, indexes) in projects {
+            rows.push(ProjectRow::Project(project.to_owned()));
+            if !self.projects.collapsed.contains(project) {
+                rows.extend(indexes.into_iter().map(ProjectRow::Container));
+            }
+        }
+        rows.extend(ungrouped.into_iter().map(ProjectRow::Container));
+        rows
+    }
+
+    /// Get the index of the selected row of the grouped containers panel
+    /// If the selected container is in a collapsed project, the index of the project header is returned
+    pub fn get_project_row_index(&self, rows: &[ProjectRow]) -> Option<usize> {
+        if let Some(project) = self.get_selected_project() {
+            return rows
+                .iter()
+                .position(|i| matches!(i, ProjectRow::Project(p) if p == project));
+        }
+        let selected = self.containers.state.selected()?;
+        rows.iter()
+            .position(|i| i == &ProjectRow::Container(selected))
+            .or_else(|| {
+                let project = self.containers.items.get(selected)?.get_project()?;
+                rows.iter()
+                    .position(|i| matches!(i, ProjectRow::Project(p) if p == project))
+            })
+    }
+
+    /// Select a row of the grouped containers panel
+    /// Selecting a project header also selects the first container of the project, so that its logs & charts are still shown
+    fn select_project_row(&mut self, row: Option<&ProjectRow>) {
+        match row {
+            Some(ProjectRow::Project(project)) => {
+                let first = self
+                    .containers
+                    .items
+                    .iter()
+                    .position(|i| i.get_project() == Some(project.as_str()));
+                self.containers.state.select(first);
+                self.projects.selected = Some(project.clone());
+            }
+            Some(ProjectRow::Container(index)) => {
+                self.containers.state.select(Some(*index));
+                self.projects.selected = None;
+            }
+            None => return,
+        }
         self.rerender.update_draw();
     }
 
+    /// Check if a project has been collapsed, so that its containers are hidden
+    pub fn is_project_collapsed(&self, project: &str) -> bool {
+        self.projects.collapsed.contains(project)
+    }
+
+    /// Get the name of the selected project, only if grouped by project and a project header is selected
+    pub fn get_selected_project(&self) -> Option<&str> {
+        self.projects.selected.as_deref().filter(|project| {
+            self.projects.grouped
+                && self
+                    .containers
+                    .items
+                    .iter()
+                    .any(|i| i.get_project() == Some(project))
+        })
+    }
+
+    /// Collapse, or expand, the selected project, if a container in a project is selected, its project is collapsed and then selected
+    pub fn toggle_project_collapsed(&mut self) {
+        if !self.projects.grouped {
+            return;
+        }
+        let project = self
+            .get_selected_project()
+            .or_else(|| {
+                self.get_selected_container()
+                    .and_then(ContainerItem::get_project)
+            })
+            .map(ToOwned::to_owned);
+        if let Some(project) = project {
+            if !self.projects.collapsed.remove(&project) {
+                self.projects.collapsed.insert(project.clone());
+            }
+            self.projects.selected = Some(project);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Get the ids of every container in the selected project, a containerised oxker is ignored
+    pub fn get_selected_project_ids(&self) -> Vec<ContainerId> {
+        self.get_selected_project()
+            .map_or_else(Vec::new, |project| {
+                self.containers
+                    .items
+                    .iter()
+                    .filter(|i| {
+                        i.get_project() == Some(project)
+                            && !(i.is_oxker && self.config.in_container)
+                    })
+                    .map(|i| i.id.clone())
+                    .collect()
+            })
+    }
+
+    /// Get the Docker Compose projects that the given containers belong to
+    pub fn get_projects_by_ids(&self, ids: &[ContainerId]) -> HashSet<String> {
+        self.containers
+            .items
+            .iter()
+            .filter(|i| ids.contains(&i.id))
+            .filter_map(|i| i.get_project().map(ToOwned::to_owned))
+            .collect()
+    }
+
+    /// Get the number of containers in a project, and the combined current cpu & memory usage
+    pub fn get_project_summary(&self, project: &str) -> (usize, CpuStats, ByteStats) {
+        let containers = self
+            .containers
+            .items
+            .iter()
+            .filter(|i| i.get_project() == Some(project))
+            .collect::<Vec<_>>();
+        (
+            containers.len(),
+            containers
+                .iter()
+                .filter_map(|i| i.cpu_stats.back().copied())
+                .sum(),
+            containers
+                .iter()
+                .filter_map(|i| i.mem_stats.back().copied())
+                .sum(),
+        )
+    }
+
     /// Get ListState of containers
     pub const fn get_container_state(&mut self) -> &mut ListState {
         &mut self.containers.state
@@ -597,7 +1478,7 @@ impl AppData {
     pub fn get_selected_ports(&self) -> Option<(Vec<ContainerPorts>, State)> {
         if let Some(item) = self.get_selected_container() {
             let mut ports = item.ports.clone();
-            ports.sort_by(|a, b| a.private.cmp(&b.private));
+            ports.sort_by_key(|a| a.private);
             return Some((ports, item.state));
         }
         None
@@ -659,51 +1540,60 @@ impl AppData {
     /// Get the current selected docker command
     /// So know which command to execute
     pub fn selected_docker_controls(&self) -> Option<DockerCommand> {
-        self.get_selected_container().and_then(|i| {
-            i.docker_controls.state.selected().and_then(|x| {
-                i.docker_controls
-                    .items
-                    .get(x)
-                    .map(std::borrow::ToOwned::to_owned)
-            })
+        let controls = if self.get_selected_project().is_some() {
+            Some(&self.projects.controls)
+        } else {
+            self.get_selected_container().map(|i| &i.docker_controls)
+        };
+        controls.and_then(|i| {
+            i.state
+                .selected()
+                .and_then(|x| i.items.get(x).map(std::borrow::ToOwned::to_owned))
         })
     }
 
+    /// Get the docker commands of the selected project, or of the selected container if no project is selected
+    fn get_mut_controls(&mut self) -> Option<&mut StatefulList<DockerCommand>> {
+        if self.get_selected_project().is_some() {
+            Some(&mut self.projects.controls)
+        } else {
+            self.get_mut_selected_container()
+                .map(|i| &mut i.docker_controls)
+        }
+    }
+
     /// Change selected choice of docker commands of selected container
     pub fn docker_controls_scroll(&mut self, scroll: &ScrollDirection) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.scroll(scroll);
-            // i.docker_controls.next();
+        if let Some(i) = self.get_mut_controls() {
+            i.scroll(scroll);
             self.rerender.update_draw();
         }
     }
 
     /// Change selected choice of docker commands of selected container
     pub fn docker_controls_start(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.start();
+        if let Some(i) = self.get_mut_controls() {
+            i.start();
             self.rerender.update_draw();
         }
     }
 
     /// Change selected choice of docker commands of selected container
     pub fn docker_controls_end(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.docker_controls.end();
+        if let Some(i) = self.get_mut_controls() {
+            i.end();
             self.rerender.update_draw();
         }
     }
 
-    /// Get mutable Option of the currently selected container DockerCommand state
+    /// Get mutable Option of the currently selected container, or project, DockerCommand state
     pub fn get_control_state(&mut self) -> Option<&mut ListState> {
-        self.get_mut_selected_container()
-            .map(|i| &mut i.docker_controls.state)
+        self.get_mut_controls().map(|i| &mut i.state)
     }
 
-    /// Get mutable Option of the currently selected container DockerCommand items
+    /// Get mutable Option of the currently selected container, or project, DockerCommand items
     pub fn get_control_items(&mut self) -> Option<&mut Vec<DockerCommand>> {
-        self.get_mut_selected_container()
-            .map(|i| &mut i.docker_controls.items)
+        self.get_mut_controls().map(|i| &mut i.items)
     }
 
     /// Logs related methods
@@ -846,6 +1736,21 @@ impl AppData {
         let mut columns = Columns::new();
         let count = |x: &str| u8::try_from(x.chars().count()).unwrap_or(12);
 
+        // Optional columns, only displayed when enabled in the config, or when monitoring more than one host
+        if self.config.hosts.len() > 1 {
+            columns.host = Some((Header::Host, 4));
+        }
+        if self.config.show_disk_io {
+            columns.disk_read = Some((Header::DiskRead, 9));
+            columns.disk_write = Some((Header::DiskWrite, 10));
+        }
+        if self.config.show_pids {
+            columns.pids = Some((Header::Pids, 4));
+        }
+        if self.config.show_throttling {
+            columns.throttled = Some((Header::Throttled, 9));
+        }
+
         for container in [&self.containers.items, &self.hidden_containers] {
             for container in container {
                 // TODO refactor these
@@ -873,6 +1778,25 @@ impl AppData {
                     .max(count(&container.tx.current_total().to_string()));
                 columns.state.1 = columns.state.1.max(count(&container.state.to_string()));
                 columns.status.1 = columns.status.1.max(count(container.status.get()));
+                if let Some(host) = columns.host.as_mut() {
+                    host.1 = host.1.max(count(&container.host.to_string()));
+                }
+                if let Some(disk_read) = columns.disk_read.as_mut() {
+                    disk_read.1 = disk_read
+                        .1
+                        .max(count(&container.disk_read.current_total().to_string()));
+                }
+                if let Some(disk_write) = columns.disk_write.as_mut() {
+                    disk_write.1 = disk_write
+                        .1
+                        .max(count(&container.disk_write.current_total().to_string()));
+                }
+                if let Some(pids) = columns.pids.as_mut() {
+                    pids.1 = pids.1.max(count(&container.pids.to_string()));
+                }
+                if let Some(throttled) = columns.throttled.as_mut() {
+                    throttled.1 = throttled.1.max(count(&container.throttling.to_string()));
+                }
             }
         }
         columns
@@ -888,16 +1812,19 @@ impl AppData {
         }
     }
 
-    /// Update container mem, cpu, & network stats, in single function so only need to call .lock() once
+    /// Update container mem, cpu, network, block I/O, pids & throttling stats, in single function so only need to call .lock() once
     /// Will also, if a sort is set, sort the containers
+    #[allow(clippy::too_many_arguments)]
     pub fn update_stats_by_id(
         &mut self,
         id: &ContainerId,
         cpu_stat: Option<f64>,
         mem_stat: Option<u64>,
         mem_limit: u64,
-        rx: u64,
-        tx: u64,
+        networks: BTreeMap<String, (u64, u64)>,
+        disk: (u64, u64),
+        pids: Option<u64>,
+        throttling: CpuThrottling,
     ) {
         if let Some(container) = self.get_any_container_by_id(id) {
             if container.cpu_stats.len() >= 60 {
@@ -915,11 +1842,36 @@ impl AppData {
             }
 
             // Only insert if alive, or if is empty, need two to create an entry in the bandwidth chart, so instead this fills in the RX/TX total columns
+            // RX/TX are the sum of every network interface, with each interface also stored individually
             if container.rx.is_empty() || container.state.is_alive() {
+                let (rx, tx) = networks.values().fold((0u64, 0u64), |acc, (rx, tx)| {
+                    (acc.0.saturating_add(*rx), acc.1.saturating_add(*tx))
+                });
                 container.rx.push(rx);
                 container.tx.push(tx);
+                container
+                    .interfaces
+                    .retain(|name, _| networks.contains_key(name));
+                for (name, (rx, tx)) in networks {
+                    let interface = container
+                        .interfaces
+                        .entry(name)
+                        .or_insert_with(InterfaceBandwidth::new);
+                    interface.rx.push(rx);
+                    interface.tx.push(tx);
+                }
+            }
+
+            // Block I/O totals, same as RX/TX, the chart is made from the difference between each entry
+            if container.disk_read.is_empty() || container.state.is_alive() {
+                container.disk_read.push(disk.0);
+                container.disk_write.push(disk.1);
             }
 
+            if let Some(pids) = pids {
+                container.pids = pids;
+            }
+            container.throttling = throttling;
             container.mem_limit.update(mem_limit);
         }
         if self.is_selected_container(id) {
@@ -928,125 +1880,182 @@ impl AppData {
         self.sort_containers();
     }
 
-    /// Update, or insert, containers
-    pub fn update_containers(&mut self, mut all_containers: Vec<ContainerSummary>) {
+    /// Once a container has stopped it no longer has a stats stream, so set its cpu, memory, and pids to zero, and clear its throttling
+    /// Otherwise the last values from when it was running would be displayed
+    pub fn reset_stats_by_id(&mut self, id: &ContainerId) {
+        if let Some(container) = self.get_any_container_by_id(id) {
+            if container.cpu_stats.len() >= 60 {
+                container.cpu_stats.pop_front();
+            }
+            if container.mem_stats.len() >= 60 {
+                container.mem_stats.pop_front();
+            }
+            container.cpu_stats.push_back(CpuStats::new(0.0));
+            container.mem_stats.push_back(ByteStats::new(0));
+            container.pids = 0;
+            container.throttling = CpuThrottling::default();
+        }
+        if self.is_selected_container(id) {
+            self.rerender.update_draw();
+        }
+        self.sort_containers();
+    }
+
+    /// Update, or insert, containers of a single host, containers from any other host are left untouched
+    pub fn update_containers(
+        &mut self,
+        host: &ContainerHost,
+        mut all_containers: Vec<ContainerSummary>,
+    ) {
         let all_ids = self
             .containers
             .items
             .iter()
+            .filter(|i| &i.host == host)
             .map(|i| i.id.clone())
             .collect::<Vec<_>>();
 
-        // Only sort it no containers currently set, as afterwards the order is fixed
-        if self.containers.items.is_empty() {
-            all_containers.sort_by(|a, b| a.created.cmp(&b.created));
+        // Only sort it no containers currently set for this host, as afterwards the order is fixed
+        if all_ids.is_empty() {
+            all_containers.sort_by_key(|a| a.created);
         }
 
         if !all_containers.is_empty() && self.containers.state.selected().is_none() {
             self.containers.start();
         }
 
-        for (index, id) in all_ids.iter().enumerate() {
+        // Containers are looked up by id, rather than by index, as the containers of other hosts are interleaved
+        for id in &all_ids {
             if !all_containers
                 .iter()
                 .filter_map(|i| i.id.as_ref())
                 .any(|x| x == id.get())
             {
-                // If removed container is currently selected, then change selected to previous
-                // This will default to 0 in any edge cases
-                if self.containers.state.selected().is_some() {
-                    self.containers.scroll(&ScrollDirection::Up);
-                }
-                // Check is some, else can cause out of bounds error, if containers get removed before a docker update
-                if self.containers.items.get(index).is_some() {
-                    self.containers.items.remove(index);
-                    if self.is_selected_container(id) {
-                        self.rerender.update_draw();
-                    }
-                }
+                self.remove_container(id);
             }
         }
 
-        for mut i in all_containers {
-            if let Some(id) = i.id.as_ref() {
-                let name = i.names.as_mut().map_or(String::new(), |names| {
-                    names.first_mut().map_or(String::new(), |f| {
-                        if f.starts_with('/') {
-                            f.remove(0);
-                        }
-                        (*f).clone()
-                    })
-                });
+        for i in all_containers {
+            self.update_container(host, i);
+        }
+    }
 
-                let ports = i.ports.map_or(vec![], |i| {
-                    i.into_iter().map(ContainerPorts::from).collect::<Vec<_>>()
-                });
+    /// Remove a single container, visible or hidden, based on id
+    /// Used when the Docker events stream reports that a container has been destroyed
+    pub fn remove_container(&mut self, id: &ContainerId) {
+        self.hidden_containers.retain(|i| &i.id != id);
+        if let Some(index) = self.containers.items.iter().position(|i| &i.id == id) {
+            let is_selected = self.is_selected_container(id);
+            // If the removed container is at, or before, the selected container, move the selection up one
+            if self
+                .containers
+                .state
+                .selected()
+                .is_some_and(|selected| selected >= index)
+            {
+                self.containers.scroll(&ScrollDirection::Up);
+            }
+            self.containers.items.remove(index);
+            if self.containers.items.is_empty() {
+                self.containers.state.select(None);
+            }
+            if is_selected {
+                self.rerender.update_draw();
+            }
+        }
+    }
+
+    /// Update, or insert, a single container
+    /// Used by the full container list update, and by targeted updates from the Docker events stream
+    pub fn update_container(&mut self, host: &ContainerHost, mut i: ContainerSummary) {
+        let Some(id) = i.id.as_ref() else {
+            return;
+        };
+        let name = i.names.as_mut().map_or(String::new(), |names| {
+            names.first_mut().map_or(String::new(), |f| {
+                if f.starts_with('/') {
+                    f.remove(0);
+                }
+                (*f).clone()
+            })
+        });
 
-                let id = ContainerId::from(id.as_str());
+        let ports = i.ports.map_or(vec![], |i| {
+            i.into_iter().map(ContainerPorts::from).collect::<Vec<_>>()
+        });
 
-                let is_oxker = i
-                    .command
-                    .as_ref()
-                    .is_some_and(|i| i.starts_with(ENTRY_POINT));
+        let id = ContainerId::new(host, id);
 
-                let status = ContainerStatus::from(
-                    i.status
-                        .as_ref()
-                        .map_or(String::new(), std::clone::Clone::clone),
-                );
-                let state = State::from((
-                    i.state
-                        .as_ref()
-                        .map_or(&bollard::secret::ContainerSummaryStateEnum::DEAD, |z| z),
-                    &status,
-                ));
-                let image = i
-                    .image
-                    .as_ref()
-                    .map_or(String::new(), std::clone::Clone::clone);
-
-                let created = i
-                    .created
-                    .map_or(0, |i| u64::try_from(i).unwrap_or_default());
-
-                if let Some(item) = self.get_any_container_by_id(&id) {
-                    if item.name.get() != name {
-                        item.name.set(name);
-                    }
-                    if item.status != status {
-                        item.status = status;
-                    }
-                    if item.state != state {
-                        item.docker_controls.items = DockerCommand::gen_vec(state);
-                        // Update the list state, needs to be None if the gen_vec returns an empty vec
-                        match state {
-                            State::Removing | State::Restarting | State::Unknown => {
-                                item.docker_controls.state.select(None);
-                            }
-                            _ => item.docker_controls.start(),
-                        }
-                        item.state = state;
+        let is_oxker = i
+            .command
+            .as_ref()
+            .is_some_and(|i| i.starts_with(ENTRY_POINT));
+
+        let status = ContainerStatus::from(
+            i.status
+                .as_ref()
+                .map_or(String::new(), std::clone::Clone::clone),
+        );
+        let state = State::from((
+            i.state
+                .as_ref()
+                .map_or(&bollard::secret::ContainerSummaryStateEnum::DEAD, |z| z),
+            &status,
+        ));
+        let image = i
+            .image
+            .as_ref()
+            .map_or(String::new(), std::clone::Clone::clone);
+
+        let created = i
+            .created
+            .map_or(0, |i| u64::try_from(i).unwrap_or_default());
+
+        let labels = i.labels.unwrap_or_default();
+
+        if let Some(item) = self.get_any_container_by_id(&id) {
+            if item.name.get() != name {
+                item.name.set(name);
+            }
+            if item.status != status {
+                item.status = status;
+            }
+            if item.state != state {
+                item.docker_controls.items = DockerCommand::gen_vec(state);
+                // Update the list state, needs to be None if the gen_vec returns an empty vec
+                match state {
+                    State::Removing | State::Restarting | State::Unknown => {
+                        item.docker_controls.state.select(None);
                     }
+                    _ => item.docker_controls.start(),
+                }
+                item.state = state;
+            }
 
-                    item.ports = ports;
+            item.ports = ports;
 
-                    if item.image.get() != image {
-                        item.image.set(image);
-                    }
-                } else {
-                    // container not known, so make new ContainerItem and push into containers Ve
-                    let container = ContainerItem::new(
-                        created, id, image, is_oxker, name, ports, state, status,
-                    );
-                    let can_insert = self.can_insert(&container);
-                    if can_insert {
-                        self.containers.items.push(container);
-                    } else {
-                        self.hidden_containers.push(container);
-                    }
+            if item.image.get() != image {
+                item.image.set(image);
+            }
+
+            if item.labels != labels {
+                item.labels = labels;
+            }
+        } else {
+            // container not known, so make new ContainerItem and push into containers Ve
+            let mut container =
+                ContainerItem::new(created, id, image, is_oxker, name, ports, state, status);
+            container.host = host.clone();
+            container.labels = labels;
+            let can_insert = self.can_insert(&container);
+            if can_insert {
+                self.containers.items.push(container);
+                if self.containers.state.selected().is_none() {
+                    self.containers.start();
                 }
+            } else {
+                self.hidden_containers.push(container);
             }
-            // self.redraw.set_true("update_containers");
         }
     }
 
@@ -1063,7 +2072,6 @@ impl AppData {
 
         if let Some(container) = self.get_any_container_by_id(id) {
             if !container.is_oxker {
-                container.last_updated = Self::get_systemtime();
                 let current_len = container.logs.len();
                 for mut i in logs {
                     let (log_tz, log_content) = LogsTz::splitter(i.as_str());
@@ -1355,15 +2363,15 @@ mod tests {
         assert_eq!(result, &containers);
 
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.rx = NetworkBandwidth::new();
+            i.rx = Bandwidth::new();
             i.rx.push(40);
         }
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.rx = NetworkBandwidth::new();
+            i.rx = Bandwidth::new();
             i.rx.push(80);
         }
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.rx = NetworkBandwidth::new();
+            i.rx = Bandwidth::new();
             i.rx.push(2);
         }
 
@@ -1395,15 +2403,15 @@ mod tests {
         assert_eq!(result, &containers);
 
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.rx = NetworkBandwidth::new();
+            i.rx = Bandwidth::new();
             i.rx.push(400);
         }
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.rx = NetworkBandwidth::new();
+            i.rx = Bandwidth::new();
             i.rx.push(80);
         }
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.rx = NetworkBandwidth::new();
+            i.rx = Bandwidth::new();
             i.rx.push(83);
         }
 
@@ -1424,6 +2432,146 @@ mod tests {
         assert_eq!(c.id, ContainerId::from("1"));
     }
 
+    #[test]
+    /// Sort by header: disk read
+    fn test_app_data_set_sort_by_header_disk_read() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.disk_read = Bandwidth::new();
+            i.disk_read.push(4000);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.disk_read = Bandwidth::new();
+            i.disk_read.push(80);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.disk_read = Bandwidth::new();
+            i.disk_read.push(900);
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::DiskRead, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("2"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::DiskRead, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+    }
+
+    #[test]
+    /// Sort by header: disk write
+    fn test_app_data_set_sort_by_header_disk_write() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.disk_write = Bandwidth::new();
+            i.disk_write.push(10);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.disk_write = Bandwidth::new();
+            i.disk_write.push(5000);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.disk_write = Bandwidth::new();
+            i.disk_write.push(700);
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::DiskWrite, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::DiskWrite, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("2"));
+    }
+
+    #[test]
+    /// Sort by header: pids
+    fn test_app_data_set_sort_by_header_pids() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        for (id, pids) in [("1", 10), ("2", 5000), ("3", 700)] {
+            if let Some(i) = app_data.get_container_by_id(&ContainerId::from(id)) {
+                i.pids = pids;
+            }
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::Pids, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Pids, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("2"));
+    }
+
+    #[test]
+    /// Sort by header: throttled, uses the total throttled time
+    fn test_app_data_set_sort_by_header_throttled() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        for (id, time) in [("1", 10), ("2", 5000), ("3", 700)] {
+            if let Some(i) = app_data.get_container_by_id(&ContainerId::from(id)) {
+                i.throttling.throttled_time = time;
+            }
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::Throttled, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Throttled, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("2"));
+    }
+
     #[test]
     /// Sort by header when selected headers match
     fn test_app_data_set_sort_by_header_match() {
@@ -1462,15 +2610,15 @@ mod tests {
         assert_eq!(result, &containers);
 
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.rx = NetworkBandwidth::new();
+            i.rx = Bandwidth::new();
             i.rx.push(400);
         }
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.rx = NetworkBandwidth::new();
+            i.rx = Bandwidth::new();
             i.rx.push(80);
         }
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.rx = NetworkBandwidth::new();
+            i.rx = Bandwidth::new();
             i.rx.push(83);
         }
 
@@ -1755,7 +2903,7 @@ mod tests {
         app_data.docker_controls_scroll(&ScrollDirection::Up);
 
         let result = app_data.selected_docker_controls();
-        assert_eq!(result, Some(DockerCommand::Stop));
+        assert_eq!(result, Some(DockerCommand::Kill));
 
         // previous has no effect when at start
         app_data.docker_controls_start();
@@ -1809,13 +2957,18 @@ mod tests {
             &mut vec![
                 DockerCommand::Resume,
                 DockerCommand::Stop,
+                DockerCommand::Kill,
                 DockerCommand::Delete,
             ],
         );
         test_state(State::Removing, &mut vec![DockerCommand::Delete]);
         test_state(
             State::Restarting,
-            &mut vec![DockerCommand::Stop, DockerCommand::Delete],
+            &mut vec![
+                DockerCommand::Stop,
+                DockerCommand::Kill,
+                DockerCommand::Delete,
+            ],
         );
         test_state(
             State::Running(RunningState::Healthy),
@@ -1823,12 +2976,107 @@ mod tests {
                 DockerCommand::Pause,
                 DockerCommand::Restart,
                 DockerCommand::Stop,
+                DockerCommand::Kill,
                 DockerCommand::Delete,
             ],
         );
         test_state(State::Unknown, &mut vec![DockerCommand::Delete]);
     }
 
+    #[test]
+    /// Containers can be marked & unmarked, and the number of marked containers is shown in the title
+    fn test_app_data_marked() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_marked().is_empty());
+
+        app_data.containers_start();
+        app_data.toggle_marked();
+        assert_eq!(app_data.get_marked(), [ids[0].clone()]);
+        assert_eq!(app_data.get_container_title(), " 1/3 - 1 marked");
+
+        app_data.containers_end();
+        app_data.toggle_marked();
+        assert_eq!(app_data.get_marked(), [ids[0].clone(), ids[2].clone()]);
+        assert_eq!(app_data.get_container_title(), " 3/3 - 2 marked");
+
+        app_data.toggle_marked();
+        assert_eq!(app_data.get_marked(), [ids[0].clone()]);
+
+        app_data.clear_marked();
+        assert!(app_data.get_marked().is_empty());
+        assert_eq!(app_data.get_container_title(), " 3/3");
+    }
+
+    #[test]
+    /// Containers are grouped by project, projects can be collapsed, and the project commands are used when a project header is selected
+    fn test_app_data_projects() {
+        let (ids, mut containers) = gen_containers();
+        for i in &mut containers[..2] {
+            i.labels.insert(
+                "com.docker.compose.project".to_owned(),
+                "project_1".to_owned(),
+            );
+        }
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_selected_project().is_none());
+
+        app_data.toggle_grouped();
+        let rows = app_data.get_project_rows();
+        assert_eq!(
+            rows,
+            [
+                ProjectRow::Project("project_1".to_owned()),
+                ProjectRow::Container(0),
+                ProjectRow::Container(1),
+                ProjectRow::Container(2),
+            ]
+        );
+
+        app_data.containers_start();
+        assert_eq!(app_data.get_selected_project(), Some("project_1"));
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[0].clone()));
+        assert_eq!(
+            app_data.get_selected_project_ids(),
+            [ids[0].clone(), ids[1].clone()]
+        );
+        assert_eq!(
+            app_data.get_control_items().cloned(),
+            Some(DockerCommand::PROJECT.to_vec())
+        );
+        assert_eq!(app_data.get_project_summary("project_1").0, 2);
+        assert_eq!(
+            app_data.get_projects_by_ids(&ids),
+            HashSet::from(["project_1".to_owned()])
+        );
+        assert!(app_data.get_projects_by_ids(&ids[2..]).is_empty());
+
+        app_data.containers_scroll(&ScrollDirection::Down);
+        assert!(app_data.get_selected_project().is_none());
+        assert_eq!(app_data.get_project_row_index(&rows), Some(1));
+
+        // Collapsing from a container row selects the project header
+        app_data.toggle_project_collapsed();
+        assert_eq!(
+            app_data.get_project_rows(),
+            [
+                ProjectRow::Project("project_1".to_owned()),
+                ProjectRow::Container(2),
+            ]
+        );
+        assert_eq!(app_data.get_selected_project(), Some("project_1"));
+
+        app_data.containers_scroll(&ScrollDirection::Down);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[2].clone()));
+
+        // The grouped ListState is retained, so the scroll offset isn't lost between frames
+        *app_data.get_project_state(Some(1)).offset_mut() = 1;
+        assert_eq!(app_data.get_project_state(Some(1)).offset(), 1);
+
+        app_data.toggle_grouped();
+        assert!(app_data.get_selected_project().is_none());
+    }
+
     // ****** //
     // Filter //
     // ****** //
@@ -1859,6 +3107,23 @@ mod tests {
         assert!(!app_data.can_insert(&containers[2]));
     }
 
+    #[test]
+    /// Only the containers that match the filter term are returned, and nothing is returned without a filter term
+    fn test_app_data_get_filtered() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_filtered().is_empty());
+
+        app_data.filter_term_push('_');
+        assert_eq!(app_data.get_filtered(), ids);
+
+        app_data.filter_term_push('2');
+        assert_eq!(app_data.get_filtered(), [ids[1].clone()]);
+
+        app_data.filter_term_clear();
+        assert!(app_data.get_filtered().is_empty());
+    }
+
     #[test]
     /// Data is filtered correctly by image
     fn test_app_data_filter_by_image() {
@@ -1869,15 +3134,45 @@ mod tests {
         assert!(app_data.get_filter().1.is_none());
 
         let pre_len = app_data.containers.items.len();
-        for c in ['i', 'm', 'a', 'g', 'e', '_', '2'] {
-            app_data.filter_term_push(c);
-        }
-        // app_data.filter_term_push('2');
+        for c in ['i', 'm', 'a', 'g', 'e', '_', '2'] {
+            app_data.filter_term_push(c);
+        }
+        // app_data.filter_term_push('2');
+        app_data.filter_by_next();
+
+        assert_eq!(
+            app_data.get_filter(),
+            (FilterBy::Image, Some(&"image_2".to_string()))
+        );
+
+        app_data.filter_containers();
+        let post_len = app_data.containers.items.len();
+        assert!(pre_len != post_len);
+        assert_eq!(post_len, 1);
+
+        assert!(!app_data.can_insert(&containers[0]));
+        assert!(app_data.can_insert(&containers[1]));
+        assert!(!app_data.can_insert(&containers[2]));
+    }
+
+    #[test]
+    /// Data is filtered correctly by status
+    fn test_app_data_filter_by_status() {
+        let (_, mut containers) = gen_containers();
+        ContainerStatus::from("Exited".to_owned()).clone_into(&mut containers[0].status);
+        let mut app_data = gen_appdata(&containers);
+
+        assert!(app_data.get_filter().1.is_none());
+
+        let pre_len = app_data.containers.items.len();
+        app_data.filter_term_push('x');
+
+        app_data.filter_by_next();
         app_data.filter_by_next();
 
         assert_eq!(
             app_data.get_filter(),
-            (FilterBy::Image, Some(&"image_2".to_string()))
+            (FilterBy::Status, Some(&"x".to_string()))
         );
 
         app_data.filter_containers();
@@ -1885,29 +3180,30 @@ mod tests {
         assert!(pre_len != post_len);
         assert_eq!(post_len, 1);
 
-        assert!(!app_data.can_insert(&containers[0]));
-        assert!(app_data.can_insert(&containers[1]));
+        assert!(app_data.can_insert(&containers[0]));
+        assert!(!app_data.can_insert(&containers[1]));
         assert!(!app_data.can_insert(&containers[2]));
     }
 
     #[test]
-    /// Data is filtered correctly by status
-    fn test_app_data_filter_by_status() {
+    /// Data is filtered correctly by host, the full host is matched, rather than the truncated host shown in the table
+    fn test_app_data_filter_by_host() {
         let (_, mut containers) = gen_containers();
-        ContainerStatus::from("Exited".to_owned()).clone_into(&mut containers[0].status);
+        containers[0].host = ContainerHost::from("tcp://docker-host.internal.example.com:2375");
         let mut app_data = gen_appdata(&containers);
 
-        assert!(app_data.get_filter().1.is_none());
-
         let pre_len = app_data.containers.items.len();
-        app_data.filter_term_push('x');
+        for c in "example.com".chars() {
+            app_data.filter_term_push(c);
+        }
 
+        app_data.filter_by_next();
         app_data.filter_by_next();
         app_data.filter_by_next();
 
         assert_eq!(
             app_data.get_filter(),
-            (FilterBy::Status, Some(&"x".to_string()))
+            (FilterBy::Host, Some(&"example.com".to_string()))
         );
 
         app_data.filter_containers();
@@ -1935,6 +3231,7 @@ mod tests {
         app_data.filter_by_next();
         app_data.filter_by_next();
         app_data.filter_by_next();
+        app_data.filter_by_next();
 
         assert_eq!(
             app_data.get_filter(),
@@ -2251,21 +3548,31 @@ mod tests {
 
         app_data.containers_start();
 
-        let mut rx = NetworkBandwidth::new();
+        let mut rx = Bandwidth::new();
         rx.push(200);
         rx.push(100);
         rx.push(200);
 
-        let mut tx = NetworkBandwidth::new();
+        let mut tx = Bandwidth::new();
         tx.push(300);
         tx.push(600);
         tx.push(900);
 
+        let mut disk_read = Bandwidth::new();
+        disk_read.push(1000);
+        disk_read.push(5000);
+
+        let mut disk_write = Bandwidth::new();
+        disk_write.push(4000);
+        disk_write.push(6000);
+
         if let Some(item) = app_data.get_container_by_id(&ContainerId::from("1")) {
             item.cpu_stats = VecDeque::from([CpuStats::new(1.2), CpuStats::new(1.2)]);
             item.mem_stats = VecDeque::from([ByteStats::new(1), ByteStats::new(2)]);
             item.rx = rx;
             item.tx = tx;
+            item.disk_read = disk_read;
+            item.disk_write = disk_write;
         }
 
         let result = app_data.get_chart_data();
@@ -2282,6 +3589,7 @@ mod tests {
                     max: CpuStats::new(1.2),
                     current: CpuStats::new(1.2)
                 },
+                interfaces: vec![],
                 rx: ChartSeries {
                     dataset: vec![(0.0, 0.0), (1.0, 100.0)],
                     max: BandwidthStat::new(100),
@@ -2292,6 +3600,18 @@ mod tests {
                     max: BandwidthStat::new(300),
                     current: BandwidthStat::new(300)
                 },
+                disk_read: ChartSeries {
+                    dataset: vec![(0.0, 4000.0)],
+                    max: BandwidthStat::new(4000),
+                    current: BandwidthStat::new(4000)
+                },
+                disk_write: ChartSeries {
+                    dataset: vec![(0.0, 2000.0)],
+                    max: BandwidthStat::new(2000),
+                    current: BandwidthStat::new(2000)
+                },
+                pids: 0,
+                throttling: CpuThrottling::default(),
                 state: State::Running(RunningState::Healthy)
             })
         );
@@ -2318,10 +3638,55 @@ mod tests {
             image: (Header::Image, 7),
             net_rx: (Header::Rx, 7),
             net_tx: (Header::Tx, 7),
+            disk_read: None,
+            disk_write: None,
+            host: None,
+            pids: None,
+            throttled: None,
         };
         assert_eq!(result, expected);
     }
 
+    #[test]
+    /// Optional disk, pids & throttled columns only returned when enabled in the config
+    fn test_app_data_get_width_optional() {
+        let (_ids, mut containers) = gen_containers();
+        containers[0].pids = 123_456;
+        containers[1].throttling.throttled_time = 12_345_000_000_000;
+        containers[1].throttling.throttled_periods = 100;
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_width();
+        assert!(result.disk_read.is_none());
+        assert!(result.disk_write.is_none());
+        assert!(result.pids.is_none());
+        assert!(result.throttled.is_none());
+
+        app_data.config.show_disk_io = true;
+        app_data.config.show_pids = true;
+        app_data.config.show_throttling = true;
+        let result = app_data.get_width();
+        assert_eq!(result.disk_read, Some((Header::DiskRead, 9)));
+        assert_eq!(result.disk_write, Some((Header::DiskWrite, 10)));
+        assert_eq!(result.pids, Some((Header::Pids, 6)));
+        assert_eq!(result.throttled, Some((Header::Throttled, 13)));
+    }
+
+    #[test]
+    /// Host column only returned when monitoring multiple hosts, and is at least as wide as the widest host
+    fn test_app_data_get_width_host() {
+        let (_ids, mut containers) = gen_containers();
+        containers[0].host = ContainerHost::from("tcp://10.0.0.1:2375");
+        let mut app_data = gen_appdata(&containers);
+
+        let result = app_data.get_width();
+        assert!(result.host.is_none());
+
+        app_data.config.hosts = vec!["tcp://10.0.0.1:2375".to_owned(), "local".to_owned()];
+        let result = app_data.get_width();
+        assert_eq!(result.host, Some((Header::Host, 19)));
+    }
+
     #[test]
     /// Header widths return correctly when some containers hidden
     fn test_app_data_get_width_filtered() {
@@ -2340,6 +3705,11 @@ mod tests {
             image: (Header::Image, 7),
             net_rx: (Header::Rx, 7),
             net_tx: (Header::Tx, 7),
+            disk_read: None,
+            disk_write: None,
+            host: None,
+            pids: None,
+            throttled: None,
         };
 
         assert_eq!(result, expected);
@@ -2436,7 +3806,21 @@ mod tests {
         let result = app_data.get_container_items();
         assert_eq!(result[0], containers[0]);
 
-        app_data.update_stats_by_id(&ids[0], Some(10.0), Some(10), 10, 10, 10);
+        app_data.update_stats_by_id(
+            &ids[0],
+            Some(10.0),
+            Some(10),
+            10,
+            BTreeMap::from([("eth0".to_owned(), (10, 10))]),
+            (20, 30),
+            Some(4),
+            CpuThrottling {
+                throttled_periods: 5,
+                throttled_time: 6,
+                recent_periods: 7,
+                recent_throttled_periods: 8,
+            },
+        );
 
         let result = app_data.get_container_items();
         assert_ne!(result[0], containers[0]);
@@ -2444,14 +3828,132 @@ mod tests {
         assert_eq!(result[0].mem_stats, VecDeque::from([ByteStats::new(10)]));
         assert_eq!(result[0].mem_limit, ByteStats::new(10));
 
-        let mut rx = NetworkBandwidth::new();
+        let mut rx = Bandwidth::new();
         rx.push(10);
-        let mut tx = NetworkBandwidth::new();
+        let mut tx = Bandwidth::new();
         tx.push(10);
         assert_eq!(result[0].rx, rx);
         // VecDeque::from([ByteStats::new(10)]));
         assert_eq!(result[0].tx, tx);
         // VecDeque::from([ByteStats::new(10)]));
+
+        let mut disk_read = Bandwidth::new();
+        disk_read.push(20);
+        let mut disk_write = Bandwidth::new();
+        disk_write.push(30);
+        assert_eq!(result[0].disk_read, disk_read);
+        assert_eq!(result[0].disk_write, disk_write);
+
+        assert_eq!(result[0].pids, 4);
+        assert_eq!(
+            result[0].throttling,
+            CpuThrottling {
+                throttled_periods: 5,
+                throttled_time: 6,
+                recent_periods: 7,
+                recent_throttled_periods: 8,
+            }
+        );
+    }
+
+    #[test]
+    /// Resetting stats sets cpu, memory, and pids to zero, and clears throttling, but keeps the history
+    fn test_app_data_reset_stats() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        app_data.update_stats_by_id(
+            &ids[0],
+            Some(10.0),
+            Some(10),
+            10,
+            BTreeMap::new(),
+            (0, 0),
+            Some(4),
+            CpuThrottling {
+                throttled_periods: 5,
+                throttled_time: 6,
+                recent_periods: 7,
+                recent_throttled_periods: 8,
+            },
+        );
+        app_data.reset_stats_by_id(&ids[0]);
+
+        let result = app_data.get_container_items();
+        assert_eq!(
+            result[0].cpu_stats,
+            VecDeque::from([CpuStats::new(10.0), CpuStats::new(0.0)])
+        );
+        assert_eq!(
+            result[0].mem_stats,
+            VecDeque::from([ByteStats::new(10), ByteStats::new(0)])
+        );
+        assert_eq!(result[0].pids, 0);
+        assert_eq!(result[0].throttling, CpuThrottling::default());
+    }
+
+    #[test]
+    /// RX/TX are the sum of all network interfaces, and each interface is stored individually
+    fn test_app_data_update_stats_multiple_interfaces() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+
+        for i in 1..=2 {
+            app_data.update_stats_by_id(
+                &ids[0],
+                Some(10.0),
+                Some(10),
+                10,
+                BTreeMap::from([
+                    ("eth0".to_owned(), (i * 10, i * 20)),
+                    ("eth1".to_owned(), (i * 100, i * 200)),
+                ]),
+                (0, 0),
+                None,
+                CpuThrottling::default(),
+            );
+        }
+
+        let result = app_data.get_container_items();
+        assert_eq!(result[0].rx.current_total(), ByteStats::new(220));
+        assert_eq!(result[0].tx.current_total(), ByteStats::new(440));
+        assert_eq!(result[0].interfaces.len(), 2);
+
+        let result = app_data.get_chart_data().unwrap();
+        assert_eq!(
+            result.interfaces,
+            vec![
+                InterfaceRate {
+                    name: "eth0".to_owned(),
+                    rx: BandwidthStat::new(10),
+                    tx: BandwidthStat::new(20),
+                },
+                InterfaceRate {
+                    name: "eth1".to_owned(),
+                    rx: BandwidthStat::new(100),
+                    tx: BandwidthStat::new(200),
+                },
+            ]
+        );
+
+        // An interface that is no longer present is removed
+        app_data.update_stats_by_id(
+            &ids[0],
+            Some(10.0),
+            Some(10),
+            10,
+            BTreeMap::from([("eth1".to_owned(), (300, 600))]),
+            (0, 0),
+            None,
+            CpuThrottling::default(),
+        );
+        let result = app_data.get_container_items();
+        assert_eq!(result[0].rx.current_total(), ByteStats::new(300));
+        assert_eq!(
+            result[0].interfaces.keys().collect::<Vec<_>>(),
+            vec!["eth1"]
+        );
     }
 
     #[test]
@@ -2465,13 +3967,505 @@ mod tests {
             gen_container_summary(2, "dead"),
         ];
 
-        app_data.update_containers(input);
+        app_data.update_containers(&ContainerHost::default(), input);
         let result_post = app_data.get_container_items().to_owned();
         assert_ne!(result_pre, result_post);
         assert_eq!(result_post[0].state, State::Paused);
         assert_eq!(result_post[1].state, State::Dead);
     }
 
+    #[test]
+    /// Updating the containers of one host leaves the containers of any other host untouched
+    fn test_app_data_update_containers_multiple_hosts() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let host = ContainerHost::from("tcp://10.0.0.2:2375");
+
+        app_data.update_containers(&host, vec![gen_container_summary(4, "running")]);
+        let result = app_data.get_container_items();
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[3].host, host);
+        assert_eq!(
+            app_data.get_host_by_id(&ContainerId::new(&host, "4")),
+            Some(host.clone())
+        );
+        assert!(app_data.get_host_by_id(&ContainerId::from("4")).is_none());
+        assert_eq!(
+            app_data.get_host_by_id(&ids[0]),
+            Some(ContainerHost::default())
+        );
+        assert!(app_data.get_host_by_id(&ContainerId::from("5")).is_none());
+        assert_eq!(
+            app_data.get_all_id_state(&host),
+            vec![(
+                State::Running(RunningState::Healthy),
+                ContainerId::new(&host, "4")
+            )]
+        );
+        assert_eq!(
+            app_data.get_all_id_state(&ContainerHost::default()).len(),
+            3
+        );
+
+        // The second host now has no containers, only its own container is removed
+        app_data.update_containers(&host, vec![]);
+        let result = app_data.get_container_items();
+        assert_eq!(result.len(), 3);
+        assert!(result.iter().all(|i| i.host == ContainerHost::default()));
+    }
+
+    #[test]
+    /// The same daemon listed under two host spellings keeps a separate container for each host, keyed by host & id
+    fn test_app_data_update_containers_same_id_multiple_hosts() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let host = ContainerHost::from("unix:///var/run/docker.sock");
+
+        app_data.update_containers(&host, vec![gen_container_summary(1, "running")]);
+        let result = app_data.get_container_items();
+        assert_eq!(result.len(), 4);
+        assert_ne!(ids[0], ContainerId::new(&host, "1"));
+        assert_eq!(
+            app_data.get_host_by_id(&ids[0]),
+            Some(ContainerHost::default())
+        );
+        assert_eq!(
+            app_data.get_host_by_id(&ContainerId::new(&host, "1")),
+            Some(host.clone())
+        );
+
+        app_data.update_containers(&host, vec![]);
+        assert_eq!(app_data.get_container_items().len(), 3);
+        assert_eq!(
+            app_data.get_host_by_id(&ids[0]),
+            Some(ContainerHost::default())
+        );
+    }
+
+    #[test]
+    /// Disconnected hosts are tracked, sorted, and the containers are kept until the host is removed
+    fn test_app_data_host_connected() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let host_a = ContainerHost::from("host_a");
+        let host_b = ContainerHost::from("host_b");
+        assert!(app_data.get_disconnected().is_empty());
+
+        app_data.set_host_connected(&host_b, false);
+        app_data.set_host_connected(&host_a, false);
+        app_data.set_host_connected(&ContainerHost::default(), false);
+        assert_eq!(
+            app_data.get_disconnected(),
+            [ContainerHost::default(), host_a.clone(), host_b.clone()]
+        );
+        assert_eq!(app_data.get_container_items().len(), 3);
+
+        app_data.set_host_connected(&host_a, true);
+        assert_eq!(
+            app_data.get_disconnected(),
+            [ContainerHost::default(), host_b.clone()]
+        );
+
+        app_data.remove_host(&ContainerHost::default());
+        assert_eq!(app_data.get_disconnected(), [host_b]);
+        assert!(app_data.get_container_items().is_empty());
+    }
+
+    #[test]
+    /// Update a single container, without effecting any other containers
+    fn test_app_data_update_container() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        app_data.update_container(
+            &ContainerHost::default(),
+            gen_container_summary(2, "paused"),
+        );
+        let result = app_data.get_container_items();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].state, State::Running(RunningState::Healthy));
+        assert_eq!(result[1].state, State::Paused);
+        assert_eq!(result[2].state, State::Running(RunningState::Healthy));
+    }
+
+    #[test]
+    /// Update a single unknown container, inserts it into the containers vec
+    fn test_app_data_update_container_insert() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        app_data.update_container(
+            &ContainerHost::default(),
+            gen_container_summary(4, "exited"),
+        );
+        let result = app_data.get_container_items();
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[3].id, ContainerId::from("4"));
+        assert_eq!(result[3].state, State::Exited);
+    }
+
+    #[test]
+    /// Remove a single container, the selected container is moved up if it was at, or after, the removed container
+    fn test_app_data_remove_container() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_end();
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[2].clone()));
+
+        app_data.remove_container(&ids[1]);
+        let result = app_data.get_container_items();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].id, ids[0]);
+        assert_eq!(result[1].id, ids[2]);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[2].clone()));
+
+        app_data.remove_container(&ids[2]);
+        assert_eq!(app_data.get_container_items().len(), 1);
+        assert_eq!(app_data.get_selected_container_id(), Some(ids[0].clone()));
+
+        app_data.remove_container(&ids[0]);
+        assert!(app_data.get_container_items().is_empty());
+        assert!(app_data.get_selected_container_id().is_none());
+    }
+
+    #[test]
+    /// Remove a single container, that is currently hidden by a filter
+    fn test_app_data_remove_container_hidden() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.filter_term_push('1');
+        assert_eq!(app_data.hidden_containers.len(), 2);
+
+        app_data.remove_container(&ids[2]);
+        assert_eq!(app_data.hidden_containers.len(), 1);
+        assert_eq!(app_data.get_container_items().len(), 1);
+    }
+
+    /// Generate an image, for a given host, with the created time used as the id
+    fn gen_image(host: &ContainerHost, created: i64) -> ImageItem {
+        ImageItem::new(
+            bollard::secret::ImageSummary {
+                id: format!("sha256:{created}"),
+                created,
+                ..Default::default()
+            },
+            0,
+            host,
+        )
+    }
+
+    #[test]
+    /// Images are sorted newest first, and updating a host only replaces the images of that host
+    /// The selected image stays selected, and if it has been removed, the selected index is kept within bounds
+    fn test_app_data_update_images() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let (host_a, host_b) = (ContainerHost::from("a"), ContainerHost::from("b"));
+
+        app_data.update_images(&host_a, vec![gen_image(&host_a, 1), gen_image(&host_a, 3)]);
+        app_data.update_images(&host_b, vec![gen_image(&host_b, 2)]);
+        let created = |app_data: &AppData| {
+            app_data
+                .get_image_items()
+                .iter()
+                .map(|i| i.created)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(created(&app_data), [3, 2, 1]);
+        assert_eq!(app_data.get_selected_image().unwrap().created, 3);
+
+        app_data.images_scroll(&ScrollDirection::Down);
+        app_data.update_images(&host_a, vec![gen_image(&host_a, 1), gen_image(&host_a, 4)]);
+        assert_eq!(created(&app_data), [4, 2, 1]);
+        assert_eq!(app_data.get_selected_image().unwrap().created, 2);
+
+        app_data.images_end();
+        app_data.update_images(&host_a, vec![]);
+        assert_eq!(created(&app_data), [2]);
+        assert_eq!(app_data.get_selected_image().unwrap().created, 2);
+
+        app_data.remove_host(&host_b);
+        assert!(app_data.get_image_items().is_empty());
+        assert!(app_data.get_selected_image().is_none());
+    }
+
+    #[test]
+    /// Volumes are sorted by name, with anonymous volumes last, and updating a host only replaces the volumes of that host
+    fn test_app_data_update_volumes() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let (host_a, host_b) = (ContainerHost::from("a"), ContainerHost::from("b"));
+        let anonymous = "0123456789abcdef".repeat(4);
+        let gen_volume = |host: &ContainerHost, name: &str| {
+            VolumeItem::new(
+                bollard::secret::Volume {
+                    name: name.to_owned(),
+                    ..Default::default()
+                },
+                vec![],
+                None,
+                host,
+            )
+        };
+        let names = |app_data: &AppData| {
+            app_data
+                .get_volume_items()
+                .iter()
+                .map(|i| i.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        app_data.update_volumes(
+            &host_a,
+            vec![gen_volume(&host_a, &anonymous), gen_volume(&host_a, "b")],
+        );
+        app_data.update_volumes(&host_b, vec![gen_volume(&host_b, "a")]);
+        assert_eq!(names(&app_data), ["a", "b", anonymous.as_str()]);
+        assert_eq!(app_data.get_selected_volume().unwrap().name, "b");
+
+        app_data.volumes_scroll(&ScrollDirection::Down);
+        app_data.update_volumes(&host_a, vec![gen_volume(&host_a, "b")]);
+        assert_eq!(names(&app_data), ["a", "b"]);
+        assert_eq!(app_data.get_selected_volume().unwrap().name, "b");
+
+        app_data.remove_host(&host_a);
+        assert_eq!(names(&app_data), ["a"]);
+        assert_eq!(app_data.get_selected_volume().unwrap().name, "a");
+    }
+
+    #[test]
+    /// Disk usage categories are ordered by host, then kind, and updating a host only replaces the categories of that host
+    fn test_app_data_update_disk_usage() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let (host_a, host_b) = (ContainerHost::from("a"), ContainerHost::from("b"));
+        let gen_response = || bollard::secret::SystemDataUsageResponse {
+            images_disk_usage: Some(bollard::secret::ImagesDiskUsage::default()),
+            volumes_disk_usage: Some(bollard::secret::VolumesDiskUsage::default()),
+            ..Default::default()
+        };
+        let kinds = |app_data: &AppData| {
+            app_data
+                .get_disk_usage_items()
+                .iter()
+                .map(|i| (i.host.clone(), i.kind))
+                .collect::<Vec<_>>()
+        };
+
+        app_data.update_disk_usage(
+            &host_b,
+            DiskUsageCategory::from_response(gen_response(), &host_b),
+        );
+        app_data.update_disk_usage(
+            &host_a,
+            DiskUsageCategory::from_response(gen_response(), &host_a),
+        );
+        assert_eq!(
+            kinds(&app_data),
+            [
+                (host_a.clone(), DiskUsageKind::Images),
+                (host_a.clone(), DiskUsageKind::Volumes),
+                (host_b.clone(), DiskUsageKind::Images),
+                (host_b.clone(), DiskUsageKind::Volumes),
+            ]
+        );
+
+        app_data.disk_usage_end();
+        app_data.remove_host(&host_a);
+        assert_eq!(
+            kinds(&app_data),
+            [
+                (host_b.clone(), DiskUsageKind::Images),
+                (host_b.clone(), DiskUsageKind::Volumes),
+            ]
+        );
+        let selected = app_data.get_selected_disk_usage().unwrap();
+        assert_eq!(
+            (&selected.host, selected.kind),
+            (&host_b, DiskUsageKind::Volumes)
+        );
+    }
+
+    #[test]
+    /// When focused, the scroll keys move through the items of the selected category, which can be sorted by name or size
+    fn test_app_data_disk_usage_items() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let host = ContainerHost::default();
+        let image = |tag: &str, size: i64| {
+            serde_json::to_value(bollard::secret::ImageSummary {
+                id: format!("sha256:{tag}"),
+                repo_tags: vec![tag.to_owned()],
+                size,
+                ..Default::default()
+            })
+            .unwrap()
+        };
+        let response = bollard::secret::SystemDataUsageResponse {
+            images_disk_usage: Some(bollard::secret::ImagesDiskUsage {
+                items: Some(vec![image("b:1", 1), image("a:1", 2), image("c:1", 3)]),
+                ..Default::default()
+            }),
+            volumes_disk_usage: Some(bollard::secret::VolumesDiskUsage::default()),
+            ..Default::default()
+        };
+        app_data.update_disk_usage(&host, DiskUsageCategory::from_response(response, &host));
+        let names = |app_data: &AppData| {
+            app_data
+                .get_disk_usage_sorted_items()
+                .into_iter()
+                .map(|i| i.name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&app_data), ["c:1", "a:1", "b:1"]);
+
+        app_data.set_disk_usage_sort_by_header(DiskUsageHeader::Name);
+        assert_eq!(names(&app_data), ["a:1", "b:1", "c:1"]);
+        app_data.set_disk_usage_sort_by_header(DiskUsageHeader::Name);
+        assert_eq!(names(&app_data), ["c:1", "b:1", "a:1"]);
+        app_data.set_disk_usage_sort_by_header(DiskUsageHeader::Size);
+        assert_eq!(names(&app_data), ["b:1", "a:1", "c:1"]);
+        app_data.set_disk_usage_sorted(None);
+        assert_eq!(names(&app_data), ["c:1", "a:1", "b:1"]);
+
+        // Unfocused, the categories are scrolled, and no item is selected
+        assert!(app_data.get_disk_usage_items_state().selected().is_none());
+        app_data.toggle_disk_usage_focus();
+        assert!(app_data.is_disk_usage_items_focused());
+        assert_eq!(app_data.get_disk_usage_items_state().selected(), Some(0));
+        app_data.disk_usage_scroll(&ScrollDirection::Down);
+        app_data.disk_usage_scroll(&ScrollDirection::Down);
+        app_data.disk_usage_scroll(&ScrollDirection::Down);
+        assert_eq!(app_data.get_disk_usage_items_state().selected(), Some(2));
+        assert_eq!(
+            app_data.get_selected_disk_usage().unwrap().kind,
+            DiskUsageKind::Images
+        );
+        app_data.disk_usage_start();
+        assert_eq!(app_data.get_disk_usage_items_state().selected(), Some(0));
+
+        app_data.toggle_disk_usage_focus();
+        app_data.disk_usage_scroll(&ScrollDirection::Down);
+        assert_eq!(
+            app_data.get_selected_disk_usage().unwrap().kind,
+            DiskUsageKind::Volumes
+        );
+        assert!(app_data.get_disk_usage_items_state().selected().is_none());
+    }
+
+    #[test]
+    /// Networks are sorted by name, then host, and the selected network is kept when the networks are updated
+    fn test_app_data_update_networks() {
+        let (_ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let (host_a, host_b) = (ContainerHost::from("a"), ContainerHost::from("b"));
+        let gen_network = |host: &ContainerHost, id: &str, name: &str| {
+            NetworkItem::new(
+                bollard::secret::Network {
+                    id: Some(id.to_owned()),
+                    name: Some(name.to_owned()),
+                    ..Default::default()
+                },
+                vec![],
+                host,
+            )
+        };
+        let names = |app_data: &AppData| {
+            app_data
+                .get_network_items()
+                .iter()
+                .map(|i| (i.name.clone(), i.host.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        app_data.update_networks(
+            &host_b,
+            vec![
+                gen_network(&host_b, "1", "bridge"),
+                gen_network(&host_b, "2", "backend"),
+            ],
+        );
+        app_data.update_networks(&host_a, vec![gen_network(&host_a, "3", "bridge")]);
+        assert_eq!(
+            names(&app_data),
+            [
+                ("backend".to_owned(), host_b.clone()),
+                ("bridge".to_owned(), host_a.clone()),
+                ("bridge".to_owned(), host_b.clone()),
+            ]
+        );
+
+        app_data.networks_end();
+        assert_eq!(app_data.get_network_title(), " 3/3");
+        app_data.update_networks(
+            &host_b,
+            vec![
+                gen_network(&host_b, "1", "bridge"),
+                gen_network(&host_b, "4", "frontend"),
+            ],
+        );
+        let selected = app_data.get_selected_network().unwrap();
+        assert_eq!((selected.id.as_str(), &selected.host), ("1", &host_b));
+
+        app_data.remove_host(&host_b);
+        assert_eq!(names(&app_data), [("bridge".to_owned(), host_a)]);
+        assert_eq!(app_data.get_selected_network().unwrap().id, "3");
+    }
+
+    #[test]
+    /// Processes are only updated for the container shown in the processes view, and the selected process is kept when sorted
+    fn test_app_data_update_processes() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let gen_process = |pid: u64, cpu: f64| ProcessItem {
+            command: format!("process_{pid}"),
+            cpu,
+            memory: 0.0,
+            pid,
+            user: "root".to_owned(),
+        };
+        let pids = |app_data: &AppData| {
+            app_data
+                .get_process_items()
+                .iter()
+                .map(|i| i.pid)
+                .collect::<Vec<_>>()
+        };
+        let processes = vec![
+            gen_process(3, 1.0),
+            gen_process(1, 5.0),
+            gen_process(2, 0.5),
+        ];
+
+        app_data.update_processes(&ids[0], processes.clone());
+        assert!(app_data.get_process_items().is_empty());
+
+        app_data.set_processes_container(Some(ids[0].clone()));
+        app_data.update_processes(&ids[1], processes.clone());
+        assert!(app_data.get_process_items().is_empty());
+
+        app_data.update_processes(&ids[0], processes);
+        assert_eq!(pids(&app_data), [1, 2, 3]);
+
+        app_data.processes_end();
+        app_data.set_process_sort_by_header(ProcessHeader::Cpu);
+        assert_eq!(pids(&app_data), [2, 3, 1]);
+        assert_eq!(
+            app_data.get_process_sorted(),
+            Some((ProcessHeader::Cpu, SortedOrder::Asc))
+        );
+        assert_eq!(app_data.get_selected_process().unwrap().pid, 3);
+
+        app_data.set_process_sort_by_header(ProcessHeader::Cpu);
+        assert_eq!(pids(&app_data), [1, 3, 2]);
+        app_data.set_process_sort_by_header(ProcessHeader::Cpu);
+        assert_eq!(pids(&app_data), [1, 2, 3]);
+        assert!(app_data.get_process_sorted().is_none());
+
+        app_data.set_processes_container(None);
+        assert!(app_data.get_process_items().is_empty());
+    }
+
     #[test]
     /// Update logs don't work if container is_oxker: true
     fn test_app_data_update_log_by_id_is_oxker() {
@@ -0,0 +1,178 @@
+use bollard::secret::{ContainerSummary, ContainerSummaryStateEnum, EndpointSettings, Network};
+
+use super::{ContainerHost, ContainerId};
+
+/// Networks created by Docker itself, which can't be removed
+const PREDEFINED: [&str; 3] = ["bridge", "host", "none"];
+
+/// A container attached to a network, with the addresses and aliases of its endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkContainer {
+    pub aliases: Vec<String>,
+    pub id: ContainerId,
+    pub ipv4: Option<String>,
+    pub ipv6: Option<String>,
+    pub name: String,
+    pub running: bool,
+}
+
+impl NetworkContainer {
+    /// Aliases includes the DNS names of the endpoint, as compose services are usually only reachable by their DNS names
+    pub fn new(
+        summary: &ContainerSummary,
+        endpoint: &EndpointSettings,
+        host: &ContainerHost,
+    ) -> Self {
+        let not_empty = |i: &Option<String>| i.as_ref().filter(|i| !i.is_empty()).cloned();
+        let mut aliases: Vec<String> = vec![];
+        for alias in endpoint
+            .aliases
+            .iter()
+            .chain(endpoint.dns_names.iter())
+            .flatten()
+        {
+            if !aliases.contains(alias) {
+                aliases.push(alias.clone());
+            }
+        }
+        Self {
+            aliases,
+            id: ContainerId::new(host, summary.id.as_deref().unwrap_or_default()),
+            ipv4: not_empty(&endpoint.ip_address),
+            ipv6: not_empty(&endpoint.global_ipv6_address),
+            name: summary
+                .names
+                .as_ref()
+                .and_then(|i| i.first())
+                .map_or_else(String::new, |i| i.trim_start_matches('/').to_owned()),
+            running: summary.state == Some(ContainerSummaryStateEnum::RUNNING),
+        }
+    }
+}
+
+/// Info for each network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkItem {
+    pub containers: Vec<NetworkContainer>,
+    pub driver: String,
+    pub gateway: String,
+    pub host: ContainerHost,
+    pub id: String,
+    pub name: String,
+    pub subnet: String,
+}
+
+impl NetworkItem {
+    /// Create a new network item, a network can have both an IPv4 and an IPv6 subnet, so they are comma separated
+    pub fn new(network: Network, containers: Vec<NetworkContainer>, host: &ContainerHost) -> Self {
+        let config = network.ipam.and_then(|i| i.config).unwrap_or_default();
+        let join = |f: fn(&bollard::secret::IpamConfig) -> Option<&String>| {
+            config
+                .iter()
+                .filter_map(f)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        Self {
+            containers,
+            driver: network.driver.unwrap_or_default(),
+            gateway: join(|i| i.gateway.as_ref()),
+            host: host.clone(),
+            id: network.id.unwrap_or_default(),
+            name: network.name.unwrap_or_default(),
+            subnet: join(|i| i.subnet.as_ref()),
+        }
+    }
+
+    /// Docker's own networks can't be removed
+    pub fn is_predefined(&self) -> bool {
+        PREDEFINED.contains(&self.name.as_str())
+    }
+
+    /// Check if a given container is attached to the network
+    pub fn contains(&self, id: &ContainerId) -> bool {
+        self.containers.iter().any(|i| &i.id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::secret::{ContainerSummary, EndpointSettings, Ipam, IpamConfig, Network};
+
+    use super::{NetworkContainer, NetworkItem};
+    use crate::app_data::{ContainerHost, ContainerId};
+
+    #[test]
+    /// Subnets and gateways, of every ipam config, are comma separated
+    fn test_network_state_new() {
+        let network = Network {
+            name: Some("backend".to_owned()),
+            id: Some("abc".to_owned()),
+            driver: Some("bridge".to_owned()),
+            ipam: Some(Ipam {
+                config: Some(vec![
+                    IpamConfig {
+                        subnet: Some("172.18.0.0/16".to_owned()),
+                        gateway: Some("172.18.0.1".to_owned()),
+                        ..Default::default()
+                    },
+                    IpamConfig {
+                        subnet: Some("fd00::/64".to_owned()),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let network = NetworkItem::new(network, vec![], &ContainerHost::default());
+        assert_eq!(network.name, "backend");
+        assert_eq!(network.driver, "bridge");
+        assert_eq!(network.subnet, "172.18.0.0/16, fd00::/64");
+        assert_eq!(network.gateway, "172.18.0.1");
+        assert!(!network.is_predefined());
+
+        let network = NetworkItem::new(
+            Network {
+                name: Some("bridge".to_owned()),
+                ..Default::default()
+            },
+            vec![],
+            &ContainerHost::default(),
+        );
+        assert!(network.is_predefined());
+        assert!(network.subnet.is_empty());
+    }
+
+    #[test]
+    /// Empty addresses are None, and aliases are combined with the DNS names, without duplicates
+    fn test_network_state_container() {
+        let summary = ContainerSummary {
+            id: Some("1".to_owned()),
+            names: Some(vec!["/container_1".to_owned()]),
+            ..Default::default()
+        };
+        let endpoint = EndpointSettings {
+            ip_address: Some("172.18.0.2".to_owned()),
+            global_ipv6_address: Some(String::new()),
+            aliases: Some(vec!["api".to_owned()]),
+            dns_names: Some(vec!["container_1".to_owned(), "api".to_owned()]),
+            ..Default::default()
+        };
+        let container = NetworkContainer::new(&summary, &endpoint, &ContainerHost::default());
+        assert_eq!(container.name, "container_1");
+        assert_eq!(container.id, ContainerId::from("1"));
+        assert_eq!(container.ipv4.as_deref(), Some("172.18.0.2"));
+        assert!(container.ipv6.is_none());
+        assert_eq!(container.aliases, ["api", "container_1"]);
+
+        let network = NetworkItem::new(
+            Network::default(),
+            vec![container],
+            &ContainerHost::default(),
+        );
+        assert!(network.contains(&ContainerId::from("1")));
+        assert!(!network.contains(&ContainerId::from("2")));
+    }
+}
@@ -0,0 +1,189 @@
+use std::cmp::Ordering;
+
+use bollard::secret::ContainerTopResponse;
+
+use super::{Signal, SortedOrder};
+
+/// The `ps` arguments used for the top endpoint, `-e` is required as otherwise only the processes of the current terminal are listed
+pub const PS_ARGS: &str = "-eo pid,user,pcpu,pmem,args";
+
+/// The columns of the processes view, that the processes can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProcessHeader {
+    Pid,
+    User,
+    Cpu,
+    Memory,
+    Command,
+}
+
+/// A single process running in a container
+/// The PID and user are those of the host, which are only the same as the PID and user inside the container if it shares the host's PID namespace
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessItem {
+    pub command: String,
+    pub cpu: f64,
+    pub memory: f64,
+    pub pid: u64,
+    pub user: String,
+}
+
+impl ProcessItem {
+    /// Parse the processes from a top response, the columns are found by their titles, as the daemon may not have used PS_ARGS
+    /// Windows daemons, for example, ignore the ps arguments, so any missing column is left empty
+    pub fn from_response(response: ContainerTopResponse) -> Vec<Self> {
+        let titles = response.titles.unwrap_or_default();
+        let position = |names: &[&str]| titles.iter().position(|i| names.contains(&i.as_str()));
+        let Some(pid) = position(&["PID"]) else {
+            return vec![];
+        };
+        let user = position(&["USER", "UID"]);
+        let cpu = position(&["%CPU", "C"]);
+        let memory = position(&["%MEM"]);
+        let command = position(&["COMMAND", "CMD", "ARGS", "Name"]);
+
+        let mut processes = response
+            .processes
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|row| {
+                let text = |index: Option<usize>| {
+                    index.and_then(|i| row.get(i)).cloned().unwrap_or_default()
+                };
+                let number = |index: Option<usize>| text(index).parse::<f64>().unwrap_or_default();
+                Some(Self {
+                    command: text(command),
+                    cpu: number(cpu),
+                    memory: number(memory),
+                    pid: text(Some(pid)).parse().ok()?,
+                    user: text(user),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        processes.sort_by_key(|i| i.pid);
+        processes
+    }
+
+    /// The command to exec in the container to send a signal to this process, `kill` expects the signal name without the SIG prefix
+    /// Only valid if the container shares the host's PID namespace, as otherwise the PID inside the container isn't the same as this PID
+    pub fn kill_command(&self, signal: Signal) -> Vec<String> {
+        vec![
+            "kill".to_owned(),
+            "-s".to_owned(),
+            signal.as_str().trim_start_matches("SIG").to_owned(),
+            self.pid.to_string(),
+        ]
+    }
+
+    /// Compare two processes by a given header, ties are always ordered by PID
+    pub fn compare(
+        &self,
+        other: &Self,
+        sorted_by: Option<(ProcessHeader, SortedOrder)>,
+    ) -> Ordering {
+        let Some((header, order)) = sorted_by else {
+            return self.pid.cmp(&other.pid);
+        };
+        let (a, b) = match order {
+            SortedOrder::Asc => (self, other),
+            SortedOrder::Desc => (other, self),
+        };
+        match header {
+            ProcessHeader::Pid => a.pid.cmp(&b.pid),
+            ProcessHeader::User => a.user.cmp(&b.user),
+            ProcessHeader::Cpu => a.cpu.total_cmp(&b.cpu),
+            ProcessHeader::Memory => a.memory.total_cmp(&b.memory),
+            ProcessHeader::Command => a.command.cmp(&b.command),
+        }
+        .then_with(|| self.pid.cmp(&other.pid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::secret::ContainerTopResponse;
+
+    use super::{ProcessHeader, ProcessItem};
+    use crate::app_data::{Signal, SortedOrder};
+
+    /// Generate a top response, in the format returned when using PS_ARGS
+    fn gen_response() -> ContainerTopResponse {
+        let row = |pid: &str, cpu: &str, command: &str| {
+            vec![
+                pid.to_owned(),
+                "root".to_owned(),
+                cpu.to_owned(),
+                "0.1".to_owned(),
+                command.to_owned(),
+            ]
+        };
+        ContainerTopResponse {
+            titles: Some(
+                ["PID", "USER", "%CPU", "%MEM", "COMMAND"]
+                    .map(ToOwned::to_owned)
+                    .to_vec(),
+            ),
+            processes: Some(vec![
+                row("1203", "12.5", "nginx: worker process"),
+                row("1100", "0.0", "nginx: master process nginx"),
+                row("1202", "3.0", "nginx: worker process"),
+                row("1305", "1.0", "nginx: worker process"),
+                row("invalid", "0.0", "sh"),
+            ]),
+        }
+    }
+
+    #[test]
+    /// Processes are parsed by column title, invalid PIDs are skipped, and processes are ordered by PID
+    fn test_process_state_from_response() {
+        let result = ProcessItem::from_response(gen_response());
+        assert_eq!(result.len(), 4);
+        assert_eq!(
+            result.iter().map(|i| i.pid).collect::<Vec<_>>(),
+            [1100, 1202, 1203, 1305]
+        );
+        assert_eq!(result[0].command, "nginx: master process nginx");
+        assert_eq!(result[2].user, "root");
+        assert!((result[2].cpu - 12.5).abs() < f64::EPSILON);
+
+        let result = ProcessItem::from_response(ContainerTopResponse {
+            titles: Some(vec!["UID".to_owned()]),
+            processes: Some(vec![vec!["root".to_owned()]]),
+        });
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    /// Processes are sorted by the given header and order, or by PID if not sorted
+    fn test_process_state_compare() {
+        let mut result = ProcessItem::from_response(gen_response());
+        result.sort_by(|a, b| a.compare(b, Some((ProcessHeader::Cpu, SortedOrder::Desc))));
+        assert_eq!(
+            result.iter().map(|i| i.pid).collect::<Vec<_>>(),
+            [1203, 1202, 1305, 1100]
+        );
+
+        result.sort_by(|a, b| a.compare(b, Some((ProcessHeader::Command, SortedOrder::Asc))));
+        assert_eq!(
+            result.iter().map(|i| i.pid).collect::<Vec<_>>(),
+            [1100, 1202, 1203, 1305]
+        );
+
+        result.sort_by(|a, b| a.compare(b, None));
+        assert_eq!(
+            result.iter().map(|i| i.pid).collect::<Vec<_>>(),
+            [1100, 1202, 1203, 1305]
+        );
+    }
+
+    #[test]
+    /// The kill command signals the PID directly, with the signal name without the SIG prefix
+    fn test_process_state_kill_command() {
+        let result = ProcessItem::from_response(gen_response());
+        assert_eq!(
+            result[0].kill_command(Signal::Hup),
+            ["kill", "-s", "HUP", "1100"]
+        );
+    }
+}
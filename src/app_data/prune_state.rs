@@ -0,0 +1,222 @@
+use std::fmt;
+
+use super::{ByteStats, ContainerHost, DiskUsageCategory, DiskUsageKind, NetworkItem};
+
+/// The five kinds of object that can be pruned, each matching the default behaviour of the relevant Docker CLI prune command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PruneKind {
+    Containers,
+    Images,
+    Networks,
+    Volumes,
+    BuildCache,
+}
+
+impl From<DiskUsageKind> for PruneKind {
+    fn from(kind: DiskUsageKind) -> Self {
+        match kind {
+            DiskUsageKind::Images => Self::Images,
+            DiskUsageKind::Containers => Self::Containers,
+            DiskUsageKind::Volumes => Self::Volumes,
+            DiskUsageKind::BuildCache => Self::BuildCache,
+        }
+    }
+}
+
+impl PruneKind {
+    /// The object type to request from the `df` endpoint, networks aren't included in the disk usage data
+    pub const fn disk_usage_type(self) -> Option<&'static str> {
+        match self {
+            Self::Containers => Some("container"),
+            Self::Images => Some("image"),
+            Self::Networks => None,
+            Self::Volumes => Some("volume"),
+            Self::BuildCache => Some("build-cache"),
+        }
+    }
+}
+
+impl fmt::Display for PruneKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let disp = match self {
+            Self::Containers => "stopped containers",
+            Self::Images => "dangling images",
+            Self::Networks => "unused networks",
+            Self::Volumes => "unused anonymous volumes",
+            Self::BuildCache => "unused build cache",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// A single object that would be removed by a prune, networks don't have a size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneItem {
+    /// The id, or name for a volume, that the object is removed by
+    pub id: String,
+    pub name: String,
+    pub size: Option<ByteStats>,
+}
+
+/// Everything that a prune would remove, on a single host, so that it can be checked before anything is actually removed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrunePreview {
+    pub host: ContainerHost,
+    pub items: Vec<PruneItem>,
+    pub kind: PruneKind,
+    pub reclaimable: Option<ByteStats>,
+}
+
+impl PrunePreview {
+    /// Only the prunable items of a disk usage category, largest first
+    pub fn from_disk_usage(category: &DiskUsageCategory) -> Self {
+        let items = category
+            .items
+            .iter()
+            .filter(|i| i.prunable)
+            .map(|i| PruneItem {
+                id: i.id.clone(),
+                name: i.name.clone(),
+                size: Some(i.size),
+            })
+            .collect::<Vec<_>>();
+        Self {
+            host: category.host.clone(),
+            reclaimable: Some(items.iter().filter_map(|i| i.size).sum()),
+            items,
+            kind: PruneKind::from(category.kind),
+        }
+    }
+
+    /// Only networks without any running containers attached, excluding Docker's own networks, sorted by name
+    /// The daemon prunes networks whose only endpoints are stopped containers, so those are included
+    pub fn from_networks(networks: &[NetworkItem], host: &ContainerHost) -> Self {
+        let mut items = networks
+            .iter()
+            .filter(|i| !i.is_predefined() && !i.containers.iter().any(|c| c.running))
+            .map(|i| PruneItem {
+                id: i.id.clone(),
+                name: i.name.clone(),
+                size: None,
+            })
+            .collect::<Vec<_>>();
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+        Self {
+            host: host.clone(),
+            items,
+            kind: PruneKind::Networks,
+            reclaimable: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::secret::{
+        ContainerSummary, ContainerSummaryStateEnum, ContainersDiskUsage, EndpointSettings,
+        Network, SystemDataUsageResponse,
+    };
+
+    use super::{PruneItem, PruneKind, PrunePreview};
+    use crate::app_data::{
+        ByteStats, ContainerHost, DiskUsageCategory, NetworkContainer, NetworkItem,
+    };
+
+    #[test]
+    /// Only stopped containers are included in the preview, and the reclaimable space is the sum of their sizes
+    fn test_prune_state_from_disk_usage() {
+        let container = |id: &str, name: &str, size: i64, state: ContainerSummaryStateEnum| {
+            serde_json::to_value(ContainerSummary {
+                id: Some(id.to_owned()),
+                names: Some(vec![format!("/{name}")]),
+                size_rw: Some(size),
+                state: Some(state),
+                ..Default::default()
+            })
+            .unwrap_or_default()
+        };
+        let response = SystemDataUsageResponse {
+            containers_disk_usage: Some(ContainersDiskUsage {
+                items: Some(vec![
+                    container("0", "running", 500, ContainerSummaryStateEnum::RUNNING),
+                    container("1", "paused", 400, ContainerSummaryStateEnum::PAUSED),
+                    container("3", "exited", 100, ContainerSummaryStateEnum::EXITED),
+                    container("2", "created", 200, ContainerSummaryStateEnum::CREATED),
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let host = ContainerHost::default();
+        let categories = DiskUsageCategory::from_response(response, &host);
+        let preview = PrunePreview::from_disk_usage(&categories[0]);
+
+        assert_eq!(preview.kind, PruneKind::Containers);
+        assert_eq!(preview.host, host);
+        assert_eq!(preview.reclaimable, Some(ByteStats::new(300)));
+        assert_eq!(
+            preview.items,
+            [
+                PruneItem {
+                    id: "2".to_owned(),
+                    name: "created".to_owned(),
+                    size: Some(ByteStats::new(200)),
+                },
+                PruneItem {
+                    id: "3".to_owned(),
+                    name: "exited".to_owned(),
+                    size: Some(ByteStats::new(100)),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    /// Networks with running containers attached, and Docker's own networks, are excluded from the preview
+    fn test_prune_state_from_networks() {
+        let host = ContainerHost::default();
+        let network = |name: &str, containers: Vec<NetworkContainer>| {
+            NetworkItem::new(
+                Network {
+                    name: Some(name.to_owned()),
+                    ..Default::default()
+                },
+                containers,
+                &host,
+            )
+        };
+        let attached = |state: ContainerSummaryStateEnum| {
+            NetworkContainer::new(
+                &ContainerSummary {
+                    id: Some("1".to_owned()),
+                    state: Some(state),
+                    ..Default::default()
+                },
+                &EndpointSettings::default(),
+                &ContainerHost::default(),
+            )
+        };
+        let networks = [
+            network("frontend", vec![]),
+            network("bridge", vec![]),
+            network(
+                "backend",
+                vec![attached(ContainerSummaryStateEnum::RUNNING)],
+            ),
+            network("cache", vec![]),
+            network("db", vec![attached(ContainerSummaryStateEnum::EXITED)]),
+        ];
+        let preview = PrunePreview::from_networks(&networks, &host);
+
+        assert_eq!(preview.kind, PruneKind::Networks);
+        assert!(preview.reclaimable.is_none());
+        assert_eq!(
+            preview
+                .items
+                .iter()
+                .map(|i| i.name.as_str())
+                .collect::<Vec<_>>(),
+            ["cache", "db", "frontend"]
+        );
+    }
+}
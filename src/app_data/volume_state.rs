@@ -0,0 +1,111 @@
+use bollard::secret::Volume;
+
+use super::{ByteStats, ContainerHost};
+
+/// Docker labels every anonymous volume with this label
+const ANONYMOUS_LABEL: &str = "com.docker.volume.anonymous";
+
+/// Info for each volume
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeItem {
+    pub anonymous: bool,
+    pub containers: Vec<String>,
+    pub driver: String,
+    pub host: ContainerHost,
+    pub mountpoint: String,
+    pub name: String,
+    pub size: Option<ByteStats>,
+}
+
+impl VolumeItem {
+    /// Create a new volume item, containers are the names of the containers, of the same host, that reference the volume
+    /// The size is only known if the daemon reports it, via the `df` endpoint
+    pub fn new(
+        volume: Volume,
+        containers: Vec<String>,
+        size: Option<ByteStats>,
+        host: &ContainerHost,
+    ) -> Self {
+        // Older daemons don't label anonymous volumes, but their names are always a 64 char hex string
+        let anonymous = volume.labels.contains_key(ANONYMOUS_LABEL)
+            || (volume.name.len() == 64 && volume.name.chars().all(|c| c.is_ascii_hexdigit()));
+        Self {
+            anonymous,
+            containers,
+            driver: volume.driver,
+            host: host.clone(),
+            mountpoint: volume.mountpoint,
+            name: volume.name,
+            size,
+        }
+    }
+
+    /// Anonymous volume names are shortened to 12 chars, as with container ids
+    pub fn get_name(&self) -> &str {
+        if self.anonymous {
+            self.name.get(..12).unwrap_or(&self.name)
+        } else {
+            &self.name
+        }
+    }
+
+    /// Comma separated names of every container that references the volume
+    pub fn get_containers(&self) -> String {
+        self.containers.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::secret::Volume;
+
+    use super::VolumeItem;
+    use crate::app_data::{ByteStats, ContainerHost};
+
+    fn gen_volume(name: &str, labels: HashMap<String, String>) -> Volume {
+        Volume {
+            name: name.to_owned(),
+            driver: "local".to_owned(),
+            mountpoint: format!("/var/lib/docker/volumes/{name}/_data"),
+            labels,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Named volumes are displayed in full, anonymous volumes are shortened
+    fn test_volume_state_name() {
+        let host = ContainerHost::default();
+        let volume = VolumeItem::new(
+            gen_volume("postgres_data", HashMap::new()),
+            vec!["postgres".to_owned(), "backup".to_owned()],
+            Some(ByteStats::new(1_000_000)),
+            &host,
+        );
+        assert!(!volume.anonymous);
+        assert_eq!(volume.get_name(), "postgres_data");
+        assert_eq!(volume.get_containers(), "postgres, backup");
+        assert_eq!(volume.driver, "local");
+
+        let name = "0123456789abcdef".repeat(4);
+        let volume = VolumeItem::new(gen_volume(&name, HashMap::new()), vec![], None, &host);
+        assert!(volume.anonymous);
+        assert_eq!(volume.get_name(), "0123456789ab");
+        assert_eq!(volume.name, name);
+
+        let volume = VolumeItem::new(
+            gen_volume(
+                "labelled",
+                HashMap::from([("com.docker.volume.anonymous".to_owned(), String::new())]),
+            ),
+            vec![],
+            None,
+            &host,
+        );
+        assert!(volume.anonymous);
+        assert_eq!(volume.get_name(), "labelled");
+        assert!(volume.get_containers().is_empty());
+    }
+}
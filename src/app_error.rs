@@ -1,13 +1,22 @@
-use crate::app_data::DockerCommand;
+use crate::app_data::{DockerCommand, PruneKind};
 use std::fmt;
 
 /// app errors to set in global state
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum AppError {
+    DockerBatch((DockerCommand, usize, Vec<String>)),
+    DockerChanges,
     DockerCommand(DockerCommand),
+    DockerCopy((&'static str, String)),
     DockerExec,
+    DockerImage(&'static str),
     DockerLogs,
     DockerConnect,
+    DockerDiskUsage,
+    DockerNetwork(&'static str),
+    DockerProcess(&'static str),
+    DockerPrune(PruneKind),
+    DockerVolume(&'static str),
     IO(String),
     MouseCapture(bool),
     Parse(String),
@@ -18,10 +27,24 @@ pub enum AppError {
 impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Self::DockerBatch((s, total, failed)) => write!(
+                f,
+                "Unable to {s} {} of {total} containers: {}",
+                failed.len(),
+                failed.join(", ")
+            ),
+            Self::DockerChanges => write!(f, "Unable to get filesystem changes"),
             Self::DockerCommand(s) => write!(f, "Unable to {s} container"),
+            Self::DockerCopy((s, e)) => write!(f, "Unable to copy {s} container: {e}"),
             Self::DockerExec => write!(f, "Unable to exec into container"),
+            Self::DockerImage(s) => write!(f, "Unable to {s}"),
             Self::DockerLogs => write!(f, "Unable to save logs"),
             Self::DockerConnect => write!(f, "Unable to access docker daemon"),
+            Self::DockerDiskUsage => write!(f, "Unable to get disk usage"),
+            Self::DockerNetwork(s) => write!(f, "Unable to {s}"),
+            Self::DockerProcess(s) => write!(f, "Unable to {s}"),
+            Self::DockerPrune(kind) => write!(f, "Unable to prune {kind}"),
+            Self::DockerVolume(s) => write!(f, "Unable to {s}"),
             Self::IO(msg) => write!(f, "IO error with: {msg}"),
             Self::MouseCapture(x) => {
                 let reason = if *x { "en" } else { "dis" };
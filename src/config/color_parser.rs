@@ -2,6 +2,8 @@ use ratatui::style::Color;
 
 static COLOR_RX: Color = Color::Rgb(255, 233, 193);
 static COLOR_TX: Color = Color::Rgb(205, 140, 140);
+static COLOR_DISK_READ: Color = Color::Rgb(193, 225, 255);
+static COLOR_DISK_WRITE: Color = Color::Rgb(190, 160, 220);
 
 /// The macro accepts a list of struct names with key names
 /// Returns a struct where every key name is an Option<String>, with the correct derived attributes
@@ -171,6 +173,37 @@ impl From<Option<ConfigColors>> for AppColors {
                 Self::map_color(cm.y_axis.as_deref(), &mut app_colors.chart_memory.y_axis);
             }
 
+            // Chart disk
+            if let Some(cd) = config_colors.chart_disk {
+                Self::map_color(
+                    cd.background.as_deref(),
+                    &mut app_colors.chart_disk.background,
+                );
+                Self::map_color(cd.border.as_deref(), &mut app_colors.chart_disk.border);
+                Self::map_color(cd.max_read.as_deref(), &mut app_colors.chart_disk.max_read);
+                Self::map_color(
+                    cd.max_write.as_deref(),
+                    &mut app_colors.chart_disk.max_write,
+                );
+                Self::map_color(
+                    cd.points_read.as_deref(),
+                    &mut app_colors.chart_disk.points_read,
+                );
+                Self::map_color(
+                    cd.points_write.as_deref(),
+                    &mut app_colors.chart_disk.points_write,
+                );
+                Self::map_color(
+                    cd.title_read.as_deref(),
+                    &mut app_colors.chart_disk.title_read,
+                );
+                Self::map_color(
+                    cd.title_write.as_deref(),
+                    &mut app_colors.chart_disk.title_write,
+                );
+                Self::map_color(cd.y_axis.as_deref(), &mut app_colors.chart_disk.y_axis);
+            }
+
             // Chart ports
             if let Some(cp) = config_colors.chart_ports {
                 Self::map_color(
@@ -189,10 +222,26 @@ impl From<Option<ConfigColors>> for AppColors {
                     c.background.as_deref(),
                     &mut app_colors.containers.background,
                 );
+                Self::map_color(
+                    c.background_marked.as_deref(),
+                    &mut app_colors.containers.background_marked,
+                );
                 Self::map_color(c.icon.as_deref(), &mut app_colors.containers.icon);
                 Self::map_color(c.text.as_deref(), &mut app_colors.containers.text);
+                Self::map_color(
+                    c.text_disconnected.as_deref(),
+                    &mut app_colors.containers.text_disconnected,
+                );
                 Self::map_color(c.text_rx.as_deref(), &mut app_colors.containers.text_rx);
                 Self::map_color(c.text_tx.as_deref(), &mut app_colors.containers.text_tx);
+                Self::map_color(
+                    c.text_disk_read.as_deref(),
+                    &mut app_colors.containers.text_disk_read,
+                );
+                Self::map_color(
+                    c.text_disk_write.as_deref(),
+                    &mut app_colors.containers.text_disk_write,
+                );
             }
 
             // Commands
@@ -207,6 +256,7 @@ impl From<Option<ConfigColors>> for AppColors {
                 Self::map_color(cc.delete.as_deref(), &mut app_colors.commands.start);
                 Self::map_color(cc.resume.as_deref(), &mut app_colors.commands.resume);
                 Self::map_color(cc.start.as_deref(), &mut app_colors.commands.start);
+                Self::map_color(cc.kill.as_deref(), &mut app_colors.commands.kill);
             }
 
             // Logs panel
@@ -255,10 +305,11 @@ optional_config_struct!(
     ConfigChartBandwidth, background, border, max_rx, max_tx, title_tx, title_rx, points_rx, points_tx, y_axis;
 
     ConfigChartCpu, background, border, order, title, max, points,y_axis;
+    ConfigChartDisk, background, border, max_read, max_write, title_read, title_write, points_read, points_write, y_axis;
     ConfigChartMemory, background, border, title, max, points, y_axis;
     ConfigChartPorts, background, border, title, headings, text;
-    ConfigCommands, background, pause, restart, stop, delete, resume, start;
-    ConfigContainers, background, icon, text, text_rx, text_tx;
+    ConfigCommands, background, pause, restart, stop, delete, resume, start, kill;
+    ConfigContainers, background, background_marked, icon, text, text_disconnected, text_rx, text_tx, text_disk_read, text_disk_write;
     ConfigContainerState, background, dead, exited, paused, removing, restarting, running_healthy, running_unhealthy, unknown;
     ConfigFilter, background, text, selected_filter_background, selected_filter_text, highlight;
      ConfigLogSearch, background, text, button_text, highlight;
@@ -271,10 +322,11 @@ config_struct!(
     ChartCpu, background, border, title, max, points, y_axis;
     ChartMemory, background, border, title, max, points, y_axis;
     ChartBandwidth, background, border, max_rx, max_tx, title_rx, title_tx, points_rx, points_tx, y_axis;
+    ChartDisk, background, border, max_read, max_write, title_read, title_write, points_read, points_write, y_axis;
 
     ChartPorts, background, border, title, headings, text;
-    Commands, background, pause, restart, stop, delete, resume, start;
-    Containers, background, icon, text, text_rx, text_tx;
+    Commands, background, pause, restart, stop, delete, resume, start, kill;
+    Containers, background, background_marked, icon, text, text_disconnected, text_rx, text_tx, text_disk_read, text_disk_write;
     ContainerState, dead, exited, paused, removing, restarting, running_healthy, running_unhealthy, unknown;
     Filter, background, text, selected_filter_background, selected_filter_text, highlight;
     LogSearch, background, text, button_text, highlight;
@@ -292,6 +344,7 @@ pub struct ConfigColors {
     chart_cpu: Option<ConfigChartCpu>,
     chart_memory: Option<ConfigChartMemory>,
     chart_bandwidth: Option<ConfigChartBandwidth>,
+    chart_disk: Option<ConfigChartDisk>,
     chart_ports: Option<ConfigChartPorts>,
     commands: Option<ConfigCommands>,
     container_state: Option<ConfigContainerState>,
@@ -339,6 +392,7 @@ impl Commands {
             delete: Color::Gray,
             resume: Color::Blue,
             start: Color::Green,
+            kill: Color::LightRed,
         }
     }
 }
@@ -360,6 +414,23 @@ impl ChartBandwidth {
     }
 }
 
+/// Default colours for the Disk I/O chart
+impl ChartDisk {
+    const fn new() -> Self {
+        Self {
+            background: Color::Reset,
+            border: Color::White,
+            max_read: COLOR_DISK_READ,
+            title_read: COLOR_DISK_READ,
+            title_write: COLOR_DISK_WRITE,
+            max_write: COLOR_DISK_WRITE,
+            points_read: COLOR_DISK_READ,
+            points_write: COLOR_DISK_WRITE,
+            y_axis: Color::White,
+        }
+    }
+}
+
 /// Default colours for the CPU chart
 impl ChartCpu {
     const fn new() -> Self {
@@ -406,10 +477,14 @@ impl Containers {
     const fn new() -> Self {
         Self {
             background: Color::Reset,
+            background_marked: Color::DarkGray,
             icon: Color::White,
             text: Color::Blue,
+            text_disconnected: Color::DarkGray,
             text_rx: COLOR_RX,
             text_tx: COLOR_TX,
+            text_disk_read: COLOR_DISK_READ,
+            text_disk_write: COLOR_DISK_WRITE,
         }
     }
 }
@@ -513,6 +588,7 @@ pub struct AppColors {
     pub chart_cpu: ChartCpu,
     pub chart_memory: ChartMemory,
     pub chart_bandwidth: ChartBandwidth,
+    pub chart_disk: ChartDisk,
     pub chart_ports: ChartPorts,
     pub commands: Commands,
     pub container_state: ContainerState,
@@ -534,6 +610,7 @@ impl AppColors {
             chart_cpu: ChartCpu::new(),
             chart_memory: ChartMemory::new(),
             chart_bandwidth: ChartBandwidth::new(),
+            chart_disk: ChartDisk::new(),
             chart_ports: ChartPorts::new(),
             commands: Commands::new(),
             container_state: ContainerState::new(),
@@ -0,0 +1,315 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::Config;
+use crate::DOCKER_HOST;
+
+const DOCKER_CONFIG: &str = "DOCKER_CONFIG";
+const DOCKER_CONTEXT: &str = "DOCKER_CONTEXT";
+const DEFAULT_CONTEXT: &str = "default";
+const CA: &str = "ca.pem";
+const CERT: &str = "cert.pem";
+const KEY: &str = "key.pem";
+
+/// The parts of `~/.docker/config.json` that are needed to find the current context
+#[derive(Debug, Deserialize)]
+struct DockerConfigFile {
+    #[serde(rename = "currentContext")]
+    current_context: Option<String>,
+}
+
+/// The parts of `~/.docker/contexts/meta/[id]/meta.json` that are needed to connect to a context
+#[derive(Debug, Deserialize)]
+struct ContextMeta {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Endpoints")]
+    endpoints: Option<ContextEndpoints>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContextEndpoints {
+    docker: Option<ContextEndpoint>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContextEndpoint {
+    #[serde(rename = "Host")]
+    host: Option<String>,
+    #[serde(rename = "SkipTLSVerify", default)]
+    skip_tls_verify: bool,
+}
+
+/// A Docker context, as created by `docker context create`
+/// The default context has no host, and so will use `DOCKER_HOST`, or the default socket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DockerContext {
+    pub name: String,
+    pub host: Option<String>,
+    skip_tls_verify: bool,
+    tls_dir: Option<PathBuf>,
+}
+
+impl DockerContext {
+    fn default_context() -> Self {
+        Self {
+            name: DEFAULT_CONTEXT.to_owned(),
+            host: None,
+            skip_tls_verify: false,
+            tls_dir: None,
+        }
+    }
+
+    /// The Docker config directory, `$DOCKER_CONFIG` or `$HOME/.docker`
+    fn config_dir() -> Option<PathBuf> {
+        std::env::var(DOCKER_CONFIG)
+            .ok()
+            .filter(|i| !i.trim().is_empty())
+            .map(PathBuf::from)
+            .or_else(|| directories::BaseDirs::new().map(|i| i.home_dir().join(".docker")))
+    }
+
+    /// Read a single context from a meta.json file, the TLS files are stored in a directory with the same id as the meta directory
+    fn read_meta(dir: &Path, meta_path: &Path) -> Option<Self> {
+        let meta = serde_json::from_slice::<ContextMeta>(&std::fs::read(meta_path).ok()?).ok()?;
+        let id = meta_path.parent()?.file_name()?;
+        let tls_dir = dir.join("contexts").join("tls").join(id).join("docker");
+        let endpoint = meta.endpoints.and_then(|i| i.docker);
+        Some(Self {
+            name: meta.name,
+            skip_tls_verify: endpoint.as_ref().is_some_and(|i| i.skip_tls_verify),
+            host: endpoint
+                .and_then(|i| i.host)
+                .filter(|i| !i.trim().is_empty()),
+            tls_dir: tls_dir.is_dir().then_some(tls_dir),
+        })
+    }
+
+    /// Every context in the given Docker config directory, sorted by name, with the default context first
+    fn list_in(dir: &Path) -> Vec<Self> {
+        let mut output = std::fs::read_dir(dir.join("contexts").join("meta"))
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .filter_map(|entry| Self::read_meta(dir, &entry.path().join("meta.json")))
+                    .filter(|i| i.name != DEFAULT_CONTEXT)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        output.sort_by(|a, b| a.name.cmp(&b.name));
+        output.insert(0, Self::default_context());
+        output
+    }
+
+    /// Find a context by name in the given Docker config directory
+    fn find_in(dir: &Path, name: &str) -> Option<Self> {
+        Self::list_in(dir).into_iter().find(|i| i.name == name)
+    }
+
+    /// The name of the current context, `$DOCKER_CONTEXT` takes priority over the `currentContext` value in config.json
+    fn current_name_in(dir: &Path) -> Option<String> {
+        std::env::var(DOCKER_CONTEXT)
+            .ok()
+            .filter(|i| !i.trim().is_empty())
+            .or_else(|| {
+                std::fs::read(dir.join("config.json"))
+                    .ok()
+                    .and_then(|i| serde_json::from_slice::<DockerConfigFile>(&i).ok())
+                    .and_then(|i| i.current_context)
+            })
+    }
+
+    /// Every context available to the current user
+    pub fn list() -> Vec<Self> {
+        Self::config_dir().map_or_else(|| vec![Self::default_context()], |dir| Self::list_in(&dir))
+    }
+
+    /// Find a context available to the current user by name
+    pub fn find(name: &str) -> Option<Self> {
+        Self::config_dir().and_then(|dir| Self::find_in(&dir, name))
+    }
+
+    /// The name of the currently active context, if one has been set
+    pub fn current_name() -> Option<String> {
+        Self::config_dir().and_then(|dir| Self::current_name_in(&dir))
+    }
+
+    /// Work out which context should be used, follows the same precedence as the Docker cli
+    /// `--host` > `--context` > `DOCKER_HOST` > `DOCKER_CONTEXT` > `currentContext` in config.json
+    pub fn resolve(config: &Config) -> Option<Self> {
+        if !config.hosts.is_empty() {
+            return None;
+        }
+        if let Some(name) = &config.context {
+            return Self::find(name);
+        }
+        if std::env::var(DOCKER_HOST).is_ok_and(|i| !i.trim().is_empty()) {
+            return None;
+        }
+        Self::current_name().and_then(|name| Self::find(&name))
+    }
+
+    /// Contexts created with `--docker skip-tls-verify=true` can't be connected to, as the https client always verifies the daemon certificate
+    /// Returns the reason, to be displayed to the user, if the context is unsupported
+    pub fn unsupported(&self) -> Option<String> {
+        self.skip_tls_verify.then(|| {
+            format!(
+                "context {} skips TLS verification, which is not supported",
+                self.name
+            )
+        })
+    }
+
+    /// Create a new config which connects to this context, TLS files found in the context replace those in the given config
+    pub fn apply(&self, config: &Config) -> Config {
+        let mut output = config.clone();
+        output.hosts = self.host.iter().cloned().collect();
+        output.context = Some(self.name.clone());
+        if let Some(tls_dir) = &self.tls_dir {
+            for (path, name) in [
+                (&mut output.tls_ca, CA),
+                (&mut output.tls_cert, CERT),
+                (&mut output.tls_key, KEY),
+            ] {
+                let file = tls_dir.join(name);
+                if file.is_file() {
+                    *path = Some(file);
+                }
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use uuid::Uuid;
+
+    use super::DockerContext;
+    use crate::tests::gen_config;
+
+    /// Create a Docker config directory, in the tmp dir, with a current context and two contexts, one of which has TLS files
+    fn gen_config_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("oxker_context_{}", Uuid::new_v4()));
+        let write = |path: &Path, content: &str| {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        };
+        write(&dir.join("config.json"), r#"{"currentContext":"remote"}"#);
+        write(
+            &dir.join("contexts/meta/abc/meta.json"),
+            r#"{"Name":"remote","Metadata":{},"Endpoints":{"docker":{"Host":"tcp://10.0.0.1:2376","SkipTLSVerify":false}}}"#,
+        );
+        write(&dir.join("contexts/tls/abc/docker/ca.pem"), "ca");
+        write(&dir.join("contexts/tls/abc/docker/cert.pem"), "cert");
+        write(&dir.join("contexts/tls/abc/docker/key.pem"), "key");
+        write(
+            &dir.join("contexts/meta/def/meta.json"),
+            r#"{"Name":"local_rootless","Metadata":{},"Endpoints":{"docker":{"Host":"unix:///run/user/1000/docker.sock"}}}"#,
+        );
+        write(
+            &dir.join("contexts/meta/jkl/meta.json"),
+            r#"{"Name":"insecure","Metadata":{},"Endpoints":{"docker":{"Host":"tcp://10.0.0.2:2376","SkipTLSVerify":true}}}"#,
+        );
+        write(&dir.join("contexts/meta/ghi/meta.json"), "not valid json");
+        dir
+    }
+
+    #[test]
+    /// Contexts are listed by name, with the default first, invalid meta files are ignored
+    fn test_docker_context_list() {
+        let dir = gen_config_dir();
+        let result = DockerContext::list_in(&dir);
+        assert_eq!(
+            result.iter().map(|i| i.name.as_str()).collect::<Vec<_>>(),
+            ["default", "insecure", "local_rootless", "remote"]
+        );
+        assert!(result[0].host.is_none());
+        assert_eq!(
+            result[2].host.as_deref(),
+            Some("unix:///run/user/1000/docker.sock")
+        );
+        assert!(result[2].tls_dir.is_none());
+        assert_eq!(result[3].host.as_deref(), Some("tcp://10.0.0.1:2376"));
+        assert_eq!(result[3].tls_dir, Some(dir.join("contexts/tls/abc/docker")));
+
+        // A missing directory still returns the default context
+        let result = DockerContext::list_in(&dir.join("missing"));
+        assert_eq!(result, [DockerContext::default_context()]);
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    /// Current context is read from config.json, and contexts can be found by name
+    fn test_docker_context_current_find() {
+        let dir = gen_config_dir();
+        assert_eq!(
+            DockerContext::current_name_in(&dir).as_deref(),
+            Some("remote")
+        );
+        assert!(DockerContext::find_in(&dir, "remote").is_some());
+        assert!(DockerContext::find_in(&dir, "default").is_some());
+        assert!(DockerContext::find_in(&dir, "unknown").is_none());
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    /// Only contexts which skip TLS verification are unsupported
+    fn test_docker_context_unsupported() {
+        let dir = gen_config_dir();
+        let result = DockerContext::find_in(&dir, "insecure")
+            .unwrap()
+            .unsupported();
+        assert_eq!(
+            result.as_deref(),
+            Some("context insecure skips TLS verification, which is not supported")
+        );
+        for name in ["default", "local_rootless", "remote"] {
+            assert!(
+                DockerContext::find_in(&dir, name)
+                    .unwrap()
+                    .unsupported()
+                    .is_none()
+            );
+        }
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    /// Applying a context sets the host, and replaces any TLS files
+    fn test_docker_context_apply() {
+        let dir = gen_config_dir();
+        let mut config = gen_config();
+        config.hosts = vec!["/var/run/docker.sock".to_owned()];
+        config.tls_key = Some(PathBuf::from("/certs/key.pem"));
+
+        let context = DockerContext::find_in(&dir, "remote").unwrap();
+        let result = context.apply(&config);
+        assert_eq!(result.hosts, ["tcp://10.0.0.1:2376"]);
+        assert_eq!(result.context.as_deref(), Some("remote"));
+        assert_eq!(
+            result.tls_ca,
+            Some(dir.join("contexts/tls/abc/docker/ca.pem"))
+        );
+        assert_eq!(
+            result.tls_cert,
+            Some(dir.join("contexts/tls/abc/docker/cert.pem"))
+        );
+        assert_eq!(
+            result.tls_key,
+            Some(dir.join("contexts/tls/abc/docker/key.pem"))
+        );
+
+        let context = DockerContext::find_in(&dir, "default").unwrap();
+        let result = context.apply(&config);
+        assert!(result.hosts.is_empty());
+        assert_eq!(result.tls_key, Some(PathBuf::from("/certs/key.pem")));
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -14,6 +14,18 @@ macro_rules! optional_config_struct {
                 )*
                 pub scroll_many: Option<Vec<String>>,
             }
+
+            #[cfg(test)]
+            impl $struct_name {
+                /// Every key given in the config file, both the primary and secondary definitions
+                pub fn keys(&self) -> Vec<String> {
+                    let mut output = vec![];
+                    $(
+                        output.extend(self.$key_name.iter().flatten().cloned());
+                    )*
+                    output
+                }
+            }
         )*
     };
 }
@@ -30,18 +42,40 @@ macro_rules! config_struct {
                 )*
                 pub scroll_many: KeyModifiers,
             }
+
+            #[cfg(test)]
+            impl $struct_name {
+                /// Every key in the keymap, both the primary and secondary definitions
+                pub fn keys(&self) -> Vec<KeyCode> {
+                    let mut output = vec![];
+                    $(
+                        output.push(self.$key_name.0);
+                        output.extend(self.$key_name.1);
+                    )*
+                    output
+                }
+            }
         )*
     };
 }
 
 optional_config_struct!(
     ConfigKeymap,
+    bulk_action,
     clear,
+    connect,
+    context_picker,
+    create,
     delete_confirm,
     delete_deny,
+    delete_force,
+    delete_volumes,
+    disk_usage,
     exec,
     filter_mode,
     force_redraw,
+    group_projects,
+    images,
     inspect,
     scroll_back,
     scroll_forward,
@@ -49,7 +83,15 @@ optional_config_struct!(
     log_section_height_decrease,
     log_section_height_increase,
     log_section_toggle,
+    mark,
+    networks,
+    processes,
+    changes,
+    copy_from,
+    copy_to,
+    prune,
     quit,
+    remove,
     save_logs,
     scroll_down,
     scroll_end,
@@ -58,35 +100,58 @@ optional_config_struct!(
     select_next_panel,
     select_previous_panel,
     sort_by_cpu,
+    sort_by_disk_read,
+    sort_by_disk_write,
+    sort_by_host,
     sort_by_id,
     sort_by_image,
     sort_by_memory,
     sort_by_name,
+    sort_by_pids,
     sort_by_rx,
     sort_by_state,
     sort_by_status,
+    sort_by_throttled,
     sort_by_tx,
     sort_reset,
     toggle_help,
-    toggle_mouse_capture
+    toggle_mouse_capture,
+    volumes
 );
 
 config_struct!(
     Keymap,
+    bulk_action,
     clear,
+    connect,
+    context_picker,
+    create,
     delete_confirm,
     delete_deny,
+    delete_force,
+    delete_volumes,
+    disk_usage,
     exec,
     filter_mode,
     inspect,
     force_redraw,
+    group_projects,
+    images,
     scroll_back,
     scroll_forward,
     log_search_mode,
     log_section_height_decrease,
     log_section_height_increase,
     log_section_toggle,
+    mark,
+    networks,
+    processes,
+    changes,
+    copy_from,
+    copy_to,
+    prune,
     quit,
+    remove,
     save_logs,
     scroll_down,
     scroll_end,
@@ -95,36 +160,59 @@ config_struct!(
     select_next_panel,
     select_previous_panel,
     sort_by_cpu,
+    sort_by_disk_read,
+    sort_by_disk_write,
+    sort_by_host,
     sort_by_id,
     sort_by_image,
     sort_by_memory,
     sort_by_name,
+    sort_by_pids,
     sort_by_rx,
     sort_by_state,
     sort_by_status,
+    sort_by_throttled,
     sort_by_tx,
     sort_reset,
     toggle_help,
-    toggle_mouse_capture
+    toggle_mouse_capture,
+    volumes
 );
 
 impl Keymap {
     pub const fn new() -> Self {
         Self {
+            bulk_action: (KeyCode::Char('b'), None),
             clear: (KeyCode::Char('c'), Some(KeyCode::Esc)),
+            connect: (KeyCode::Char('C'), None),
+            context_picker: (KeyCode::Char('x'), None),
+            create: (KeyCode::Char('a'), None),
             delete_confirm: (KeyCode::Char('y'), None),
             delete_deny: (KeyCode::Char('n'), None),
+            delete_force: (KeyCode::Char('r'), None),
+            delete_volumes: (KeyCode::Char('v'), None),
+            disk_usage: (KeyCode::Char('D'), None),
             exec: (KeyCode::Char('e'), None),
             inspect: (KeyCode::Char('i'), None),
             filter_mode: (KeyCode::Char('/'), Some(KeyCode::F(1))),
             force_redraw: (KeyCode::Char('f'), None),
+            group_projects: (KeyCode::Char('g'), None),
+            images: (KeyCode::Char('I'), None),
             scroll_back: (KeyCode::Left, None),
             scroll_forward: (KeyCode::Right, None),
             log_search_mode: (KeyCode::Char('#'), None),
             log_section_height_decrease: (KeyCode::Char('-'), None),
             log_section_height_increase: (KeyCode::Char('='), None),
             log_section_toggle: (KeyCode::Char('\\'), None),
+            mark: (KeyCode::Char(' '), None),
+            networks: (KeyCode::Char('N'), None),
+            processes: (KeyCode::Char('t'), None),
+            changes: (KeyCode::Char('F'), None),
+            copy_from: (KeyCode::Char('o'), None),
+            copy_to: (KeyCode::Char('u'), None),
+            prune: (KeyCode::Char('p'), None),
             quit: (KeyCode::Char('q'), None),
+            remove: (KeyCode::Char('d'), None),
             save_logs: (KeyCode::Char('s'), None),
             scroll_down: (KeyCode::Down, Some(KeyCode::Char('j'))),
             scroll_end: (KeyCode::End, None),
@@ -134,17 +222,23 @@ impl Keymap {
             select_next_panel: (KeyCode::Tab, None),
             select_previous_panel: (KeyCode::BackTab, None),
             sort_by_cpu: (KeyCode::Char('4'), None),
+            sort_by_disk_read: (KeyCode::Char('['), None),
+            sort_by_disk_write: (KeyCode::Char(']'), None),
+            sort_by_host: (KeyCode::Char(';'), None),
             sort_by_id: (KeyCode::Char('6'), None),
             sort_by_image: (KeyCode::Char('7'), None),
             sort_by_memory: (KeyCode::Char('5'), None),
             sort_by_name: (KeyCode::Char('1'), None),
+            sort_by_pids: (KeyCode::Char(','), None),
             sort_by_rx: (KeyCode::Char('8'), None),
             sort_by_state: (KeyCode::Char('2'), None),
             sort_by_status: (KeyCode::Char('3'), None),
+            sort_by_throttled: (KeyCode::Char('.'), None),
             sort_by_tx: (KeyCode::Char('9'), None),
             sort_reset: (KeyCode::Char('0'), None),
             toggle_help: (KeyCode::Char('h'), None),
             toggle_mouse_capture: (KeyCode::Char('m'), None),
+            volumes: (KeyCode::Char('V'), None),
         }
     }
 }
@@ -180,9 +274,22 @@ impl From<Option<ConfigKeymap>> for Keymap {
             };
 
         if let Some(ck) = value {
+            update_keymap(ck.bulk_action, &mut keymap.bulk_action, &mut clash);
             update_keymap(ck.clear, &mut keymap.clear, &mut clash);
+            update_keymap(ck.group_projects, &mut keymap.group_projects, &mut clash);
+            update_keymap(ck.images, &mut keymap.images, &mut clash);
+            update_keymap(ck.networks, &mut keymap.networks, &mut clash);
+            update_keymap(ck.create, &mut keymap.create, &mut clash);
+            update_keymap(ck.connect, &mut keymap.connect, &mut clash);
+            update_keymap(ck.disk_usage, &mut keymap.disk_usage, &mut clash);
+            update_keymap(ck.processes, &mut keymap.processes, &mut clash);
+            update_keymap(ck.changes, &mut keymap.changes, &mut clash);
+            update_keymap(ck.copy_from, &mut keymap.copy_from, &mut clash);
+            update_keymap(ck.copy_to, &mut keymap.copy_to, &mut clash);
             update_keymap(ck.delete_deny, &mut keymap.delete_deny, &mut clash);
             update_keymap(ck.delete_confirm, &mut keymap.delete_confirm, &mut clash);
+            update_keymap(ck.delete_force, &mut keymap.delete_force, &mut clash);
+            update_keymap(ck.delete_volumes, &mut keymap.delete_volumes, &mut clash);
             update_keymap(
                 ck.log_section_height_decrease,
                 &mut keymap.log_section_height_decrease,
@@ -199,10 +306,14 @@ impl From<Option<ConfigKeymap>> for Keymap {
                 &mut clash,
             );
 
+            update_keymap(ck.context_picker, &mut keymap.context_picker, &mut clash);
             update_keymap(ck.exec, &mut keymap.exec, &mut clash);
             update_keymap(ck.filter_mode, &mut keymap.filter_mode, &mut clash);
             update_keymap(ck.force_redraw, &mut keymap.force_redraw, &mut clash);
+            update_keymap(ck.mark, &mut keymap.mark, &mut clash);
+            update_keymap(ck.prune, &mut keymap.prune, &mut clash);
             update_keymap(ck.quit, &mut keymap.quit, &mut clash);
+            update_keymap(ck.remove, &mut keymap.remove, &mut clash);
             update_keymap(ck.save_logs, &mut keymap.save_logs, &mut clash);
             update_keymap(ck.scroll_down, &mut keymap.scroll_down, &mut clash);
             update_keymap(ck.scroll_end, &mut keymap.scroll_end, &mut clash);
@@ -230,6 +341,23 @@ impl From<Option<ConfigKeymap>> for Keymap {
             update_keymap(ck.sort_by_image, &mut keymap.sort_by_image, &mut clash);
             update_keymap(ck.sort_by_rx, &mut keymap.sort_by_rx, &mut clash);
             update_keymap(ck.sort_by_tx, &mut keymap.sort_by_tx, &mut clash);
+            update_keymap(
+                ck.sort_by_disk_read,
+                &mut keymap.sort_by_disk_read,
+                &mut clash,
+            );
+            update_keymap(
+                ck.sort_by_disk_write,
+                &mut keymap.sort_by_disk_write,
+                &mut clash,
+            );
+            update_keymap(ck.sort_by_host, &mut keymap.sort_by_host, &mut clash);
+            update_keymap(ck.sort_by_pids, &mut keymap.sort_by_pids, &mut clash);
+            update_keymap(
+                ck.sort_by_throttled,
+                &mut keymap.sort_by_throttled,
+                &mut clash,
+            );
             update_keymap(ck.sort_reset, &mut keymap.sort_reset, &mut clash);
             update_keymap(ck.toggle_help, &mut keymap.toggle_help, &mut clash);
             update_keymap(
@@ -237,6 +365,7 @@ impl From<Option<ConfigKeymap>> for Keymap {
                 &mut keymap.toggle_mouse_capture,
                 &mut clash,
             );
+            update_keymap(ck.volumes, &mut keymap.volumes, &mut clash);
             // TODO need to check for clashes when using additional modifiers
             if let Some(scroll_many) = Self::try_parse_modifier(ck.scroll_many) {
                 keymap.scroll_many = scroll_many;
@@ -314,6 +443,7 @@ impl Keymap {
                     "pagedown" => Some(KeyCode::PageDown),
                     "pageup" => Some(KeyCode::PageUp),
                     "right" => Some(KeyCode::Right),
+                    "space" => Some(KeyCode::Char(' ')),
                     "tab" => Some(KeyCode::Tab),
                     "up" => Some(KeyCode::Up),
                     _ => None,
@@ -344,6 +474,14 @@ mod tests {
 
     use super::Keymap;
 
+    #[test]
+    /// The default keymap can't contain any duplicate keys, as a custom keymap is checked for duplicates
+    fn test_default_no_duplicates() {
+        let keys = Keymap::new().keys();
+        let unique = keys.iter().collect::<std::collections::HashSet<_>>();
+        assert_eq!(unique.len(), keys.len());
+    }
+
     #[test]
     /// Only allow two definitions to be parsed
     fn test_return_max_two() {
@@ -388,9 +526,23 @@ mod tests {
     /// If any key definitions clash, just return the default keymap
     fn test_clash_returns_default() {
         let input = ConfigKeymap {
+            bulk_action: None,
             clear: Some(vec!["s".to_owned()]),
+            group_projects: None,
+            images: None,
+            networks: None,
+            create: None,
+            connect: None,
+            disk_usage: None,
+            processes: None,
+            changes: None,
+            copy_from: None,
+            copy_to: None,
             delete_deny: Some(vec!["s".to_owned()]),
+            context_picker: None,
             delete_confirm: None,
+            delete_force: None,
+            delete_volumes: None,
             exec: None,
             filter_mode: None,
             force_redraw: None,
@@ -401,7 +553,10 @@ mod tests {
             log_section_height_decrease: None,
             log_section_height_increase: None,
             log_section_toggle: None,
+            mark: None,
+            prune: None,
             quit: None,
+            remove: None,
             save_logs: None,
             scroll_down: None,
             scroll_end: None,
@@ -411,10 +566,15 @@ mod tests {
             select_next_panel: None,
             select_previous_panel: None,
             sort_by_cpu: None,
+            sort_by_disk_read: None,
+            sort_by_disk_write: None,
+            sort_by_host: None,
             sort_by_id: None,
             sort_by_image: None,
             sort_by_memory: None,
             sort_by_name: None,
+            sort_by_pids: None,
+            sort_by_throttled: None,
             sort_by_rx: None,
             sort_by_state: None,
             sort_by_status: None,
@@ -422,6 +582,7 @@ mod tests {
             sort_reset: None,
             toggle_help: None,
             toggle_mouse_capture: None,
+            volumes: None,
         };
 
         let result = Keymap::from(Some(input));
@@ -435,9 +596,22 @@ mod tests {
         let gen_v = |a: (&str, &str)| Some(vec![a.0.to_owned(), a.1.to_owned()]);
 
         let input = ConfigKeymap {
+            bulk_action: gen_v(("J", "K")),
+            group_projects: gen_v(("L", "M")),
+            images: gen_v(("N", "O")),
+            networks: gen_v(("V", "W")),
+            create: gen_v(("X", "Y")),
+            connect: gen_v(("Z", "#")),
+            disk_usage: gen_v(("backspace", "delete")),
+            processes: gen_v(("insert", "backtab")),
+            changes: Some(vec!["esc".to_owned()]),
+            copy_from: None,
+            copy_to: None,
             clear: gen_v(("a", "b")),
             delete_confirm: gen_v(("c", "d")),
             delete_deny: gen_v(("e", "f")),
+            delete_force: gen_v(("E", "F")),
+            delete_volumes: gen_v(("G", "H")),
             exec: gen_v(("g", "h")),
             filter_mode: gen_v(("i", "j")),
             force_redraw: gen_v(("k", "l")),
@@ -448,7 +622,10 @@ mod tests {
             log_section_height_decrease: gen_v(("m", "n")),
             log_section_height_increase: gen_v(("o", "p")),
             log_section_toggle: gen_v(("u", "v")),
+            mark: gen_v(("I", "space")),
+            prune: gen_v(("P", "Q")),
             quit: gen_v(("w", "x")),
+            remove: gen_v(("R", "S")),
             save_logs: gen_v(("y", "z")),
             scroll_down: gen_v(("3", "4")),
             scroll_end: gen_v(("5", "6")),
@@ -458,10 +635,16 @@ mod tests {
             select_next_panel: gen_v(("F3", "F4")),
             select_previous_panel: gen_v(("F5", "F6")),
             sort_by_cpu: gen_v(("F7", "F8")),
+            sort_by_disk_read: gen_v(("9", "0")),
+            sort_by_disk_write: gen_v((";", "'")),
+            sort_by_host: gen_v(("A", "B")),
+            context_picker: gen_v(("C", "D")),
             sort_by_id: gen_v(("F9", "F10")),
             sort_by_image: gen_v(("F11", "F12")),
             sort_by_memory: gen_v(("HOME", "END")),
             sort_by_name: gen_v(("UP", "DOWN")),
+            sort_by_pids: None,
+            sort_by_throttled: None,
             sort_by_rx: gen_v(("LEFT", "RIGHT")),
             sort_by_state: gen_v(("[", "]")),
             sort_by_status: gen_v(("INSERTt", "TAB")),
@@ -469,14 +652,28 @@ mod tests {
             sort_reset: gen_v((",", ".")),
             toggle_help: gen_v(("-", "=")),
             toggle_mouse_capture: gen_v(("\\", "/")),
+            volumes: gen_v(("T", "U")),
         };
 
         let result = Keymap::from(Some(input));
 
         let expected = Keymap {
+            bulk_action: (KeyCode::Char('J'), Some(KeyCode::Char('K'))),
+            group_projects: (KeyCode::Char('L'), Some(KeyCode::Char('M'))),
+            images: (KeyCode::Char('N'), Some(KeyCode::Char('O'))),
+            networks: (KeyCode::Char('V'), Some(KeyCode::Char('W'))),
+            create: (KeyCode::Char('X'), Some(KeyCode::Char('Y'))),
+            connect: (KeyCode::Char('Z'), Some(KeyCode::Char('#'))),
+            disk_usage: (KeyCode::Backspace, Some(KeyCode::Delete)),
+            processes: (KeyCode::Insert, Some(KeyCode::BackTab)),
+            changes: (KeyCode::Esc, None),
+            copy_from: (KeyCode::Char('o'), None),
+            copy_to: (KeyCode::Char('u'), None),
             clear: (KeyCode::Char('a'), Some(KeyCode::Char('b'))),
             delete_confirm: (KeyCode::Char('c'), Some(KeyCode::Char('d'))),
             delete_deny: (KeyCode::Char('e'), Some(KeyCode::Char('f'))),
+            delete_force: (KeyCode::Char('E'), Some(KeyCode::Char('F'))),
+            delete_volumes: (KeyCode::Char('G'), Some(KeyCode::Char('H'))),
             exec: (KeyCode::Char('g'), Some(KeyCode::Char('h'))),
             filter_mode: (KeyCode::Char('i'), Some(KeyCode::Char('j'))),
             force_redraw: (KeyCode::Char('k'), Some(KeyCode::Char('l'))),
@@ -487,7 +684,10 @@ mod tests {
             log_section_height_decrease: (KeyCode::Char('m'), Some(KeyCode::Char('n'))),
             log_section_height_increase: (KeyCode::Char('o'), Some(KeyCode::Char('p'))),
             log_section_toggle: (KeyCode::Char('u'), Some(KeyCode::Char('v'))),
+            mark: (KeyCode::Char('I'), Some(KeyCode::Char(' '))),
+            prune: (KeyCode::Char('P'), Some(KeyCode::Char('Q'))),
             quit: (KeyCode::Char('w'), Some(KeyCode::Char('x'))),
+            remove: (KeyCode::Char('R'), Some(KeyCode::Char('S'))),
             save_logs: (KeyCode::Char('y'), Some(KeyCode::Char('z'))),
             scroll_down: (KeyCode::Char('3'), Some(KeyCode::Char('4'))),
             scroll_end: (KeyCode::Char('5'), Some(KeyCode::Char('6'))),
@@ -497,10 +697,16 @@ mod tests {
             select_next_panel: (KeyCode::F(3), Some(KeyCode::F(4))),
             select_previous_panel: (KeyCode::F(5), Some(KeyCode::F(6))),
             sort_by_cpu: (KeyCode::F(7), Some(KeyCode::F(8))),
+            sort_by_disk_read: (KeyCode::Char('9'), Some(KeyCode::Char('0'))),
+            sort_by_disk_write: (KeyCode::Char(';'), Some(KeyCode::Char('\''))),
+            sort_by_host: (KeyCode::Char('A'), Some(KeyCode::Char('B'))),
+            context_picker: (KeyCode::Char('C'), Some(KeyCode::Char('D'))),
             sort_by_id: (KeyCode::F(9), Some(KeyCode::F(10))),
             sort_by_image: (KeyCode::F(11), Some(KeyCode::F(12))),
             sort_by_memory: (KeyCode::Home, Some(KeyCode::End)),
             sort_by_name: (KeyCode::Up, Some(KeyCode::Down)),
+            sort_by_pids: (KeyCode::Char(','), None),
+            sort_by_throttled: (KeyCode::Char('.'), None),
             sort_by_rx: (KeyCode::Left, Some(KeyCode::Right)),
             sort_by_state: (KeyCode::Char('['), Some(KeyCode::Char(']'))),
             sort_by_status: (KeyCode::Tab, None),
@@ -508,6 +714,7 @@ mod tests {
             sort_reset: (KeyCode::Char(','), Some(KeyCode::Char('.'))),
             toggle_help: (KeyCode::Char('-'), Some(KeyCode::Char('='))),
             toggle_mouse_capture: (KeyCode::Char('\\'), Some(KeyCode::Char('/'))),
+            volumes: (KeyCode::Char('T'), Some(KeyCode::Char('U'))),
         };
         assert_eq!(expected, result);
     }
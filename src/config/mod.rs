@@ -1,38 +1,101 @@
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 
 use clap::Parser;
 use jiff::tz::TimeZone;
 use parse_args::Args;
-use parse_config_file::ConfigFile;
+use parse_config_file::{ConfigFile, ConfigHostSettings};
 mod color_parser;
+mod docker_context;
 mod keymap_parser;
 
 use crate::{ENV_KEY, ENV_VALUE};
-pub use {color_parser::AppColors, keymap_parser::Keymap};
+pub use {color_parser::AppColors, docker_context::DockerContext, keymap_parser::Keymap};
 
 mod parse_args;
 mod parse_config_file;
 
+/// Minimum docker update interval, in ms, stats are streamed by Docker, so this only effects how often the containers are checked & sorted
+const MIN_DOCKER_INTERVAL: u32 = 100;
+
+/// Default Docker connection timeout, in seconds
+const DEFAULT_DOCKER_TIMEOUT: u64 = 120;
+
+/// The connector used to talk to a Docker daemon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockerScheme {
+    /// Unix socket, or Windows named pipe
+    Socket,
+    /// Unencrypted TCP
+    Http,
+    /// TCP with TLS client certificates
+    Https,
+}
+
+impl DockerScheme {
+    /// Parse a user given scheme, case insensitive, invalid schemes are ignored
+    fn parse(input: Option<String>) -> Option<Self> {
+        match input?.trim().to_lowercase().as_str() {
+            "socket" | "unix" | "npipe" => Some(Self::Socket),
+            "http" | "tcp" => Some(Self::Http),
+            "https" | "tls" => Some(Self::Https),
+            _ => None,
+        }
+    }
+}
+
+/// Connection settings of a single Docker host, any unset value falls back to the global value
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HostSettings {
+    pub docker_scheme: Option<DockerScheme>,
+    pub tls_ca: Option<PathBuf>,
+    pub tls_cert: Option<PathBuf>,
+    pub tls_key: Option<PathBuf>,
+}
+
+impl From<ConfigHostSettings> for HostSettings {
+    fn from(value: ConfigHostSettings) -> Self {
+        Self {
+            docker_scheme: DockerScheme::parse(value.docker_scheme),
+            tls_ca: value.tls_ca.map(PathBuf::from),
+            tls_cert: value.tls_cert.map(PathBuf::from),
+            tls_key: value.tls_key.map(PathBuf::from),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Config {
     pub app_colors: AppColors,
     pub color_logs: bool,
+    pub context: Option<String>,
+    pub delete_force: bool,
+    pub delete_volumes: bool,
     pub docker_interval_ms: u32,
+    pub docker_scheme: Option<DockerScheme>,
+    pub docker_timeout: u64,
     pub gui: bool,
-    pub host: Option<String>,
+    pub hosts: Vec<String>,
+    pub host_settings: HashMap<String, HostSettings>,
     pub in_container: bool,
     pub keymap: Keymap,
     pub log_search_case_sensitive: bool,
     pub raw_logs: bool,
     pub dir_config: Option<PathBuf>,
     pub dir_save: Option<PathBuf>,
+    pub show_disk_io: bool,
     pub show_logs: bool,
+    pub show_pids: bool,
     pub show_self: bool,
     pub show_std_err: bool,
+    pub show_throttling: bool,
     pub show_timestamp: bool,
+    pub stop_timeout: Option<i32>,
     pub timestamp_format: String,
     pub timezone: Option<TimeZone>,
+    pub tls_ca: Option<PathBuf>,
+    pub tls_cert: Option<PathBuf>,
+    pub tls_key: Option<PathBuf>,
     pub use_cli: bool,
 }
 
@@ -41,21 +104,34 @@ impl From<&Args> for Config {
         Self {
             app_colors: AppColors::new(),
             color_logs: args.color,
+            context: args.context.clone(),
+            delete_force: true,
+            delete_volumes: false,
             docker_interval_ms: args.docker_interval,
+            docker_scheme: DockerScheme::parse(args.scheme.clone()),
+            docker_timeout: args.timeout,
             gui: !args.gui,
-            host: args.host.clone(),
+            hosts: Self::parse_hosts(args.host.clone()),
+            host_settings: HashMap::new(),
             in_container: Self::check_if_in_container(),
             keymap: Keymap::new(),
             log_search_case_sensitive: true,
             raw_logs: args.raw,
             dir_save: Self::try_get_logs_dir(args.save_dir.as_ref()),
             dir_config: args.config_file.as_ref().map(|i| PathBuf::from(&i)),
+            show_disk_io: false,
             show_logs: true,
+            show_pids: false,
             show_self: !args.show_self,
             show_std_err: !args.no_std_err,
+            show_throttling: false,
             show_timestamp: !args.timestamp,
+            stop_timeout: None,
             timestamp_format: Self::parse_timestamp_format(None),
             timezone: Self::parse_timezone(args.timezone.clone()),
+            tls_ca: args.tls_ca.as_ref().map(PathBuf::from),
+            tls_cert: args.tls_cert.as_ref().map(PathBuf::from),
+            tls_key: args.tls_key.as_ref().map(PathBuf::from),
             use_cli: args.use_cli,
         }
     }
@@ -66,27 +142,82 @@ impl From<(ConfigFile, Option<PathBuf>)> for Config {
         Self {
             app_colors: AppColors::from(config_file.colors),
             color_logs: config_file.color_logs.unwrap_or(false),
-            docker_interval_ms: config_file.docker_interval.unwrap_or(1000),
+            context: config_file.context.filter(|i| !i.trim().is_empty()),
+            delete_force: config_file.delete_force.unwrap_or(true),
+            delete_volumes: config_file.delete_volumes.unwrap_or(false),
+            docker_interval_ms: config_file
+                .docker_interval
+                .filter(|i| *i >= MIN_DOCKER_INTERVAL)
+                .unwrap_or(1000),
+            docker_scheme: DockerScheme::parse(config_file.docker_scheme),
+            docker_timeout: config_file
+                .docker_timeout
+                .filter(|i| *i > 0)
+                .unwrap_or(DEFAULT_DOCKER_TIMEOUT),
             dir_config: dir,
             gui: config_file.gui.unwrap_or(true),
-            host: config_file.host,
+            hosts: Self::parse_hosts(
+                config_file
+                    .host
+                    .into_iter()
+                    .chain(config_file.hosts.unwrap_or_default()),
+            ),
+            host_settings: config_file
+                .host_settings
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(host, settings)| (host.trim().to_owned(), HostSettings::from(settings)))
+                .collect(),
             in_container: Self::check_if_in_container(),
             keymap: Keymap::from(config_file.keymap),
             log_search_case_sensitive: config_file.log_search_case_sensitive.unwrap_or(true),
             raw_logs: config_file.raw_logs.unwrap_or(false),
             dir_save: Self::try_get_logs_dir(config_file.save_dir.as_ref()),
+            show_disk_io: config_file.show_disk_io.unwrap_or(false),
             show_logs: config_file.show_logs.unwrap_or(true),
+            show_pids: config_file.show_pids.unwrap_or(false),
             show_self: config_file.show_self.unwrap_or(false),
             show_std_err: config_file.show_std_err.unwrap_or(true),
+            show_throttling: config_file.show_throttling.unwrap_or(false),
             show_timestamp: config_file.show_timestamp.unwrap_or(true),
+            stop_timeout: config_file.stop_timeout.filter(|i| *i >= -1),
             timestamp_format: Self::parse_timestamp_format(config_file.timestamp_format),
             timezone: Self::parse_timezone(config_file.timezone),
+            tls_ca: config_file.tls_ca.map(PathBuf::from),
+            tls_cert: config_file.tls_cert.map(PathBuf::from),
+            tls_key: config_file.tls_key.map(PathBuf::from),
             use_cli: config_file.use_cli.unwrap_or(false),
         }
     }
 }
 
 impl Config {
+    /// Remove any empty, or duplicated, hosts, whilst keeping the given order
+    fn parse_hosts(input: impl IntoIterator<Item = String>) -> Vec<String> {
+        let mut output: Vec<String> = vec![];
+        for host in input {
+            let host = host.trim();
+            if !host.is_empty() && !output.iter().any(|i| i == host) {
+                output.push(host.to_owned());
+            }
+        }
+        output
+    }
+
+    /// The connection settings of a host, a value set for the host in the config file takes priority over the global value
+    pub fn get_host_settings(&self, host: Option<&str>) -> HostSettings {
+        let settings = host
+            .and_then(|i| self.host_settings.get(i))
+            .cloned()
+            .unwrap_or_default();
+        HostSettings {
+            docker_scheme: settings.docker_scheme.or(self.docker_scheme),
+            tls_ca: settings.tls_ca.or_else(|| self.tls_ca.clone()),
+            tls_cert: settings.tls_cert.or_else(|| self.tls_cert.clone()),
+            tls_key: settings.tls_key.or_else(|| self.tls_key.clone()),
+        }
+    }
+
     /// A basic timestampt format parser, will only take 32 chars, and checks if the parsed timestamp isn't identical to the given formatter
     fn parse_timestamp_format(input: Option<String>) -> String {
         let default = || "%Y-%m-%dT%H:%M:%S.%8f".to_owned();
@@ -157,7 +288,7 @@ impl Config {
             self.docker_interval_ms = config_from_cli.docker_interval_ms;
         }
 
-        if config_from_cli.docker_interval_ms < 1000 {
+        if config_from_cli.docker_interval_ms < MIN_DOCKER_INTERVAL {
             self.docker_interval_ms = default_args.docker_interval;
         }
 
@@ -181,8 +312,32 @@ impl Config {
             self.use_cli = config_from_cli.use_cli;
         }
 
-        if let Some(host) = config_from_cli.host {
-            self.host = Some(host);
+        if !config_from_cli.hosts.is_empty() {
+            self.hosts = config_from_cli.hosts;
+        }
+
+        if config_from_cli.docker_timeout != default_args.timeout {
+            self.docker_timeout = config_from_cli.docker_timeout;
+        }
+
+        if let Some(x) = config_from_cli.context {
+            self.context = Some(x);
+        }
+
+        if let Some(x) = config_from_cli.docker_scheme {
+            self.docker_scheme = Some(x);
+        }
+
+        if let Some(x) = config_from_cli.tls_ca {
+            self.tls_ca = Some(x);
+        }
+
+        if let Some(x) = config_from_cli.tls_cert {
+            self.tls_cert = Some(x);
+        }
+
+        if let Some(x) = config_from_cli.tls_key {
+            self.tls_key = Some(x);
         }
 
         if let Some(x) = config_from_cli.dir_save {
@@ -282,4 +437,78 @@ mod tests {
             assert_eq!(result, expected);
         }
     }
+
+    #[test]
+    /// Empty and duplicated hosts are removed, order is kept
+    fn test_config_parse_hosts() {
+        let result = super::Config::parse_hosts(vec![
+            "/var/run/docker.sock".to_owned(),
+            " ".to_owned(),
+            "tcp://10.0.0.2:2375".to_owned(),
+            " /var/run/docker.sock ".to_owned(),
+        ]);
+        assert_eq!(result, ["/var/run/docker.sock", "tcp://10.0.0.2:2375"]);
+
+        assert!(super::Config::parse_hosts(vec![]).is_empty());
+    }
+
+    #[test]
+    /// Schemes are parsed case insensitive, with invalid schemes ignored
+    fn test_config_parse_docker_scheme() {
+        use super::DockerScheme;
+        for (input, expected) in [
+            ("socket", Some(DockerScheme::Socket)),
+            ("UNIX", Some(DockerScheme::Socket)),
+            ("http", Some(DockerScheme::Http)),
+            (" tcp ", Some(DockerScheme::Http)),
+            ("Https", Some(DockerScheme::Https)),
+            ("tls", Some(DockerScheme::Https)),
+            ("ssh", None),
+            ("", None),
+        ] {
+            assert_eq!(DockerScheme::parse(Some(input.to_owned())), expected);
+        }
+        assert!(DockerScheme::parse(None).is_none());
+    }
+
+    #[test]
+    /// Per host settings take priority over the global settings, unset values fall back to the global settings
+    fn test_config_get_host_settings() {
+        use super::{DockerScheme, HostSettings};
+        use std::path::PathBuf;
+
+        let mut config = crate::tests::gen_config();
+        config.tls_ca = Some(PathBuf::from("/certs/ca.pem"));
+        config.tls_key = Some(PathBuf::from("/certs/key.pem"));
+        config.host_settings.insert(
+            "tcp://10.0.0.2:2376".to_owned(),
+            HostSettings {
+                docker_scheme: Some(DockerScheme::Https),
+                tls_ca: Some(PathBuf::from("/certs/10.0.0.2/ca.pem")),
+                tls_cert: None,
+                tls_key: None,
+            },
+        );
+
+        let result = config.get_host_settings(Some("tcp://10.0.0.2:2376"));
+        assert_eq!(result.docker_scheme, Some(DockerScheme::Https));
+        assert_eq!(result.tls_ca, Some(PathBuf::from("/certs/10.0.0.2/ca.pem")));
+        assert!(result.tls_cert.is_none());
+        assert_eq!(result.tls_key, Some(PathBuf::from("/certs/key.pem")));
+
+        for host in [Some("tcp://10.0.0.3:2376"), None] {
+            let result = config.get_host_settings(host);
+            assert!(result.docker_scheme.is_none());
+            assert_eq!(result.tls_ca, Some(PathBuf::from("/certs/ca.pem")));
+        }
+    }
+
+    #[test]
+    /// A timeout of 0 is rejected by the cli parser
+    fn test_config_args_timeout() {
+        use clap::Parser;
+        assert!(super::Args::try_parse_from(["oxker", "--timeout", "0"]).is_err());
+        let result = super::Args::try_parse_from(["oxker", "--timeout", "5"]).unwrap();
+        assert_eq!(result.timeout, 5);
+    }
 }
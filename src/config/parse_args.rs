@@ -5,7 +5,7 @@ use serde::Deserialize;
 #[allow(clippy::struct_excessive_bools)]
 #[command(version, about)]
 pub struct Args {
-    /// Docker update interval in ms, minimum effectively 1000
+    /// Docker update interval in ms, minimum 100
     #[clap(short = 'd', value_name = "ms", default_value_t = 1000)]
     pub docker_interval: u32,
 
@@ -29,9 +29,33 @@ pub struct Args {
     #[clap(short = 'g')]
     pub gui: bool,
 
-    /// Docker host, defaults to `/var/run/docker.sock`
+    /// Docker host, defaults to `/var/run/docker.sock`, can be given multiple times, or as a comma separated list, to monitor multiple hosts
+    #[clap(long, short = None, value_delimiter = ',')]
+    pub host: Vec<String>,
+
+    /// Docker context to connect to, defaults to the current context, as set by `docker context use`
+    #[clap(long, short = None)]
+    pub context: Option<String>,
+
+    /// Docker connection scheme, one of `socket`, `http`, or `https`, by default inferred from the host
     #[clap(long, short = None)]
-    pub host: Option<String>,
+    pub scheme: Option<String>,
+
+    /// Docker connection timeout, in seconds, must be greater than 0
+    #[clap(long, short = None, value_name = "seconds", default_value_t = 120, value_parser = clap::value_parser!(u64).range(1..))]
+    pub timeout: u64,
+
+    /// Path to the CA certificate used for https connections, defaults to `$DOCKER_CERT_PATH/ca.pem`
+    #[clap(long = "tls-ca", short = None)]
+    pub tls_ca: Option<String>,
+
+    /// Path to the client certificate used for https connections, defaults to `$DOCKER_CERT_PATH/cert.pem`
+    #[clap(long = "tls-cert", short = None)]
+    pub tls_cert: Option<String>,
+
+    /// Path to the client key used for https connections, defaults to `$DOCKER_CERT_PATH/key.pem`
+    #[clap(long = "tls-key", short = None)]
+    pub tls_key: Option<String>,
 
     /// Do not include stderr output in logs
     #[clap(long = "no-stderr")]
@@ -63,7 +87,13 @@ impl Default for Args {
             raw: false,
             show_self: false,
             gui: true,
-            host: None,
+            host: vec![],
+            context: None,
+            scheme: None,
+            timeout: 120,
+            tls_ca: None,
+            tls_cert: None,
+            tls_key: None,
             no_std_err: true,
             timezone: None,
             save_dir: None,
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     io::{Read, Write},
     path::PathBuf,
 };
@@ -59,23 +60,46 @@ impl ConfigFileFormat {
     }
 }
 
+/// Connection settings of a single Docker host, keyed by the host in the config file
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub struct ConfigHostSettings {
+    pub docker_scheme: Option<String>,
+    pub tls_ca: Option<String>,
+    pub tls_cert: Option<String>,
+    pub tls_key: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct ConfigFile {
     pub color_logs: Option<bool>,
     pub colors: Option<ConfigColors>,
+    pub context: Option<String>,
+    pub delete_force: Option<bool>,
+    pub delete_volumes: Option<bool>,
     pub docker_interval: Option<u32>,
+    pub docker_scheme: Option<String>,
+    pub docker_timeout: Option<u64>,
     pub gui: Option<bool>,
     pub host: Option<String>,
+    pub hosts: Option<Vec<String>>,
+    pub host_settings: Option<HashMap<String, ConfigHostSettings>>,
     pub keymap: Option<ConfigKeymap>,
     pub log_search_case_sensitive: Option<bool>,
     pub raw_logs: Option<bool>,
     pub save_dir: Option<String>,
+    pub show_disk_io: Option<bool>,
     pub show_logs: Option<bool>,
+    pub show_pids: Option<bool>,
     pub show_self: Option<bool>,
     pub show_std_err: Option<bool>,
+    pub show_throttling: Option<bool>,
     pub show_timestamp: Option<bool>,
+    pub stop_timeout: Option<i32>,
     pub timestamp_format: Option<String>,
     pub timezone: Option<String>,
+    pub tls_ca: Option<String>,
+    pub tls_cert: Option<String>,
+    pub tls_key: Option<String>,
     pub use_cli: Option<bool>,
 }
 
@@ -185,6 +209,18 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    /// Per host connection settings are parsed, keyed by host
+    fn test_parse_config_host_settings() {
+        let input = r#"host_settings = { "tcp://10.0.0.2:2376" = { docker_scheme = "https", tls_ca = "/certs/ca.pem" } }"#;
+        let result = ConfigFile::parse(super::ConfigFileFormat::Toml, input).unwrap();
+        let settings = result.host_settings.unwrap();
+        let host = settings.get("tcp://10.0.0.2:2376").unwrap();
+        assert_eq!(host.docker_scheme.as_deref(), Some("https"));
+        assert_eq!(host.tls_ca.as_deref(), Some("/certs/ca.pem"));
+        assert!(host.tls_cert.is_none());
+    }
+
     #[test]
     /// make sure config.toml matches the default keymap
     fn test_parse_config_keymap_toml() {
@@ -194,6 +230,17 @@ mod tests {
         assert_eq!(Keymap::from(result.keymap), Keymap::new());
     }
 
+    #[test]
+    /// config.toml is written to disk for users to edit, a duplicate key in it would cause every custom key to be ignored
+    fn test_parse_config_keymap_toml_no_duplicates() {
+        let example_toml = include_str!("./config.toml");
+        let result = ConfigFile::parse(super::ConfigFileFormat::Toml, example_toml).unwrap();
+        let keys = result.keymap.unwrap().keys();
+        let unique = keys.iter().collect::<std::collections::HashSet<_>>();
+        assert_eq!(unique.len(), keys.len());
+        assert_eq!(keys.len(), Keymap::new().keys().len());
+    }
+
     #[test]
     /// make sure example.config.jsonc matches the default keymap
     fn test_parse_config_keymap_jsonc() {
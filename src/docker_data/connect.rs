@@ -0,0 +1,314 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bollard::{API_DEFAULT_VERSION, Docker, errors::Error};
+
+use crate::{
+    app_data::ContainerHost,
+    config::{Config, DockerScheme, HostSettings},
+};
+
+const DOCKER_CERT_PATH: &str = "DOCKER_CERT_PATH";
+const DOCKER_TLS_VERIFY: &str = "DOCKER_TLS_VERIFY";
+const XDG_RUNTIME_DIR: &str = "XDG_RUNTIME_DIR";
+const PODMAN_SOCKET: &str = "/run/podman/podman.sock";
+const DEFAULT_TCP_ADDRESS: &str = "tcp://localhost:2375";
+const CA: &str = "ca.pem";
+const CERT: &str = "cert.pem";
+const KEY: &str = "key.pem";
+
+/// Paths to the TLS files used for https connections
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsPaths {
+    pub ca: PathBuf,
+    pub cert: PathBuf,
+    pub key: PathBuf,
+}
+
+impl TlsPaths {
+    /// Use the paths from the config for the given host, any missing path defaults to a file in `$DOCKER_CERT_PATH`, or `$HOME/.docker`
+    pub fn new(host: &ContainerHost, config: &Config) -> Self {
+        Self::from_settings(&config.get_host_settings(host.get()))
+    }
+
+    fn from_settings(settings: &HostSettings) -> Self {
+        let cert_dir = std::env::var(DOCKER_CERT_PATH)
+            .ok()
+            .filter(|i| !i.trim().is_empty())
+            .map(PathBuf::from)
+            .or_else(|| directories::BaseDirs::new().map(|i| i.home_dir().join(".docker")))
+            .unwrap_or_default();
+        let get = |path: Option<&PathBuf>, name: &str| {
+            path.map_or_else(|| cert_dir.join(name), ToOwned::to_owned)
+        };
+        Self {
+            ca: get(settings.tls_ca.as_ref(), CA),
+            cert: get(settings.tls_cert.as_ref(), CERT),
+            key: get(settings.tls_key.as_ref(), KEY),
+        }
+    }
+}
+
+/// Well-known socket locations, in order of preference, to try when the default socket can't be connected to
+/// Rootless Docker, Docker Desktop, rootless Podman, and then rootful Podman
+fn socket_candidates(runtime_dir: Option<&Path>, home_dir: Option<&Path>) -> Vec<PathBuf> {
+    let mut output = vec![];
+    if let Some(dir) = runtime_dir {
+        output.push(dir.join("docker.sock"));
+    }
+    if let Some(dir) = home_dir {
+        output.push(dir.join(".docker").join("run").join("docker.sock"));
+    }
+    if let Some(dir) = runtime_dir {
+        output.push(dir.join("podman").join("podman.sock"));
+    }
+    output.push(PathBuf::from(PODMAN_SOCKET));
+    output
+}
+
+/// Every well-known socket location for the current user, in order of preference
+pub fn well_known_sockets() -> Vec<PathBuf> {
+    let runtime_dir = std::env::var(XDG_RUNTIME_DIR)
+        .ok()
+        .filter(|i| !i.trim().is_empty())
+        .map(PathBuf::from);
+    let home_dir = directories::BaseDirs::new().map(|i| i.home_dir().to_owned());
+    socket_candidates(runtime_dir.as_deref(), home_dir.as_deref())
+}
+
+/// Every well-known socket that exists on this machine, as hosts, in order of preference
+pub fn discover_sockets() -> Vec<ContainerHost> {
+    well_known_sockets()
+        .into_iter()
+        .filter(|i| i.exists())
+        .map(|i| ContainerHost::from(Some(i.display().to_string())))
+        .collect()
+}
+
+/// Work out which connector to use for a given host, a scheme set in the config takes priority over the host address
+/// tcp:// hosts will use https if any TLS file has been set in the config for the host, or if `DOCKER_TLS_VERIFY` is set, as with the Docker cli
+pub fn get_scheme(host: &ContainerHost, config: &Config) -> DockerScheme {
+    let tls_verify = std::env::var(DOCKER_TLS_VERIFY).is_ok_and(|i| !i.is_empty());
+    scheme(host, &config.get_host_settings(host.get()), tls_verify)
+}
+
+fn scheme(host: &ContainerHost, settings: &HostSettings, tls_verify: bool) -> DockerScheme {
+    if let Some(scheme) = settings.docker_scheme {
+        return scheme;
+    }
+    let has_tls = tls_verify
+        || settings.tls_ca.is_some()
+        || settings.tls_cert.is_some()
+        || settings.tls_key.is_some();
+    match host.get() {
+        Some(host) if host.starts_with("https://") => DockerScheme::Https,
+        Some(host) if host.starts_with("tcp://") && has_tls => DockerScheme::Https,
+        Some(host) if host.starts_with("tcp://") || host.starts_with("http://") => {
+            DockerScheme::Http
+        }
+        _ => DockerScheme::Socket,
+    }
+}
+
+/// Create a Docker client for a given host, using the connector & timeout from the config
+/// A host of `None` uses the local default socket, or `tcp://localhost:2375` if a tcp scheme has been set
+pub fn connect(host: &ContainerHost, config: &Config) -> Result<Docker, Error> {
+    let timeout = config.docker_timeout;
+    let addr = host.get();
+    match get_scheme(host, config) {
+        DockerScheme::Socket => addr.map_or_else(
+            || {
+                Docker::connect_with_defaults()
+                    .map(|docker| docker.with_timeout(Duration::from_secs(timeout)))
+            },
+            |addr| Docker::connect_with_socket(addr, timeout, API_DEFAULT_VERSION),
+        ),
+        DockerScheme::Http => Docker::connect_with_http(
+            addr.unwrap_or(DEFAULT_TCP_ADDRESS),
+            timeout,
+            API_DEFAULT_VERSION,
+        ),
+        DockerScheme::Https => {
+            let tls = TlsPaths::new(host, config);
+            Docker::connect_with_ssl(
+                addr.unwrap_or(DEFAULT_TCP_ADDRESS),
+                &tls.key,
+                &tls.cert,
+                &tls.ca,
+                timeout,
+                API_DEFAULT_VERSION,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::path::PathBuf;
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::{TlsPaths, connect, get_scheme, scheme, socket_candidates};
+    use crate::{
+        app_data::ContainerHost,
+        config::{DockerScheme, HostSettings},
+        tests::gen_config,
+    };
+
+    #[test]
+    /// Scheme is inferred from the host, unless set in the config
+    fn test_connect_get_scheme() {
+        let mut config = gen_config();
+        for (host, expected) in [
+            (ContainerHost::default(), DockerScheme::Socket),
+            (
+                ContainerHost::from("/var/run/docker.sock"),
+                DockerScheme::Socket,
+            ),
+            (
+                ContainerHost::from("unix:///var/run/docker.sock"),
+                DockerScheme::Socket,
+            ),
+            (
+                ContainerHost::from("tcp://127.0.0.1:2375"),
+                DockerScheme::Http,
+            ),
+            (
+                ContainerHost::from("http://127.0.0.1:2375"),
+                DockerScheme::Http,
+            ),
+            (
+                ContainerHost::from("https://127.0.0.1:2376"),
+                DockerScheme::Https,
+            ),
+        ] {
+            assert_eq!(get_scheme(&host, &config), expected);
+        }
+
+        // tcp hosts use https once a TLS file is given
+        config.tls_ca = Some(PathBuf::from("/certs/ca.pem"));
+        let host = ContainerHost::from("tcp://127.0.0.1:2376");
+        assert_eq!(get_scheme(&host, &config), DockerScheme::Https);
+
+        // Config scheme always takes priority
+        config.docker_scheme = Some(DockerScheme::Http);
+        assert_eq!(get_scheme(&host, &config), DockerScheme::Http);
+
+        // Settings for a single host take priority over the global settings
+        config.host_settings.insert(
+            "tcp://127.0.0.1:2376".to_owned(),
+            HostSettings {
+                docker_scheme: Some(DockerScheme::Https),
+                ..HostSettings::default()
+            },
+        );
+        assert_eq!(get_scheme(&host, &config), DockerScheme::Https);
+        assert_eq!(
+            get_scheme(&ContainerHost::from("tcp://127.0.0.1:2375"), &config),
+            DockerScheme::Http
+        );
+    }
+
+    #[test]
+    /// DOCKER_TLS_VERIFY makes tcp hosts use https, as with the Docker cli
+    fn test_connect_scheme_tls_verify() {
+        let host = ContainerHost::from("tcp://127.0.0.1:2376");
+        let settings = HostSettings::default();
+        assert_eq!(scheme(&host, &settings, false), DockerScheme::Http);
+        assert_eq!(scheme(&host, &settings, true), DockerScheme::Https);
+        assert_eq!(
+            scheme(&ContainerHost::default(), &settings, true),
+            DockerScheme::Socket
+        );
+    }
+
+    #[test]
+    /// Sockets are probed in order, rootless Docker first and rootful Podman last
+    fn test_connect_socket_candidates() {
+        let result = socket_candidates(
+            Some(&PathBuf::from("/run/user/1000")),
+            Some(&PathBuf::from("/home/user")),
+        );
+        assert_eq!(
+            result,
+            [
+                PathBuf::from("/run/user/1000/docker.sock"),
+                PathBuf::from("/home/user/.docker/run/docker.sock"),
+                PathBuf::from("/run/user/1000/podman/podman.sock"),
+                PathBuf::from("/run/podman/podman.sock"),
+            ]
+        );
+
+        let result = socket_candidates(None, None);
+        assert_eq!(result, [PathBuf::from("/run/podman/podman.sock")]);
+    }
+
+    #[test]
+    /// TLS paths from the config are used, missing paths use the default file names
+    fn test_connect_tls_paths() {
+        let mut config = gen_config();
+        config.tls_cert = Some(PathBuf::from("/certs/client.pem"));
+        let host = ContainerHost::from("tcp://10.0.0.2:2376");
+        let result = TlsPaths::new(&host, &config);
+        assert_eq!(result.cert, PathBuf::from("/certs/client.pem"));
+        assert!(result.ca.ends_with("ca.pem"));
+        assert!(result.key.ends_with("key.pem"));
+
+        // A file set for the host replaces the global file, for that host only
+        config.host_settings.insert(
+            "tcp://10.0.0.2:2376".to_owned(),
+            HostSettings {
+                tls_cert: Some(PathBuf::from("/certs/10.0.0.2/cert.pem")),
+                ..HostSettings::default()
+            },
+        );
+        let result = TlsPaths::new(&host, &config);
+        assert_eq!(result.cert, PathBuf::from("/certs/10.0.0.2/cert.pem"));
+        let result = TlsPaths::new(&ContainerHost::default(), &config);
+        assert_eq!(result.cert, PathBuf::from("/certs/client.pem"));
+    }
+
+    #[test]
+    /// Missing TLS files return an error rather than a client
+    fn test_connect_https_missing_certs() {
+        let mut config = gen_config();
+        config.docker_scheme = Some(DockerScheme::Https);
+        config.tls_ca = Some(PathBuf::from("/oxker/does/not/exist/ca.pem"));
+        let result = connect(&ContainerHost::from("tcp://127.0.0.1:2376"), &config);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    /// Connect to a stand-in http server, which responds to a ping, using the configured timeout
+    async fn test_connect_http_ping() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 1024];
+            let _ = stream.read(&mut buf).await.unwrap();
+            stream
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 2\r\n\r\nOK",
+                )
+                .await
+                .unwrap();
+        });
+
+        let mut config = gen_config();
+        config.docker_timeout = 5;
+        let docker = connect(
+            &ContainerHost::from(format!("tcp://{addr}").as_str()),
+            &config,
+        )
+        .unwrap();
+        assert_eq!(docker.timeout().as_secs(), 5);
+        assert_eq!(docker.ping().await.unwrap(), "OK");
+    }
+}
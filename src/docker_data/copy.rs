@@ -0,0 +1,129 @@
+use std::{
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use tar::{Archive, Builder};
+
+/// Resolve a host path entered into a prompt, a leading `~` is expanded to the home directory,
+/// and a relative path is made absolute from the directory that oxker was started in
+pub fn host_path(text: &str) -> PathBuf {
+    let path = text
+        .strip_prefix('~')
+        .filter(|i| i.chars().next().is_none_or(std::path::is_separator))
+        .and_then(|rest| {
+            directories::BaseDirs::new().map(|i| {
+                i.home_dir()
+                    .join(rest.trim_start_matches(std::path::is_separator))
+            })
+        })
+        .unwrap_or_else(|| PathBuf::from(text));
+    std::path::absolute(&path).unwrap_or(path)
+}
+
+/// Write a tar archive of a host file or directory, the archive contains a single entry, named after the final component of the path
+/// The path is canonicalized first, so that `.` & `..` still result in a named entry
+/// The archive is written as it is built, so that large files, such as heap dumps, aren't held in memory
+pub fn pack(path: &Path, writer: impl Write) -> io::Result<()> {
+    let path = path.canonicalize()?;
+    let Some(name) = path.file_name() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "path has no name",
+        ));
+    };
+    let mut builder = Builder::new(writer);
+    if path.is_dir() {
+        builder.append_dir_all(name, &path)?;
+    } else {
+        builder.append_path_with_name(&path, name)?;
+    }
+    builder.into_inner()?.flush()
+}
+
+/// Extract a tar archive into a host directory, which is created if it doesn't exist
+/// Entries that would be written outside of the directory are skipped by `Archive::unpack`
+pub fn unpack(archive: impl Read, dest: &Path) -> io::Result<()> {
+    Archive::new(archive).unpack(dest)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use uuid::Uuid;
+
+    /// Create an empty, uniquely named, directory in the system temp directory
+    fn temp_dir() -> PathBuf {
+        let path = std::env::temp_dir().join(format!("oxker_test_{}", Uuid::new_v4()));
+        fs::create_dir_all(&path).unwrap();
+        path
+    }
+
+    #[test]
+    /// A directory, and everything in it, is recreated under the destination
+    fn test_copy_pack_unpack_dir() {
+        let source = temp_dir();
+        let dest = temp_dir();
+        fs::create_dir_all(source.join("report/nested")).unwrap();
+        fs::write(source.join("report/summary.txt"), "summary").unwrap();
+        fs::write(source.join("report/nested/heap.hprof"), [0, 1, 2, 3]).unwrap();
+
+        let mut archive = vec![];
+        super::pack(&source.join("report"), &mut archive).unwrap();
+        super::unpack(archive.as_slice(), &dest).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dest.join("report/summary.txt")).unwrap(),
+            "summary"
+        );
+        assert_eq!(
+            fs::read(dest.join("report/nested/heap.hprof")).unwrap(),
+            [0, 1, 2, 3]
+        );
+
+        fs::remove_dir_all(source).unwrap();
+        fs::remove_dir_all(dest).unwrap();
+    }
+
+    #[test]
+    /// A single file is archived by its name, a path that doesn't exist is an error
+    fn test_copy_pack_unpack_file() {
+        let source = temp_dir();
+        let dest = temp_dir().join("container_1");
+        fs::write(source.join("config.toml"), "a = 1").unwrap();
+
+        let mut archive = vec![];
+        super::pack(&source.join("./config.toml"), &mut archive).unwrap();
+        super::unpack(archive.as_slice(), &dest).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dest.join("config.toml")).unwrap(),
+            "a = 1"
+        );
+        assert!(super::pack(&source.join("missing.toml"), vec![]).is_err());
+
+        fs::remove_dir_all(source).unwrap();
+        fs::remove_dir_all(dest.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    /// A leading `~` is the home directory, and a relative path is made absolute from the current directory
+    fn test_copy_host_path() {
+        let home = directories::BaseDirs::new().unwrap().home_dir().to_owned();
+        let current = std::env::current_dir().unwrap();
+
+        assert_eq!(super::host_path("~"), home);
+        assert_eq!(super::host_path("~/report.html"), home.join("report.html"));
+        assert_eq!(super::host_path("~report"), current.join("~report"));
+        assert_eq!(
+            super::host_path("reports/report.html"),
+            current.join("reports/report.html")
+        );
+        assert_eq!(
+            super::host_path("/tmp/report.html"),
+            PathBuf::from("/tmp/report.html")
+        );
+    }
+}
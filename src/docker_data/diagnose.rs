@@ -0,0 +1,366 @@
+use std::{fmt, io::ErrorKind};
+
+use bollard::errors::Error;
+
+use super::{connect, get_scheme, well_known_sockets};
+use crate::{
+    DOCKER_HOST,
+    app_data::ContainerHost,
+    config::{Config, DockerScheme},
+};
+
+#[cfg(unix)]
+const DEFAULT_SOCKET: &str = "/var/run/docker.sock";
+#[cfg(windows)]
+const DEFAULT_SOCKET: &str = "//./pipe/docker_engine";
+
+/// Environment variables that affect how oxker connects to Docker, displayed on the diagnostics screen
+const ENV_VARS: [&str; 5] = [
+    DOCKER_HOST,
+    "DOCKER_CONTEXT",
+    "DOCKER_CONFIG",
+    "DOCKER_CERT_PATH",
+    "XDG_RUNTIME_DIR",
+];
+
+/// The reason that a connection to a Docker host failed
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConnectFailure {
+    ApiVersion(String),
+    NotDocker,
+    Other(String),
+    PermissionDenied,
+    Refused,
+    SocketMissing,
+}
+
+impl ConnectFailure {
+    /// A suggestion of how to fix the failure
+    pub const fn hint(&self) -> &'static str {
+        match self {
+            Self::ApiVersion(_) => {
+                "update Docker, or oxker, so that both support the same API version"
+            }
+            Self::NotDocker => "something is listening, but it doesn't speak the Docker API",
+            Self::Other(_) => "check the host address, and any TLS settings",
+            Self::PermissionDenied => {
+                "add your user to the docker group, `sudo usermod -aG docker $USER`, then log in again"
+            }
+            Self::Refused => "nothing is listening, is the Docker daemon running?",
+            Self::SocketMissing => "is Docker installed, and the daemon running?",
+        }
+    }
+
+    /// Work out the failure from the first io::Error in the source chain, if there is one
+    fn from_io(kind: ErrorKind, text: String) -> Self {
+        match kind {
+            ErrorKind::NotFound => Self::SocketMissing,
+            ErrorKind::PermissionDenied => Self::PermissionDenied,
+            ErrorKind::ConnectionRefused => Self::Refused,
+            _ => Self::Other(text),
+        }
+    }
+
+    /// A daemon rejects a client with an unsupported API version with a 400, and a message such as
+    /// "client version 1.52 is too new. Maximum supported API version is 1.43"
+    fn is_api_version_message(status_code: u16, message: &str) -> bool {
+        let message = message.to_lowercase();
+        status_code == 400
+            && (message.contains("client version") || message.contains("api version"))
+            && (message.contains("too new")
+                || message.contains("too old")
+                || message.contains("supported api version"))
+    }
+
+    /// Categorise a bollard error, a response from the server that isn't understood means that it's not a Docker daemon
+    /// An incompatible API version is detected from the daemon rejecting the client's API version, or from the version negotiation failing to find a usable API version
+    fn from_error(err: &Error) -> Self {
+        let mut source = std::error::Error::source(err);
+        while let Some(inner) = source {
+            if let Some(io) = inner.downcast_ref::<std::io::Error>() {
+                return Self::from_io(io.kind(), io.to_string());
+            }
+            source = inner.source();
+        }
+        match err {
+            Error::SocketNotFoundError(_) => Self::SocketMissing,
+            Error::IOError { err } => Self::from_io(err.kind(), err.to_string()),
+            Error::APIVersionParseError {} => {
+                Self::ApiVersion("the daemon didn't report a valid API version".to_owned())
+            }
+            Error::DockerResponseServerError {
+                status_code,
+                message,
+            } if Self::is_api_version_message(*status_code, message) => {
+                Self::ApiVersion(message.clone())
+            }
+            Error::DockerResponseServerError { .. }
+            | Error::HyperLegacyError { .. }
+            | Error::HyperResponseError { .. }
+            | Error::JsonDataError { .. }
+            | Error::JsonSerdeError { .. } => Self::NotDocker,
+            _ => Self::Other(err.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ConnectFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ApiVersion(msg) => write!(f, "incompatible API version - {msg}"),
+            Self::NotDocker => write!(f, "not a Docker daemon"),
+            Self::Other(msg) => write!(f, "{msg}"),
+            Self::PermissionDenied => write!(f, "permission denied"),
+            Self::Refused => write!(f, "connection refused"),
+            Self::SocketMissing => write!(f, "socket not found"),
+        }
+    }
+}
+
+/// A single host that oxker tried to connect to, and why it failed
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConnectAttempt {
+    pub host: String,
+    pub failure: ConnectFailure,
+}
+
+/// Every host that was tried, and the environment variables that were read, to be displayed when unable to connect to Docker
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ConnectDiagnostics {
+    pub attempts: Vec<ConnectAttempt>,
+    pub env: Vec<(String, Option<String>)>,
+}
+
+impl ConnectDiagnostics {
+    /// Check the socket file before connecting, so that a missing socket, or a permissions issue, can be told apart
+    #[cfg(unix)]
+    fn check_socket(addr: &str) -> Option<ConnectFailure> {
+        let path = addr.trim_start_matches("unix://");
+        if let Err(e) = std::fs::metadata(path) {
+            return Some(ConnectFailure::from_io(e.kind(), e.to_string()));
+        }
+        std::os::unix::net::UnixStream::connect(path)
+            .err()
+            .map(|e| ConnectFailure::from_io(e.kind(), e.to_string()))
+    }
+
+    #[cfg(not(unix))]
+    const fn check_socket(_addr: &str) -> Option<ConnectFailure> {
+        None
+    }
+
+    /// Work out why a host can't be connected to
+    pub async fn diagnose(host: &ContainerHost, config: &Config) -> ConnectFailure {
+        if get_scheme(host, config) == DockerScheme::Socket
+            && let Some(failure) = Self::check_socket(host.get().unwrap_or(DEFAULT_SOCKET))
+        {
+            return failure;
+        }
+        let docker = match connect(host, config) {
+            Ok(docker) => docker.negotiate_version().await,
+            Err(e) => Err(e),
+        };
+        match docker {
+            Err(e) => ConnectFailure::from_error(&e),
+            Ok(docker) => match docker.ping().await {
+                Ok(response) if response.trim() == "OK" => {
+                    ConnectFailure::Other("responding now, try again".to_owned())
+                }
+                Ok(_) => ConnectFailure::NotDocker,
+                Err(e) => ConnectFailure::from_error(&e),
+            },
+        }
+    }
+
+    /// Diagnose every failed host, if the default host failed, then every well-known socket that was probed is diagnosed as well
+    pub async fn new(failed: &[ContainerHost], config: &Config) -> Self {
+        let mut attempts = vec![];
+        for host in failed {
+            attempts.push(ConnectAttempt {
+                host: host.get().unwrap_or(DEFAULT_SOCKET).to_owned(),
+                failure: Self::diagnose(host, config).await,
+            });
+            if host == &ContainerHost::default() {
+                for path in well_known_sockets() {
+                    let socket = ContainerHost::from(Some(path.display().to_string()));
+                    attempts.push(ConnectAttempt {
+                        host: path.display().to_string(),
+                        failure: Self::diagnose(&socket, config).await,
+                    });
+                }
+            }
+        }
+        let env = ENV_VARS
+            .iter()
+            .map(|i| {
+                (
+                    (*i).to_owned(),
+                    std::env::var(i).ok().filter(|i| !i.trim().is_empty()),
+                )
+            })
+            .collect();
+        Self { attempts, env }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::io::ErrorKind;
+
+    use bollard::errors::Error;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+    use uuid::Uuid;
+
+    use super::{ConnectDiagnostics, ConnectFailure};
+    use crate::{app_data::ContainerHost, tests::gen_config};
+
+    #[test]
+    /// Bollard errors are categorised by their io error, or the response from the server
+    fn test_diagnose_from_error() {
+        let err = Error::SocketNotFoundError("/var/run/docker.sock".to_owned());
+        assert_eq!(
+            ConnectFailure::from_error(&err),
+            ConnectFailure::SocketMissing
+        );
+
+        for (kind, expected) in [
+            (
+                ErrorKind::PermissionDenied,
+                ConnectFailure::PermissionDenied,
+            ),
+            (ErrorKind::ConnectionRefused, ConnectFailure::Refused),
+            (ErrorKind::NotFound, ConnectFailure::SocketMissing),
+        ] {
+            let err = Error::from(std::io::Error::from(kind));
+            assert_eq!(ConnectFailure::from_error(&err), expected);
+        }
+
+        assert_eq!(
+            ConnectFailure::from_error(&Error::APIVersionParseError {}),
+            ConnectFailure::ApiVersion("the daemon didn't report a valid API version".to_owned())
+        );
+
+        let err = Error::DockerResponseServerError {
+            status_code: 404,
+            message: "page not found".to_owned(),
+        };
+        assert_eq!(ConnectFailure::from_error(&err), ConnectFailure::NotDocker);
+
+        let message = "client version 1.12 is too old. Minimum supported API version is 1.24, please upgrade your client to a newer version";
+        let err = Error::DockerResponseServerError {
+            status_code: 400,
+            message: message.to_owned(),
+        };
+        assert_eq!(
+            ConnectFailure::from_error(&err),
+            ConnectFailure::ApiVersion(message.to_owned())
+        );
+
+        let err = Error::DockerResponseServerError {
+            status_code: 400,
+            message: "bad request".to_owned(),
+        };
+        assert_eq!(ConnectFailure::from_error(&err), ConnectFailure::NotDocker);
+
+        assert_eq!(
+            ConnectFailure::from_error(&Error::RequestTimeoutError),
+            ConnectFailure::Other("Timeout error".to_owned())
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    /// A missing socket, and a file that isn't a socket, are told apart
+    async fn test_diagnose_socket() {
+        let config = gen_config();
+        let dir = std::env::temp_dir().join(format!("oxker_diagnose_{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let missing = dir.join("missing.sock");
+        let result = ConnectDiagnostics::diagnose(
+            &ContainerHost::from(missing.display().to_string().as_str()),
+            &config,
+        )
+        .await;
+        assert_eq!(result, ConnectFailure::SocketMissing);
+
+        let listener = std::os::unix::net::UnixListener::bind(dir.join("docker.sock")).unwrap();
+        drop(listener);
+        let result = ConnectDiagnostics::diagnose(
+            &ContainerHost::from(dir.join("docker.sock").display().to_string().as_str()),
+            &config,
+        )
+        .await;
+        assert_eq!(result, ConnectFailure::Refused);
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[tokio::test]
+    /// A daemon that rejects the client's API version is an incompatible API version, not something other than Docker
+    async fn test_diagnose_api_version() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let message = "client version 1.52 is too new. Maximum supported API version is 1.43";
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buf = [0; 1024];
+                    while stream.read(&mut buf).await.is_ok_and(|i| i > 0) {
+                        let body = format!(r#"{{"message":"{message}"}}"#);
+                        let response = format!(
+                            "HTTP/1.1 400 Bad Request\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+                            body.len()
+                        );
+                        if stream.write_all(response.as_bytes()).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        let result = ConnectDiagnostics::diagnose(
+            &ContainerHost::from(format!("tcp://{addr}").as_str()),
+            &gen_config(),
+        )
+        .await;
+        assert_eq!(result, ConnectFailure::ApiVersion(message.to_owned()));
+    }
+
+    #[tokio::test]
+    /// A failed default host also diagnoses every well-known socket, and the env vars are listed
+    async fn test_diagnose_new() {
+        let config = gen_config();
+        let host = ContainerHost::from("/oxker/does/not/exist.sock");
+        let result = ConnectDiagnostics::new(&[host], &config).await;
+        assert_eq!(result.attempts.len(), 1);
+        assert_eq!(result.attempts[0].host, "/oxker/does/not/exist.sock");
+        assert_eq!(result.attempts[0].failure, ConnectFailure::SocketMissing);
+        assert_eq!(
+            result.env.iter().map(|i| i.0.as_str()).collect::<Vec<_>>(),
+            [
+                "DOCKER_HOST",
+                "DOCKER_CONTEXT",
+                "DOCKER_CONFIG",
+                "DOCKER_CERT_PATH",
+                "XDG_RUNTIME_DIR"
+            ]
+        );
+
+        let result = ConnectDiagnostics::new(&[ContainerHost::default()], &config).await;
+        assert_eq!(result.attempts.len(), 1 + super::well_known_sockets().len());
+        assert!(
+            result
+                .attempts
+                .iter()
+                .skip(1)
+                .zip(super::well_known_sockets())
+                .all(|(attempt, path)| attempt.host == path.display().to_string())
+        );
+    }
+}
@@ -0,0 +1,77 @@
+/// Container actions, received via the Docker events stream, that require the container list to be updated
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ContainerEvent {
+    Create,
+    Destroy,
+    Die,
+    HealthStatus,
+    Pause,
+    Rename,
+    Restart,
+    Start,
+    Stop,
+    Unpause,
+}
+
+impl ContainerEvent {
+    /// Parse a Docker event action, some actions are suffixed with extra information, e.g. "health_status: healthy"
+    /// Returns None for actions that don't change the container list, such as "exec_start" or "attach"
+    pub fn from_action(action: &str) -> Option<Self> {
+        match action.split(':').next().unwrap_or_default().trim() {
+            "create" => Some(Self::Create),
+            "destroy" => Some(Self::Destroy),
+            "die" => Some(Self::Die),
+            "health_status" => Some(Self::HealthStatus),
+            "pause" => Some(Self::Pause),
+            "rename" => Some(Self::Rename),
+            "restart" => Some(Self::Restart),
+            "start" => Some(Self::Start),
+            "stop" => Some(Self::Stop),
+            "unpause" => Some(Self::Unpause),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContainerEvent;
+
+    #[test]
+    /// Container actions are parsed, with any suffix ignored
+    fn test_container_event_from_action() {
+        assert_eq!(
+            ContainerEvent::from_action("create"),
+            Some(ContainerEvent::Create)
+        );
+        assert_eq!(
+            ContainerEvent::from_action("destroy"),
+            Some(ContainerEvent::Destroy)
+        );
+        assert_eq!(
+            ContainerEvent::from_action("die"),
+            Some(ContainerEvent::Die)
+        );
+        assert_eq!(
+            ContainerEvent::from_action("start"),
+            Some(ContainerEvent::Start)
+        );
+        assert_eq!(
+            ContainerEvent::from_action("health_status: healthy"),
+            Some(ContainerEvent::HealthStatus)
+        );
+        assert_eq!(
+            ContainerEvent::from_action("health_status: unhealthy"),
+            Some(ContainerEvent::HealthStatus)
+        );
+    }
+
+    #[test]
+    /// Container actions that don't effect the container list are ignored
+    fn test_container_event_from_action_ignored() {
+        assert!(ContainerEvent::from_action("exec_start: sh").is_none());
+        assert!(ContainerEvent::from_action("exec_die").is_none());
+        assert!(ContainerEvent::from_action("attach").is_none());
+        assert!(ContainerEvent::from_action("").is_none());
+    }
+}
@@ -1,14 +1,66 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
-use crate::app_data::{ContainerId, DockerCommand};
+use crate::app_data::{
+    ContainerHost, ContainerId, DockerCommand, ImageId, ProcessItem, PruneKind, PrunePreview,
+    Signal,
+};
 use bollard::Docker;
 use tokio::sync::oneshot::Sender;
 
+/// Messages sent to the DockerRouter, which are either handled by the router itself, or forwarded to the DockerData of the relevant host(s)
+#[derive(Debug)]
+pub enum RouterMessage {
+    /// Containers may belong to different hosts, so the batch is split by host, and the results merged, by the router
+    Batch((DockerCommand, Option<Signal>, Vec<ContainerId>)),
+    Context(String),
+    /// A message for the DockerData of the given host only
+    Host((ContainerHost, DockerMessage)),
+    /// A container specific message is sent to the DockerData of the host of that container, any other message to every DockerData
+    Docker(DockerMessage),
+}
+
+impl From<DockerMessage> for RouterMessage {
+    fn from(message: DockerMessage) -> Self {
+        Self::Docker(message)
+    }
+}
+
+/// Messages handled by the DockerData of a single host
 #[derive(Debug)]
 pub enum DockerMessage {
+    Changes(ContainerId),
     ConfirmDelete(ContainerId),
+    CopyFrom((ContainerId, String)),
+    CopyTo((ContainerId, PathBuf, String)),
     Control((DockerCommand, ContainerId)),
-    Exec(Sender<Arc<Docker>>),
+    DiskUsage,
+    Event,
+    Exec((ContainerId, Sender<Arc<Docker>>)),
+    HostBatch(
+        (
+            DockerCommand,
+            Option<Signal>,
+            Vec<ContainerId>,
+            Sender<Vec<ContainerId>>,
+        ),
+    ),
+    ImageInspect(ImageId),
+    ImageRemove(String),
+    Images,
     Inspect(ContainerId),
+    Kill((Signal, ContainerId)),
+    Logs,
+    NetworkConnect((String, ContainerId)),
+    NetworkCreate(String),
+    NetworkDisconnect((String, ContainerId)),
+    NetworkRemove(String),
+    Networks,
+    ProcessKill((Signal, ContainerId, ProcessItem)),
+    Processes(ContainerId),
+    Prune(PrunePreview),
+    PrunePreview(PruneKind),
+    Stop(Sender<()>),
     Update,
+    VolumeRemove(String),
+    Volumes,
 }
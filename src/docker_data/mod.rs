@@ -1,73 +1,140 @@
 use bollard::{
     Docker,
+    exec::{CreateExecOptions, StartExecResults},
     query_parameters::{
-        InspectContainerOptions, ListContainersOptions, LogsOptions, RemoveContainerOptions,
-        RestartContainerOptions, StartContainerOptions, StatsOptions, StopContainerOptions,
+        DataUsageOptions, DownloadFromContainerOptions, EventsOptions, InspectContainerOptions,
+        KillContainerOptions, ListContainersOptions, ListImagesOptions, ListNetworksOptions,
+        ListVolumesOptions, LogsOptions, PruneBuildOptions, RemoveContainerOptions,
+        RemoveImageOptions, RemoveVolumeOptions, RestartContainerOptions, StartContainerOptions,
+        StatsOptions, StopContainerOptions, TopOptions, UploadToContainerOptions,
+    },
+    secret::{
+        ContainerCpuStats, ContainerStatsResponse, NetworkConnectRequest, NetworkCreateRequest,
+        NetworkDisconnectRequest, Volume,
     },
-    secret::ContainerStatsResponse,
     service::ContainerSummary,
 };
-use futures_util::StreamExt;
+use futures_util::{StreamExt, TryStreamExt, future::join_all};
 use parking_lot::Mutex;
 use std::{
-    collections::HashSet,
-    sync::{Arc, atomic::AtomicUsize},
+    collections::{BTreeMap, HashMap, HashSet, hash_map::Entry},
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize},
+    },
+    time::{Duration, Instant},
 };
-use tokio::sync::mpsc::{Receiver, Sender};
+use tokio::{
+    sync::mpsc::{Receiver, Sender},
+    task::AbortHandle,
+};
+use tokio_util::io::{ReaderStream, StreamReader, SyncIoBridge};
 use uuid::Uuid;
 
 use crate::{
     ENTRY_POINT,
-    app_data::{AppData, ContainerId, DockerCommand, State},
+    app_data::{
+        AppData, ByteStats, COMPOSE_PROJECT, ChangesData, ContainerHost, ContainerId,
+        CpuThrottling, DiskUsageCategory, DockerCommand, ImageId, ImageItem, InspectData,
+        NetworkContainer, NetworkItem, PS_ARGS, ProcessItem, PruneKind, PrunePreview, Signal,
+        State, VolumeItem,
+    },
     app_error::AppError,
     config::Config,
-    ui::{GuiState, Status},
+    ui::{Confirm, DeleteOptions, GuiState, Status},
 };
+mod connect;
+mod copy;
+mod diagnose;
+mod events;
 mod message;
-pub use message::DockerMessage;
+mod router;
+pub use connect::{TlsPaths, connect, discover_sockets, get_scheme, well_known_sockets};
+pub use copy::host_path;
+pub use diagnose::ConnectDiagnostics;
+#[cfg(test)]
+pub use diagnose::{ConnectAttempt, ConnectFailure};
+pub use events::ContainerEvent;
+pub use message::{DockerMessage, RouterMessage};
+pub use router::DockerRouter;
+
+/// When the Docker events stream is connected, containers are only fully re-listed at this interval, as a safety net against any missed events
+const RELIST_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Calculating disk usage can be slow on hosts with many images or volumes, so whilst the disk usage view is open it's only refreshed at this interval
+const DISK_USAGE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Delay before attempting to re-subscribe to the Docker events stream
+const EVENTS_RETRY: Duration = Duration::from_secs(2);
+
+/// Delay before the first attempt to reconnect to a Docker host that has gone away, doubled after every failed attempt
+const RECONNECT_MIN: Duration = Duration::from_secs(1);
+
+/// Maximum delay between attempts to reconnect to a Docker host
+const RECONNECT_MAX: Duration = Duration::from_secs(30);
+
+/// Timeout of the ping used to check if a disconnected host is back, kept short as it blocks the message handler, unlike the configured Docker timeout
+const RECONNECT_PING_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Maximum number of log lines, that are already available from the logs stream, to insert into app_data with a single lock
+const LOG_CHUNK_SIZE: usize = 256;
+
+/// Size of the in memory pipe that a copy to a container archive is streamed through
+const COPY_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Currently running spawns, with an AbortHandle so that they can be cancelled
+type Spawns = Arc<Mutex<HashMap<SpawnId, AbortHandle>>>;
+
+/// Container events received from the Docker events stream, that are yet to be applied, only the most recent event of each container is kept
+type PendingEvents = Arc<Mutex<HashMap<ContainerId, ContainerEvent>>>;
 
+/// Stats & Log spawns are long-lived streams, at most one of each per container, a LogHistory spawn is the one-off request for the existing logs of a container
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 enum SpawnId {
-    Stats((ContainerId, Binate)),
+    Stats(ContainerId),
     Log(ContainerId),
+    LogHistory(ContainerId),
 }
 
-impl SpawnId {
-    /// Extract the &ContainerId out of self
-    const fn get_id(&self) -> &ContainerId {
-        match self {
-            Self::Log(id) | Self::Stats((id, _)) => id,
-        }
-    }
-}
-
-/// Cpu & Mem stats take twice as long as the update interval to get a value, so will have two being executed at the same time
-/// SpawnId::Stats takes container_id and binate value to enable both cycles of the same container_id to be inserted into the hashmap
-/// Binate value is toggled when all handles have been spawned off
-/// Also effectively means that the minimum docker_update interval will be 1000ms
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
-enum Binate {
-    One,
-    Two,
+/// Backoff state of a lost connection to a Docker host
+#[derive(Debug, Clone, Copy)]
+struct Reconnect {
+    delay: Duration,
+    next: Instant,
 }
 
-impl Binate {
-    const fn toggle(self) -> Self {
-        match self {
-            Self::One => Self::Two,
-            Self::Two => Self::One,
+impl Reconnect {
+    fn new() -> Self {
+        Self {
+            delay: RECONNECT_MIN,
+            next: Instant::now() + RECONNECT_MIN,
         }
     }
+
+    /// Double the delay, up to RECONNECT_MAX, before the next attempt
+    fn backoff(&mut self) {
+        self.delay = self.delay.saturating_mul(2).min(RECONNECT_MAX);
+        self.next = Instant::now() + self.delay;
+    }
 }
 
 pub struct DockerData {
     app_data: Arc<Mutex<AppData>>,
-    binate: Binate,
     config: Config,
     docker: Arc<Docker>,
+    events_connected: Arc<AtomicBool>,
     gui_state: Arc<Mutex<GuiState>>,
+    host: ContainerHost,
+    last_disk_usage: Option<Instant>,
+    last_relist: Option<Instant>,
+    pending_events: PendingEvents,
     receiver: Receiver<DockerMessage>,
-    spawns: Arc<Mutex<HashSet<SpawnId>>>,
+    reconnect: Option<Reconnect>,
+    spawns: Spawns,
+    summaries: Vec<ContainerSummary>,
+    tasks: Vec<AbortHandle>,
+    volume_sizes: HashMap<String, ByteStats>,
 }
 
 impl DockerData {
@@ -118,111 +185,165 @@ impl DockerData {
         cpu_percentage
     }
 
-    /// Get a single docker stat in order to update mem and cpu usage
-    /// don't take &self, so that can tokio::spawn into it's own thread
-    /// remove if from spawns hashmap when complete
-    /// Get a single docker stat in order to update mem and cpu usage
+    /// Use docker stats to calculate the total bytes read from, and written to, block devices
+    /// Each device has its own entry, and the op casing differs between cgroups v1 ("Read") and v2 ("read")
+    fn calculate_disk_io(stats: &ContainerStatsResponse) -> (u64, u64) {
+        stats
+            .blkio_stats
+            .as_ref()
+            .and_then(|i| i.io_service_bytes_recursive.as_ref())
+            .map_or((0, 0), |entries| {
+                entries.iter().fold((0u64, 0u64), |acc, entry| {
+                    let value = entry.value.unwrap_or_default();
+                    match entry.op.as_deref().map(str::to_lowercase).as_deref() {
+                        Some("read") => (acc.0.saturating_add(value), acc.1),
+                        Some("write") => (acc.0, acc.1.saturating_add(value)),
+                        _ => acc,
+                    }
+                })
+            })
+    }
+
+    /// Use docker stats to calculate CPU throttling, the totals come from cpu_stats, and the recent values are the difference from precpu_stats
+    /// Throttling data will only be non-zero if the container has a CPU quota, e.g. `--cpus`
+    fn calculate_throttling(stats: &ContainerStatsResponse) -> CpuThrottling {
+        let throttling = |i: Option<&ContainerCpuStats>| {
+            i.and_then(|i| i.throttling_data.as_ref())
+                .map_or((0, 0, 0), |i| {
+                    (
+                        i.periods.unwrap_or_default(),
+                        i.throttled_periods.unwrap_or_default(),
+                        i.throttled_time.unwrap_or_default(),
+                    )
+                })
+        };
+        let current = throttling(stats.cpu_stats.as_ref());
+        let previous = throttling(stats.precpu_stats.as_ref());
+        CpuThrottling {
+            throttled_periods: current.1,
+            throttled_time: current.2,
+            recent_periods: current.0.saturating_sub(previous.0),
+            recent_throttled_periods: current.1.saturating_sub(previous.1),
+        }
+    }
+
+    /// Remove a spawn_id from the spawns hashmap, but only if it belongs to the current task
+    /// A task that has been aborted may still reach this point, and its spawn_id may already have been re-used by a new task
+    fn remove_spawn(spawns: &Spawns, spawn_id: &SpawnId) {
+        let mut spawns = spawns.lock();
+        if spawns
+            .get(spawn_id)
+            .is_some_and(|i| tokio::task::try_id().is_some_and(|id| id == i.id()))
+        {
+            spawns.remove(spawn_id);
+        }
+    }
+
+    /// Subscribe to a streaming docker stat, in order to update mem, cpu, and network usage
+    /// Docker will send a new stat roughly every second, until the container stops, or is removed
     /// don't take &self, so that can tokio::spawn into it's own thread
-    /// remove if from spawns hashmap when complete
+    /// remove it from spawns hashmap when complete
     async fn update_container_stat(
         app_data: Arc<Mutex<AppData>>,
         docker: Arc<Docker>,
-        state: State,
-        spawn_id: SpawnId,
-        spawns: Arc<Mutex<HashSet<SpawnId>>>,
+        id: ContainerId,
+        spawns: Spawns,
     ) {
-        let id = spawn_id.get_id();
-        let mut stream = docker
-            .stats(
-                id.get(),
-                Some(StatsOptions {
-                    stream: false,
-                    one_shot: false,
-                }),
-            )
-            .take(1);
+        let mut stream = docker.stats(
+            id.get(),
+            Some(StatsOptions {
+                stream: true,
+                one_shot: false,
+            }),
+        );
 
         while let Some(Ok(stats)) = stream.next().await {
-            // Memory stats are only collected if the container is alive - is this the behaviour we want?
-
-            let (mem_stat, cpu_stats) = if state.is_alive() {
-                let mem_cache = stats.memory_stats.as_ref().map_or(&0, |i| {
-                    i.stats
-                        .as_ref()
-                        .map_or(&0, |i| i.get("inactive_file").unwrap_or(&0))
-                });
-                (
-                    Some(
-                        stats
-                            .memory_stats
-                            .as_ref()
-                            .map_or(0, |i| i.usage.unwrap_or_default())
-                            .saturating_sub(*mem_cache),
-                    ),
-                    Some(Self::calculate_usage(&stats)),
-                )
-            } else {
-                (None, None)
-            };
+            let mem_cache = stats.memory_stats.as_ref().map_or(&0, |i| {
+                i.stats
+                    .as_ref()
+                    .map_or(&0, |i| i.get("inactive_file").unwrap_or(&0))
+            });
+            let mem_stat = stats
+                .memory_stats
+                .as_ref()
+                .map_or(0, |i| i.usage.unwrap_or_default())
+                .saturating_sub(*mem_cache);
+            let cpu_stats = Self::calculate_usage(&stats);
 
-            // TODO is hardcoded eth0 a good idea here?
-            let (rx, tx) = stats.networks.as_ref().map_or((0, 0), |i| {
-                i.get("eth0").map_or((0, 0), |x| {
-                    (
-                        x.rx_bytes.unwrap_or_default(),
-                        x.tx_bytes.unwrap_or_default(),
-                    )
-                })
+            let networks = stats.networks.as_ref().map_or_else(BTreeMap::new, |i| {
+                i.iter()
+                    .map(|(name, x)| {
+                        (
+                            name.clone(),
+                            (
+                                x.rx_bytes.unwrap_or_default(),
+                                x.tx_bytes.unwrap_or_default(),
+                            ),
+                        )
+                    })
+                    .collect::<BTreeMap<_, _>>()
             });
 
+            let disk = Self::calculate_disk_io(&stats);
+            let throttling = Self::calculate_throttling(&stats);
+            // Windows containers don't have pids_stats, but do have num_procs
+            let pids = stats
+                .pids_stats
+                .as_ref()
+                .and_then(|i| i.current)
+                .or_else(|| stats.num_procs.map(u64::from).filter(|i| *i > 0));
+
             app_data.lock().update_stats_by_id(
-                id,
-                cpu_stats,
-                mem_stat,
+                &id,
+                Some(cpu_stats),
+                Some(mem_stat),
                 stats
                     .memory_stats
                     .unwrap_or_default()
                     .limit
                     .unwrap_or_default(),
-                rx,
-                tx,
+                networks,
+                disk,
+                pids,
+                throttling,
             );
         }
-        spawns.lock().remove(&spawn_id);
+        // The stream only ends once the container has stopped
+        app_data.lock().reset_stats_by_id(&id);
+        Self::remove_spawn(&spawns, &SpawnId::Stats(id));
+    }
+
+    /// Abort the stats stream of a container that has stopped, or been removed, and zero its stats
+    fn abort_stats(&self, id: &ContainerId) {
+        if let Some(handle) = self.spawns.lock().remove(&SpawnId::Stats(id.clone())) {
+            handle.abort();
+        }
+        self.app_data.lock().reset_stats_by_id(id);
     }
 
-    /// Update all stats, spawn each container into own tokio::spawn thread
-    fn update_all_container_stats(&mut self) {
-        let all_ids = self.app_data.lock().get_all_id_state();
+    /// Make sure every running container has a stats stream, spawn each container into own tokio::spawn thread
+    fn update_all_container_stats(&self) {
+        let all_ids = self.app_data.lock().get_all_id_state(&self.host);
         for (state, id) in all_ids {
-            let spawn_id = SpawnId::Stats((id, self.binate));
-
-            if !self.spawns.lock().contains(&spawn_id) {
-                let app_data = Arc::clone(&self.app_data);
-                let docker = Arc::clone(&self.docker);
-                let spawns = Arc::clone(&self.spawns);
-                tokio::spawn(Self::update_container_stat(
-                    app_data, docker, state, spawn_id, spawns,
+            if !state.is_alive() {
+                continue;
+            }
+            if let Entry::Vacant(entry) = self.spawns.lock().entry(SpawnId::Stats(id.clone())) {
+                let handle = tokio::spawn(Self::update_container_stat(
+                    Arc::clone(&self.app_data),
+                    Arc::clone(&self.docker),
+                    id,
+                    Arc::clone(&self.spawns),
                 ));
+                entry.insert(handle.abort_handle());
             }
         }
-        self.binate = self.binate.toggle();
     }
 
-    /// Get all current containers, handle into ContainerItem in the app_data struct rather than here
-    /// Just make sure that items sent are guaranteed to have an id
-    /// If in a containerised runtime, will ignore any container that uses the `/app/oxker` as an entry point, unless the `-s` flag is set
-    async fn update_all_containers(&self) {
-        let containers = self
-            .docker
-            .list_containers(Some(ListContainersOptions {
-                all: true,
-                ..Default::default()
-            }))
-            .await
-            .unwrap_or_default();
-
-        let output = containers
+    /// Remove the oxker container, if running in a containerised runtime, unless the `-s` flag is set
+    /// Also make sure that all items are guaranteed to have an id
+    fn remove_self(&self, containers: Vec<ContainerSummary>) -> Vec<ContainerSummary> {
+        containers
             .into_iter()
             .filter_map(|f| match f.id {
                 Some(_) => {
@@ -239,69 +360,1035 @@ impl DockerData {
                 }
                 None => None,
             })
-            .collect::<Vec<ContainerSummary>>();
-        self.app_data.lock().update_containers(output);
+            .collect::<Vec<ContainerSummary>>()
     }
 
-    /// Update single container logs
-    /// remove it from spawns hashmap when complete
-    async fn update_log(
-        app_data: Arc<Mutex<AppData>>,
+    /// Get all current containers, handle into ContainerItem in the app_data struct rather than here
+    /// If in a containerised runtime, will ignore any container that uses the `/app/oxker` as an entry point, unless the `-s` flag is set
+    /// An error means the connection to the host has been lost, so the last known containers are kept
+    /// The unfiltered summaries are also kept, so that the images view can count the containers using each image without re-listing every container
+    async fn update_all_containers(&mut self) {
+        let Ok(containers) = self
+            .docker
+            .list_containers(Some(ListContainersOptions {
+                all: true,
+                ..Default::default()
+            }))
+            .await
+        else {
+            self.set_disconnected();
+            return;
+        };
+
+        self.summaries.clone_from(&containers);
+        let output = self.remove_self(containers);
+        self.app_data.lock().update_containers(&self.host, output);
+        self.last_relist = Some(Instant::now());
+    }
+
+    /// Get all images, the number of containers using each image is counted from the last known container summaries
+    /// Only called when the images view is open, as with containers an error means the connection to the host has been lost
+    async fn update_images(&mut self) {
+        let Ok(images) = self
+            .docker
+            .list_images(Some(ListImagesOptions::default()))
+            .await
+        else {
+            self.set_disconnected();
+            return;
+        };
+
+        let mut used_by = HashMap::new();
+        for image_id in self.summaries.iter().filter_map(|i| i.image_id.as_deref()) {
+            *used_by.entry(image_id).or_insert(0) += 1;
+        }
+        let images = images
+            .into_iter()
+            .map(|i| {
+                let containers = used_by.get(i.id.as_str()).copied().unwrap_or_default();
+                ImageItem::new(i, containers, &self.host)
+            })
+            .collect();
+        self.app_data.lock().update_images(&self.host, images);
+    }
+
+    /// Inspect an image, and then show the inspect view
+    async fn inspect_image(&self, id: ImageId) {
+        if let Ok(image) = self.docker.inspect_image(id.get()).await {
+            self.app_data
+                .lock()
+                .set_inspect_data(InspectData::from(image));
+            self.gui_state.lock().status_push(Status::Inspect);
+        } else {
+            self.app_data.lock().set_error(
+                AppError::DockerImage("inspect image"),
+                &self.gui_state,
+                Status::Error,
+            );
+        }
+    }
+
+    /// Get the filesystem changes of a container, relative to its image, and then show the changes view
+    async fn changes(&self, id: ContainerId) {
+        if let Ok(changes) = self.docker.container_changes(id.get()).await {
+            let name = self
+                .app_data
+                .lock()
+                .get_container_name_by_id(&id)
+                .map(|i| i.get().to_owned())
+                .unwrap_or_default();
+            self.app_data.lock().set_changes_data(ChangesData::new(
+                changes.unwrap_or_default(),
+                name,
+                &id,
+            ));
+            self.gui_state.lock().status_push(Status::Changes);
+        } else {
+            self.app_data
+                .lock()
+                .set_error(AppError::DockerChanges, &self.gui_state, Status::Error);
+        }
+    }
+
+    /// Download a path from a container, as a tar archive, and extract it into `[dir_save]/[container_name]`
+    /// The archive is streamed straight to disk, so that large files, such as heap dumps, aren't held in memory
+    async fn download(
+        docker: &Docker,
+        app_data: &Arc<Mutex<AppData>>,
+        id: &ContainerId,
+        path: String,
+    ) -> Result<PathBuf, std::io::Error> {
+        let (dir_save, name) = {
+            let mut app_data = app_data.lock();
+            (
+                app_data.config.dir_save.clone(),
+                app_data
+                    .get_container_name_by_id(id)
+                    .map_or_else(|| id.get_short(), |i| i.get().to_owned()),
+            )
+        };
+        let Some(dir_save) = dir_save else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no save directory",
+            ));
+        };
+        let dest = dir_save.join(name);
+        let archive = docker
+            .download_from_container(id.get(), Some(DownloadFromContainerOptions { path }))
+            .map_err(std::io::Error::other);
+        let reader = SyncIoBridge::new(StreamReader::new(Box::pin(archive)));
+        tokio::task::spawn_blocking(move || copy::unpack(reader, &dest).map(|()| dest)).await?
+    }
+
+    /// Copy a path from a container to the host, on it's own tokio thread
+    fn copy_from(&self, id: ContainerId, path: String) {
+        let (app_data, docker, gui_state) = (
+            Arc::clone(&self.app_data),
+            Arc::clone(&self.docker),
+            Arc::clone(&self.gui_state),
+        );
+        tokio::spawn(async move {
+            let uuid = Uuid::new_v4();
+            GuiState::start_loading_animation(&gui_state, uuid);
+            match Self::download(&docker, &app_data, &id, path.clone()).await {
+                Ok(dest) => gui_state
+                    .lock()
+                    .set_info_box(&format!("✓ copied {path} to {}", dest.display())),
+                Err(e) => app_data.lock().set_error(
+                    AppError::DockerCopy(("from", e.to_string())),
+                    &gui_state,
+                    Status::Error,
+                ),
+            }
+            gui_state.lock().stop_loading_animation(uuid);
+        });
+    }
+
+    /// Upload a host file or directory, as a tar archive, into a directory in a container
+    /// The archive is streamed to the daemon as it is built, so that large files, such as heap dumps, aren't held in memory,
+    /// if the archive can't be completed the upload is aborted, so that a partial archive is never extracted
+    async fn upload(
+        docker: &Docker,
+        id: &ContainerId,
+        source: PathBuf,
+        dir: String,
+    ) -> Result<(), std::io::Error> {
+        let (writer, reader) = tokio::io::duplex(COPY_BUFFER_SIZE);
+        let writer = SyncIoBridge::new(writer);
+        let (packed_tx, packed_rx) = tokio::sync::oneshot::channel();
+        let packing = tokio::task::spawn_blocking(move || {
+            let packed = copy::pack(&source, writer);
+            packed_tx.send(packed.is_ok()).ok();
+            packed
+        });
+        let aborted = futures_util::stream::once(async move {
+            match packed_rx.await {
+                Ok(true) => None,
+                _ => Some(Err(std::io::Error::other("archive incomplete"))),
+            }
+        })
+        .filter_map(std::future::ready);
+        let body = ReaderStream::new(reader).chain(aborted);
+        let upload = docker.upload_to_container(
+            id.get(),
+            Some(UploadToContainerOptions {
+                path: dir,
+                ..Default::default()
+            }),
+            bollard::body_try_stream(body),
+        );
+        let (packed, uploaded) = tokio::join!(packing, upload);
+        // A failed upload stops reading the archive, leaving the packing to fail with a broken pipe, so the upload error is the cause
+        match (packed?, uploaded) {
+            (Ok(()), Ok(())) => Ok(()),
+            (Err(e), _) if e.kind() != std::io::ErrorKind::BrokenPipe => Err(e),
+            (_, Err(e)) => Err(std::io::Error::other(e)),
+            (Err(e), Ok(())) => Err(e),
+        }
+    }
+
+    /// Copy a host file or directory into a directory in a container, on it's own tokio thread
+    /// The directory must already exist in the container
+    fn copy_to(&self, id: ContainerId, source: PathBuf, dir: String) {
+        let (app_data, docker, gui_state) = (
+            Arc::clone(&self.app_data),
+            Arc::clone(&self.docker),
+            Arc::clone(&self.gui_state),
+        );
+        tokio::spawn(async move {
+            let uuid = Uuid::new_v4();
+            GuiState::start_loading_animation(&gui_state, uuid);
+            match Self::upload(&docker, &id, source.clone(), dir.clone()).await {
+                Ok(()) => gui_state
+                    .lock()
+                    .set_info_box(&format!("✓ copied {} to {dir}", source.display())),
+                Err(e) => app_data.lock().set_error(
+                    AppError::DockerCopy(("to", e.to_string())),
+                    &gui_state,
+                    Status::Error,
+                ),
+            }
+            gui_state.lock().stop_loading_animation(uuid);
+        });
+    }
+
+    /// Remove an image by the given reference, as with the Docker CLI, removing a repo:tag of an image with several tags only removes that tag
+    /// This will fail if the image is used by any container, even a stopped one
+    async fn remove_image(&mut self, reference: String) {
+        let uuid = Uuid::new_v4();
+        GuiState::start_loading_animation(&self.gui_state, uuid);
+        if self
+            .docker
+            .remove_image(&reference, None::<RemoveImageOptions>, None)
+            .await
+            .is_err()
+        {
+            self.app_data.lock().set_error(
+                AppError::DockerImage("remove image"),
+                &self.gui_state,
+                Status::Error,
+            );
+        }
+        self.update_images().await;
+        self.gui_state.lock().stop_loading_animation(uuid);
+    }
+
+    /// Get the size of every volume, via the `df` endpoint, the size is only included when `verbose` is set
+    /// Calculating the sizes can be slow, so the last known sizes are re-used when the volumes are updated on each heartbeat
+    async fn update_volume_sizes(&mut self) {
+        if let Ok(usage) = self
+            .docker
+            .df(Some(DataUsageOptions {
+                _type: Some(vec!["volume".to_owned()]),
+                verbose: true,
+            }))
+            .await
+        {
+            self.volume_sizes = usage
+                .volumes_disk_usage
+                .and_then(|i| i.items)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|i| serde_json::from_value::<Volume>(i).ok())
+                .filter_map(|i| {
+                    // The daemon reports a size of -1 if it is unable to calculate it
+                    let size = u64::try_from(i.usage_data?.size).ok()?;
+                    Some((i.name, ByteStats::new(size)))
+                })
+                .collect();
+        }
+    }
+
+    /// Get the disk usage of every image, container, volume, and build cache record, via the `df` endpoint
+    /// An error isn't treated as a lost connection, as the daemon can fail to calculate usage whilst still being reachable
+    /// When refreshed by the heartbeat, an error keeps the last known usage and only shows an info box, rather than an error popup every interval
+    async fn update_disk_usage(&mut self, heartbeat: bool) {
+        self.last_disk_usage = Some(Instant::now());
+        match self
+            .docker
+            .df(Some(DataUsageOptions {
+                _type: None,
+                verbose: true,
+            }))
+            .await
+        {
+            Ok(usage) => {
+                let categories = DiskUsageCategory::from_response(usage, &self.host);
+                self.app_data
+                    .lock()
+                    .update_disk_usage(&self.host, categories);
+            }
+            Err(_) if heartbeat => {
+                self.gui_state
+                    .lock()
+                    .set_info_box("✖ unable to refresh disk usage");
+            }
+            Err(_) => {
+                self.app_data.lock().set_error(
+                    AppError::DockerDiskUsage,
+                    &self.gui_state,
+                    Status::Error,
+                );
+            }
+        }
+    }
+
+    /// Get all volumes, the containers referencing each volume are listed from the last known container summaries
+    /// Only called when the volumes view is open, as with containers an error means the connection to the host has been lost
+    async fn update_volumes(&mut self) {
+        let Ok(volumes) = self
+            .docker
+            .list_volumes(Some(ListVolumesOptions::default()))
+            .await
+        else {
+            self.set_disconnected();
+            return;
+        };
+
+        let mut used_by: HashMap<&str, Vec<String>> = HashMap::new();
+        for container in &self.summaries {
+            let name = container
+                .names
+                .as_ref()
+                .and_then(|i| i.first())
+                .map_or_else(String::new, |i| i.trim_start_matches('/').to_owned());
+            for volume in container
+                .mounts
+                .iter()
+                .flatten()
+                .filter_map(|i| i.name.as_deref())
+            {
+                used_by.entry(volume).or_default().push(name.clone());
+            }
+        }
+
+        let volumes = volumes
+            .volumes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|i| {
+                let containers = used_by.get(i.name.as_str()).cloned().unwrap_or_default();
+                let size = self.volume_sizes.get(&i.name).copied();
+                VolumeItem::new(i, containers, size, &self.host)
+            })
+            .collect();
+        self.app_data.lock().update_volumes(&self.host, volumes);
+    }
+
+    /// Remove a volume, this will fail if the volume is referenced by any container, even a stopped one
+    async fn remove_volume(&mut self, name: String) {
+        let uuid = Uuid::new_v4();
+        GuiState::start_loading_animation(&self.gui_state, uuid);
+        if self
+            .docker
+            .remove_volume(&name, None::<RemoveVolumeOptions>)
+            .await
+            .is_err()
+        {
+            self.app_data.lock().set_error(
+                AppError::DockerVolume("remove volume"),
+                &self.gui_state,
+                Status::Error,
+            );
+        }
+        self.update_volume_sizes().await;
+        self.update_volumes().await;
+        self.gui_state.lock().stop_loading_animation(uuid);
+    }
+
+    /// Get everything that a prune would remove, and ask for confirmation before anything is actually removed
+    /// Networks aren't included in the `df` response, so they are checked against the network list instead
+    async fn prune_preview(&mut self, kind: PruneKind) {
+        let uuid = Uuid::new_v4();
+        GuiState::start_loading_animation(&self.gui_state, uuid);
+        let preview = if let Some(df_type) = kind.disk_usage_type() {
+            self.docker
+                .df(Some(DataUsageOptions {
+                    _type: Some(vec![df_type.to_owned()]),
+                    verbose: true,
+                }))
+                .await
+                .ok()
+                .and_then(|usage| {
+                    DiskUsageCategory::from_response(usage, &self.host)
+                        .into_iter()
+                        .find(|i| PruneKind::from(i.kind) == kind)
+                })
+                .map(|i| PrunePreview::from_disk_usage(&i))
+        } else {
+            self.get_networks()
+                .await
+                .map(|i| PrunePreview::from_networks(&i, &self.host))
+        };
+        match preview {
+            Some(preview) if preview.items.is_empty() => {
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("✓ no {kind} to prune"));
+            }
+            Some(preview) => self
+                .gui_state
+                .lock()
+                .set_confirm(Some(Confirm::Prune(preview))),
+            None => {
+                self.app_data.lock().set_error(
+                    AppError::DockerPrune(kind),
+                    &self.gui_state,
+                    Status::Error,
+                );
+            }
+        }
+        self.gui_state.lock().stop_loading_animation(uuid);
+    }
+
+    /// Remove each object listed in the confirmed preview, rather than making a blanket prune request, so that nothing that became prunable after the preview was shown is removed
+    /// An object that has since become used, for example a container that has been started, fails to remove and is skipped
+    /// The number of objects removed, and the space reclaimed, is shown in an info box, networks don't report any reclaimed space
+    async fn prune(&mut self, preview: PrunePreview) {
+        let uuid = Uuid::new_v4();
+        GuiState::start_loading_animation(&self.gui_state, uuid);
+        let kind = preview.kind;
+        let total = preview.items.len();
+        let (deleted, reclaimed) = if kind == PruneKind::BuildCache {
+            self.prune_build_cache(&preview).await
+        } else {
+            let mut deleted = 0;
+            let mut reclaimed = vec![];
+            for item in &preview.items {
+                let id = item.id.as_str();
+                let removed = match kind {
+                    PruneKind::Containers => self
+                        .docker
+                        .remove_container(id, None::<RemoveContainerOptions>)
+                        .await
+                        .is_ok(),
+                    PruneKind::Images => self
+                        .docker
+                        .remove_image(id, None::<RemoveImageOptions>, None)
+                        .await
+                        .is_ok(),
+                    PruneKind::Networks => self.docker.remove_network(id).await.is_ok(),
+                    PruneKind::Volumes => self
+                        .docker
+                        .remove_volume(id, None::<RemoveVolumeOptions>)
+                        .await
+                        .is_ok(),
+                    PruneKind::BuildCache => false,
+                };
+                if removed {
+                    deleted += 1;
+                    reclaimed.extend(item.size);
+                }
+            }
+            (
+                deleted,
+                preview.reclaimable.map(|_| reclaimed.into_iter().sum()),
+            )
+        };
+        let reclaimed = reclaimed.map_or_else(String::new, |i| format!(", reclaimed {i}"));
+        if deleted == total {
+            self.gui_state
+                .lock()
+                .set_info_box(&format!("✓ pruned {deleted} {kind}{reclaimed}"));
+        } else if deleted > 0 {
+            self.gui_state
+                .lock()
+                .set_info_box(&format!("✖ pruned {deleted} of {total} {kind}{reclaimed}"));
+        } else {
+            self.app_data.lock().set_error(
+                AppError::DockerPrune(kind),
+                &self.gui_state,
+                Status::Error,
+            );
+        }
+        self.update_after_prune(kind).await;
+        self.gui_state.lock().stop_loading_animation(uuid);
+    }
+
+    /// Build cache records can't be removed individually, so prune with an id filter matching only the previewed records
+    async fn prune_build_cache(&self, preview: &PrunePreview) -> (usize, Option<ByteStats>) {
+        self.docker
+            .prune_build(Some(PruneBuildOptions {
+                filters: Some(HashMap::from([(
+                    "id".to_owned(),
+                    preview.items.iter().map(|i| i.id.clone()).collect(),
+                )])),
+                ..Default::default()
+            }))
+            .await
+            .map_or((0, None), |i| {
+                (
+                    i.caches_deleted.unwrap_or_default().len(),
+                    Some(ByteStats::new(
+                        i.space_reclaimed
+                            .and_then(|i| u64::try_from(i).ok())
+                            .unwrap_or_default(),
+                    )),
+                )
+            })
+    }
+
+    /// Refresh the containers, if they were pruned, and the data of any open view, so that the removed objects disappear straight away
+    async fn update_after_prune(&mut self, kind: PruneKind) {
+        if kind == PruneKind::Containers {
+            self.update_all_containers().await;
+        }
+        let status = self.gui_state.lock().get_status();
+        if status.contains(&Status::DiskUsage) {
+            self.update_disk_usage(true).await;
+        }
+        if status.contains(&Status::Images) {
+            self.update_images().await;
+        }
+        if status.contains(&Status::Networks) {
+            self.update_networks().await;
+        }
+        if status.contains(&Status::Volumes) {
+            self.update_volume_sizes().await;
+            self.update_volumes().await;
+        }
+    }
+
+    /// Get all networks, the endpoints of each container are only listed in the container summary, so the last known summaries are used
+    async fn get_networks(&self) -> Option<Vec<NetworkItem>> {
+        let networks = self
+            .docker
+            .list_networks(Some(ListNetworksOptions::default()))
+            .await
+            .ok()?;
+        let containers = &self.summaries;
+
+        let networks = networks
+            .into_iter()
+            .map(|network| {
+                let id = network.id.as_deref().unwrap_or_default();
+                let name = network.name.as_deref().unwrap_or_default();
+                // Endpoints of stopped containers don't have a network id, so fall back to matching by the network name
+                let attached = containers
+                    .iter()
+                    .filter_map(|container| {
+                        container
+                            .network_settings
+                            .as_ref()
+                            .and_then(|i| i.networks.as_ref())
+                            .and_then(|endpoints| {
+                                endpoints.iter().find(|(key, endpoint)| {
+                                    endpoint
+                                        .network_id
+                                        .as_deref()
+                                        .filter(|i| !i.is_empty())
+                                        .map_or(key.as_str() == name, |i| i == id)
+                                })
+                            })
+                            .map(|(_, endpoint)| {
+                                NetworkContainer::new(container, endpoint, &self.host)
+                            })
+                    })
+                    .collect();
+                NetworkItem::new(network, attached, &self.host)
+            })
+            .collect();
+        Some(networks)
+    }
+
+    /// Get the processes running in a container, via the top endpoint, so that `ps` doesn't need to exist in the container's image
+    /// A container that isn't running doesn't have any processes, so an error just clears the processes
+    async fn update_processes(&mut self, id: &ContainerId) {
+        let processes = self
+            .docker
+            .top_processes(
+                id.get(),
+                Some(TopOptions {
+                    ps_args: PS_ARGS.to_owned(),
+                }),
+            )
+            .await
+            .map(ProcessItem::from_response)
+            .unwrap_or_default();
+        self.app_data.lock().update_processes(id, processes);
+    }
+
+    /// Send a signal to a single process, via an exec'd `kill`, as the API can only send signals to the main process of a container
+    /// The listed PIDs are those of the host, so this is only possible if the container shares the host's PID namespace, i.e. was run with `--pid=host`,
+    /// otherwise the PID inside the container is unknown, and the signal isn't sent
+    async fn kill_process(&mut self, signal: Signal, id: ContainerId, process: ProcessItem) {
+        let uuid = Uuid::new_v4();
+        GuiState::start_loading_animation(&self.gui_state, uuid);
+        let host_pid = match self.docker.inspect_container(id.get(), None).await {
+            Ok(inspect) => inspect
+                .host_config
+                .and_then(|i| i.pid_mode)
+                .is_some_and(|i| i == "host"),
+            Err(_) => {
+                self.app_data.lock().set_error(
+                    AppError::DockerProcess("signal process"),
+                    &self.gui_state,
+                    Status::Error,
+                );
+                self.gui_state.lock().stop_loading_animation(uuid);
+                return;
+            }
+        };
+        if !host_pid {
+            self.gui_state.lock().set_info_box(&format!(
+                "✖ unable to send {signal} to pid {}, the container has its own PID namespace",
+                process.pid
+            ));
+            self.gui_state.lock().stop_loading_animation(uuid);
+            return;
+        }
+
+        let exit_code = match self
+            .docker
+            .create_exec(
+                id.get(),
+                CreateExecOptions {
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    cmd: Some(process.kill_command(signal)),
+                    ..Default::default()
+                },
+            )
+            .await
+        {
+            Ok(exec) => {
+                if let Ok(StartExecResults::Attached { mut output, .. }) =
+                    self.docker.start_exec(&exec.id, None).await
+                {
+                    while output.next().await.is_some() {}
+                }
+                self.docker
+                    .inspect_exec(&exec.id)
+                    .await
+                    .ok()
+                    .and_then(|i| i.exit_code)
+            }
+            Err(_) => None,
+        };
+        match exit_code {
+            Some(0) => {
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("✓ sent {signal} to pid {}", process.pid));
+            }
+            Some(_) => {
+                self.gui_state.lock().set_info_box(&format!(
+                    "✖ unable to send {signal} to pid {}, the process may have exited",
+                    process.pid
+                ));
+            }
+            None => {
+                self.app_data.lock().set_error(
+                    AppError::DockerProcess("signal process"),
+                    &self.gui_state,
+                    Status::Error,
+                );
+            }
+        }
+        self.update_processes(&id).await;
+        self.gui_state.lock().stop_loading_animation(uuid);
+    }
+
+    /// Only called when the networks view is open, as with containers an error means the connection to the host has been lost
+    async fn update_networks(&mut self) {
+        if let Some(networks) = self.get_networks().await {
+            self.app_data.lock().update_networks(&self.host, networks);
+        } else {
+            self.set_disconnected();
+        }
+    }
+
+    /// Create a new network, with the default driver
+    async fn create_network(&mut self, name: String) {
+        let uuid = Uuid::new_v4();
+        GuiState::start_loading_animation(&self.gui_state, uuid);
+        if self
+            .docker
+            .create_network(NetworkCreateRequest {
+                name,
+                ..Default::default()
+            })
+            .await
+            .is_err()
+        {
+            self.app_data.lock().set_error(
+                AppError::DockerNetwork("create network"),
+                &self.gui_state,
+                Status::Error,
+            );
+        }
+        self.update_networks().await;
+        self.gui_state.lock().stop_loading_animation(uuid);
+    }
+
+    /// Remove a network, this will fail if any container is still connected to the network
+    async fn remove_network(&mut self, id: String) {
+        let uuid = Uuid::new_v4();
+        GuiState::start_loading_animation(&self.gui_state, uuid);
+        if self.docker.remove_network(&id).await.is_err() {
+            self.app_data.lock().set_error(
+                AppError::DockerNetwork("remove network"),
+                &self.gui_state,
+                Status::Error,
+            );
+        }
+        self.update_networks().await;
+        self.gui_state.lock().stop_loading_animation(uuid);
+    }
+
+    /// Connect a container to, or disconnect a container from, a network
+    /// There isn't a container event for a network change, so the container is re-listed to update its endpoints
+    async fn connect_network(&mut self, id: String, container_id: ContainerId, connect: bool) {
+        let uuid = Uuid::new_v4();
+        GuiState::start_loading_animation(&self.gui_state, uuid);
+        let container = container_id.get().to_owned();
+        let result = if connect {
+            self.docker
+                .connect_network(
+                    &id,
+                    NetworkConnectRequest {
+                        container,
+                        endpoint_config: None,
+                    },
+                )
+                .await
+        } else {
+            self.docker
+                .disconnect_network(
+                    &id,
+                    NetworkDisconnectRequest {
+                        container,
+                        force: None,
+                    },
+                )
+                .await
+        };
+        if result.is_err() {
+            let action = if connect {
+                "connect container to network"
+            } else {
+                "disconnect container from network"
+            };
+            self.app_data.lock().set_error(
+                AppError::DockerNetwork(action),
+                &self.gui_state,
+                Status::Error,
+            );
+        }
+        self.list_containers_by_id(&[container_id]).await;
+        self.update_networks().await;
+        self.gui_state.lock().stop_loading_animation(uuid);
+    }
+
+    /// Only re-list every container if the events stream isn't connected, or the relist interval has elapsed
+    fn relist_required(&self) -> bool {
+        !self
+            .events_connected
+            .load(std::sync::atomic::Ordering::SeqCst)
+            || self
+                .last_relist
+                .is_none_or(|i| i.elapsed() >= RELIST_INTERVAL)
+    }
+
+    /// List the given containers, using an id filter, and replace their summaries in the last known summaries
+    /// None means the connection to the host has been lost
+    async fn list_containers_by_id(
+        &mut self,
+        ids: &[ContainerId],
+    ) -> Option<Vec<ContainerSummary>> {
+        let ids = ids.iter().map(|i| i.get().to_owned()).collect::<Vec<_>>();
+        let containers = self
+            .docker
+            .list_containers(Some(ListContainersOptions {
+                all: true,
+                filters: Some(HashMap::from([("id".to_owned(), ids.clone())])),
+                ..Default::default()
+            }))
+            .await
+            .ok()?
+            .into_iter()
+            .filter(|i| i.id.as_ref().is_some_and(|id| ids.contains(id)))
+            .collect::<Vec<_>>();
+        self.summaries
+            .retain(|i| i.id.as_ref().is_none_or(|id| !ids.contains(id)));
+        self.summaries.extend(containers.iter().cloned());
+        Some(containers)
+    }
+
+    /// Apply every pending container event, from the Docker events stream, to app_data
+    /// Destroyed containers are removed, and every other container with an event is re-listed, using a single id filtered request, and then updated
+    /// The stats stream of a container that has stopped, or been removed, is aborted, and a container that has started gets a new stats stream
+    async fn handle_events(&mut self) {
+        let pending = std::mem::take(&mut *self.pending_events.lock());
+        if pending.is_empty() {
+            return;
+        }
+        let (destroyed, changed): (Vec<_>, Vec<_>) = pending
+            .iter()
+            .partition(|(_, event)| **event == ContainerEvent::Destroy);
+
+        for (id, _) in destroyed {
+            self.summaries.retain(|i| i.id.as_deref() != Some(id.get()));
+            self.app_data.lock().remove_container(id);
+        }
+
+        let changed = changed
+            .into_iter()
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+        if !changed.is_empty() {
+            let Some(containers) = self.list_containers_by_id(&changed).await else {
+                self.set_disconnected();
+                return;
+            };
+            let containers = self.remove_self(containers);
+            let mut app_data = self.app_data.lock();
+            for id in &changed {
+                if !containers.iter().any(|i| i.id.as_deref() == Some(id.get())) {
+                    app_data.remove_container(id);
+                }
+            }
+            for container in containers {
+                app_data.update_container(&self.host, container);
+            }
+        }
+
+        for (id, event) in &pending {
+            if matches!(
+                event,
+                ContainerEvent::Destroy | ContainerEvent::Die | ContainerEvent::Stop
+            ) {
+                self.abort_stats(id);
+            }
+        }
+        self.app_data.lock().sort_containers();
+        self.watch_selected_logs();
+        self.update_all_container_stats();
+    }
+
+    /// Subscribe to the Docker events stream, and add relevant container events to the pending events
+    /// The message handler is only sent an Event message when there were no pending events, so a burst of events is applied at once, with a single re-list
+    /// As bollard gives no signal that the subscription has been accepted, events_connected is only set once the first event has been received,
+    /// until then, and whenever the stream closes, every heartbeat will re-list all containers, and the stream is re-subscribed to after a delay
+    /// On re-subscribe, any events that were missed since the last received event are replayed by Docker
+    fn events(
         docker: Arc<Docker>,
-        id: ContainerId,
-        since: u64,
-        spawns: Arc<Mutex<HashSet<SpawnId>>>,
+        docker_tx: Sender<DockerMessage>,
+        events_connected: Arc<AtomicBool>,
+        pending_events: PendingEvents,
+        host: ContainerHost,
+    ) -> AbortHandle {
+        tokio::spawn(async move {
+            let mut since = None;
+            loop {
+                let mut stream = docker.events(Some(EventsOptions {
+                    since: since.clone(),
+                    filters: Some(HashMap::from([(
+                        "type".to_owned(),
+                        vec!["container".to_owned()],
+                    )])),
+                    ..Default::default()
+                }));
+                since = Some(jiff::Timestamp::now().as_second().to_string());
+                while let Some(Ok(event)) = stream.next().await {
+                    events_connected.store(true, std::sync::atomic::Ordering::SeqCst);
+                    if let Some(time) = event.time {
+                        since = Some(time.to_string());
+                    }
+                    if let (Some(container_event), Some(id)) = (
+                        event
+                            .action
+                            .as_deref()
+                            .and_then(ContainerEvent::from_action),
+                        event.actor.and_then(|i| i.id),
+                    ) {
+                        let notify = {
+                            let mut pending = pending_events.lock();
+                            let notify = pending.is_empty();
+                            pending.insert(ContainerId::new(&host, &id), container_event);
+                            notify
+                        };
+                        if notify && docker_tx.send(DockerMessage::Event).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                events_connected.store(false, std::sync::atomic::Ordering::SeqCst);
+                tokio::time::sleep(EVENTS_RETRY).await;
+            }
+        })
+        .abort_handle()
+    }
+
+    /// Update single container logs, lines are inserted into app_data as soon as they are received
+    /// If follow is set, the stream stays open until the container stops, or the task is aborted because the container has been deselected or removed
+    /// `since` & `until` are whole seconds, and both are inclusive, so a history request with `until` of a given second,
+    /// followed by a follow request with `since` of that same second, only overlaps on lines with a timestamp of exactly that second,
+    /// which are then ignored by the LogsTz HashSet
+    async fn update_log(
+        app_data: &Arc<Mutex<AppData>>,
+        docker: &Docker,
+        id: &ContainerId,
+        follow: bool,
+        (since, until): (i32, i32),
         stderr: bool,
     ) {
         let options = Some(LogsOptions {
+            follow,
             stdout: true,
             stderr,
             timestamps: true,
-            since: i32::try_from(since).unwrap_or_default(),
+            since,
+            until,
             ..Default::default()
         });
 
-        let mut logs = docker.logs(id.get(), options);
-        let mut output = vec![];
+        let mut logs = docker.logs(id.get(), options).ready_chunks(LOG_CHUNK_SIZE);
 
-        while let Some(Ok(value)) = logs.next().await {
-            let data = value.to_string();
-            if !data.trim().is_empty() {
-                output.push(data);
+        'stream: while let Some(chunk) = logs.next().await {
+            let mut output = vec![];
+            let mut closed = false;
+            for value in chunk {
+                if let Ok(value) = value {
+                    let data = value.to_string();
+                    if !data.trim().is_empty() {
+                        output.push(data);
+                    }
+                } else {
+                    closed = true;
+                    break;
+                }
+            }
+            app_data.lock().update_log_by_id(output, id);
+            if closed {
+                break 'stream;
             }
         }
-        app_data.lock().update_log_by_id(output, &id);
-        spawns.lock().remove(&SpawnId::Log(id));
     }
 
-    /// Update all logs, spawn each container into own tokio::spawn thread
+    /// The current unix timestamp, in seconds, used to split a history request from the follow request that resumes it
+    fn log_split() -> i32 {
+        i32::try_from(jiff::Timestamp::now().as_second()).unwrap_or_default()
+    }
+
+    /// Get all existing logs for every container, spawn each container into own tokio::spawn thread
+    /// Once the existing logs have been received, the selected container, if running, keeps following its logs, from the second the history ended, in the same task
+    /// Only lines since the most recent known log line are requested, so this can also be used to fill in any logs missed whilst disconnected
     fn init_all_logs(&self, all_ids: Vec<(State, ContainerId)>) -> Arc<AtomicUsize> {
         let init = Arc::new(AtomicUsize::new(0));
-        for (_, id) in all_ids {
-            let app_data: Arc<parking_lot::lock_api::Mutex<parking_lot::RawMutex, AppData>> =
-                Arc::clone(&self.app_data);
+        let split = Self::log_split();
+        let selected = self.app_data.lock().get_selected_container_id();
+        for (state, id) in all_ids {
+            let since = self.app_data.lock().get_log_since(&id);
+            let app_data = Arc::clone(&self.app_data);
             let docker = Arc::clone(&self.docker);
             let spawns = Arc::clone(&self.spawns);
             let std_err = self.config.show_std_err;
             let init = Arc::clone(&init);
+            let follow = state.is_alive() && selected.as_ref() == Some(&id);
+            let spawn_id = if follow {
+                SpawnId::Log(id.clone())
+            } else {
+                SpawnId::LogHistory(id.clone())
+            };
 
-            self.spawns.lock().insert(SpawnId::Log(id.clone()));
-
-            tokio::spawn(async move {
-                Self::update_log(app_data, docker, id, 0, spawns, std_err).await;
+            let mut all_spawns = self.spawns.lock();
+            for i in [SpawnId::Log(id.clone()), SpawnId::LogHistory(id.clone())] {
+                if let Some(handle) = all_spawns.remove(&i) {
+                    handle.abort();
+                }
+            }
+            let task_id = spawn_id.clone();
+            let handle = tokio::spawn(async move {
+                Self::update_log(&app_data, &docker, &id, false, (since, split), std_err).await;
                 init.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                if follow {
+                    Self::update_log(&app_data, &docker, &id, true, (split, 0), std_err).await;
+                }
+                Self::remove_spawn(&spawns, &task_id);
             });
+            all_spawns.insert(spawn_id, handle.abort_handle());
         }
         init
     }
 
+    /// Make sure that the selected container, and only the selected container, has a following log stream, if it is running on this host
+    /// The log stream of any other container, i.e. one that has been deselected or removed, is aborted, as is the history request of a removed container
+    /// A new stream resumes from the second of the most recent known log line, it isn't started whilst the history of the container is still being received
+    fn watch_selected_logs(&self) {
+        let (all_ids, selected) = {
+            let mut app_data = self.app_data.lock();
+            let all_ids = app_data.get_all_id_state(&self.host);
+            let selected = app_data.get_selected_container_id().and_then(|id| {
+                all_ids
+                    .iter()
+                    .any(|(state, i)| i == &id && state.is_alive())
+                    .then(|| (app_data.get_log_since(&id), id))
+            });
+            (all_ids, selected)
+        };
+
+        let mut spawns = self.spawns.lock();
+        spawns.retain(|spawn_id, handle| match spawn_id {
+            SpawnId::Log(id) if selected.as_ref().is_none_or(|(_, i)| i != id) => {
+                handle.abort();
+                false
+            }
+            SpawnId::LogHistory(id) if !all_ids.iter().any(|(_, i)| i == id) => {
+                handle.abort();
+                false
+            }
+            _ => true,
+        });
+
+        if let Some((since, id)) = selected {
+            if spawns.contains_key(&SpawnId::LogHistory(id.clone())) {
+                return;
+            }
+            if let Entry::Vacant(entry) = spawns.entry(SpawnId::Log(id.clone())) {
+                let (app_data, docker, spawns) = (
+                    Arc::clone(&self.app_data),
+                    Arc::clone(&self.docker),
+                    Arc::clone(&self.spawns),
+                );
+                let std_err = self.config.show_std_err;
+                let handle = tokio::spawn(async move {
+                    Self::update_log(&app_data, &docker, &id, true, (since, 0), std_err).await;
+                    Self::remove_spawn(&spawns, &SpawnId::Log(id));
+                });
+                entry.insert(handle.abort_handle());
+            }
+        }
+    }
+
     /// Initialize docker container data, before any messages are received
+    /// Status::Init is set by the router, for every host, before any DockerData is started
     async fn initialise_container_data(&mut self) {
-        self.gui_state.lock().status_push(Status::Init);
         let loading_uuid = Uuid::new_v4();
         GuiState::start_loading_animation(&self.gui_state, loading_uuid);
         self.update_all_containers().await;
-        let all_ids = self.app_data.lock().get_all_id_state();
+        let all_ids = self.app_data.lock().get_all_id_state(&self.host);
         let all_ids_len = all_ids.len();
         let init = self.init_all_logs(all_ids);
         self.update_all_container_stats();
@@ -311,30 +1398,94 @@ impl DockerData {
             tokio::time::sleep(std::time::Duration::from_millis(10)).await;
         }
         self.gui_state.lock().stop_loading_animation(loading_uuid);
-        self.gui_state.lock().status_del(Status::Init);
+        self.gui_state.lock().init_end(&self.host);
+    }
+
+    /// Mark the host as disconnected, keeping the last known state of its containers, and abort every stats & logs stream
+    /// Reconnection is then attempted on each heartbeat, with an exponential backoff
+    fn set_disconnected(&mut self) {
+        if self.reconnect.is_none() {
+            self.reconnect = Some(Reconnect::new());
+            for handle in self.spawns.lock().values() {
+                handle.abort();
+            }
+            self.spawns.lock().clear();
+            self.app_data.lock().set_host_connected(&self.host, false);
+        }
+    }
+
+    /// Attempt to reconnect to a disconnected host, once the backoff delay has elapsed
+    /// Bollard opens a new connection for each request once the previous one has gone, so a successful ping means the host is back
+    /// On success, all containers are re-listed, and any logs & stats missed whilst disconnected are resynced
+    /// `init_all_logs` also restarts the follow stream of the selected container, if running, so nothing more is needed for the logs
+    async fn try_reconnect(&mut self) {
+        let Some(mut reconnect) = self.reconnect else {
+            return;
+        };
+        if reconnect.next > Instant::now() {
+            return;
+        }
+        if tokio::time::timeout(RECONNECT_PING_TIMEOUT, self.docker.ping())
+            .await
+            .is_ok_and(|i| i.is_ok())
+        {
+            self.reconnect = None;
+            self.update_all_containers().await;
+            if self.reconnect.is_none() {
+                self.app_data.lock().set_host_connected(&self.host, true);
+                let all_ids = self.app_data.lock().get_all_id_state(&self.host);
+                self.init_all_logs(all_ids);
+                self.update_all_container_stats();
+                self.app_data.lock().sort_containers();
+            }
+        } else {
+            reconnect.backoff();
+            self.reconnect = Some(reconnect);
+        }
     }
 
-    /// Update all cpu_mem, and selected container log (if a log update join_handle isn't currently being executed)
+    /// Update all cpu_mem, and make sure every running container has a log stream
+    /// Containers are only fully re-listed when required, as the Docker events stream should keep them up to date
+    /// Whilst disconnected, only a reconnection is attempted
     async fn update_everything(&mut self) {
-        self.update_all_containers().await;
-        if let Some(container) = self.app_data.lock().get_selected_container() {
-            let last_updated = container.last_updated;
-            let spawn_id = SpawnId::Log(container.id.clone());
-            // Only spawn if not already spawned with a given id/binate pair
-            if !self.spawns.lock().contains(&spawn_id) {
-                self.spawns.lock().insert(spawn_id.clone());
-                tokio::spawn(Self::update_log(
-                    Arc::clone(&self.app_data),
-                    Arc::clone(&self.docker),
-                    container.id.clone(),
-                    last_updated,
-                    Arc::clone(&self.spawns),
-                    self.config.show_std_err,
-                ));
+        if self.reconnect.is_some() {
+            self.try_reconnect().await;
+            return;
+        }
+        if self.relist_required() {
+            self.update_all_containers().await;
+            if self.reconnect.is_some() {
+                return;
             }
         }
+        self.watch_selected_logs();
         self.update_all_container_stats();
         self.app_data.lock().sort_containers();
+        let status = self.gui_state.lock().get_status();
+        if status.contains(&Status::DiskUsage)
+            && self
+                .last_disk_usage
+                .is_none_or(|i| i.elapsed() >= DISK_USAGE_INTERVAL)
+        {
+            self.update_disk_usage(true).await;
+        }
+        if status.contains(&Status::Images) {
+            self.update_images().await;
+        }
+        if status.contains(&Status::Networks) {
+            self.update_networks().await;
+        }
+        if status.contains(&Status::Processes) {
+            let id = self.app_data.lock().get_processes_container().cloned();
+            if let Some(id) = id
+                && id.host() == &self.host
+            {
+                self.update_processes(&id).await;
+            }
+        }
+        if status.contains(&Status::Volumes) {
+            self.update_volumes().await;
+        }
     }
 
     /// Set the global error as the docker error, and set gui_state to error
@@ -348,49 +1499,183 @@ impl DockerData {
             .set_error(AppError::DockerCommand(error), gui_state, Status::Error);
     }
 
+    /// Run a single docker command (start, stop etc) against a container
+    /// The signal is only used by the kill command, defaulting to SIGKILL, as the Docker CLI does
+    async fn run_command(
+        docker: &Docker,
+        control: DockerCommand,
+        id: &ContainerId,
+        signal: Option<Signal>,
+        options: DeleteOptions,
+        stop_timeout: Option<i32>,
+    ) -> Result<(), bollard::errors::Error> {
+        match control {
+            DockerCommand::Delete => {
+                docker
+                    .remove_container(
+                        id.get(),
+                        Some(RemoveContainerOptions {
+                            v: options.volumes,
+                            force: options.force,
+                            link: false,
+                        }),
+                    )
+                    .await
+            }
+            // Down, as with Docker Compose, stops a running container, using the stop timeout, before removing it
+            DockerCommand::Down => {
+                match docker
+                    .stop_container(
+                        id.get(),
+                        Some(StopContainerOptions {
+                            signal: None,
+                            t: stop_timeout,
+                        }),
+                    )
+                    .await
+                {
+                    // The daemon responds with a 304 if the container is already stopped
+                    Ok(())
+                    | Err(bollard::errors::Error::DockerResponseServerError {
+                        status_code: 304,
+                        ..
+                    }) => (),
+                    Err(e) => return Err(e),
+                }
+                docker
+                    .remove_container(
+                        id.get(),
+                        Some(RemoveContainerOptions {
+                            v: options.volumes,
+                            force: false,
+                            link: false,
+                        }),
+                    )
+                    .await
+            }
+            DockerCommand::Kill => {
+                docker
+                    .kill_container(
+                        id.get(),
+                        Some(KillContainerOptions {
+                            signal: signal.unwrap_or(Signal::Kill).to_string(),
+                        }),
+                    )
+                    .await
+            }
+            DockerCommand::Pause => docker.pause_container(id.get()).await,
+            DockerCommand::Restart => {
+                docker
+                    .restart_container(
+                        id.get(),
+                        Some(RestartContainerOptions {
+                            signal: None,
+                            t: stop_timeout,
+                        }),
+                    )
+                    .await
+            }
+            DockerCommand::Resume => docker.unpause_container(id.get()).await,
+            DockerCommand::Start => {
+                docker
+                    .start_container(id.get(), None::<StartContainerOptions>)
+                    .await
+            }
+            DockerCommand::Stop => {
+                docker
+                    .stop_container(
+                        id.get(),
+                        Some(StopContainerOptions {
+                            signal: None,
+                            t: stop_timeout,
+                        }),
+                    )
+                    .await
+            }
+        }
+    }
+
+    /// Get the Docker Compose projects of the given containers, but only for a Down command, as only then are the project networks removed
+    fn get_down_projects(
+        app_data: &Arc<Mutex<AppData>>,
+        control: DockerCommand,
+        ids: &[ContainerId],
+    ) -> HashSet<String> {
+        if control == DockerCommand::Down {
+            app_data.lock().get_projects_by_ids(ids)
+        } else {
+            HashSet::new()
+        }
+    }
+
+    /// Remove the networks that Docker Compose created for each project, as `docker compose down` does
+    /// The networks are only removed once no container of the project remains, as they can't be removed while any container is still attached
+    /// External networks don't have the project label, so are left alone
+    async fn remove_project_networks(
+        docker: &Docker,
+        projects: &HashSet<String>,
+    ) -> Result<(), bollard::errors::Error> {
+        for project in projects {
+            let filters = Some(HashMap::from([(
+                "label".to_owned(),
+                vec![format!("{COMPOSE_PROJECT}={project}")],
+            )]));
+            let remaining = docker
+                .list_containers(Some(ListContainersOptions {
+                    all: true,
+                    filters: filters.clone(),
+                    ..Default::default()
+                }))
+                .await?;
+            if !remaining.is_empty() {
+                continue;
+            }
+            let networks = docker
+                .list_networks(Some(ListNetworksOptions { filters }))
+                .await?;
+            for id in networks.into_iter().filter_map(|i| i.id) {
+                docker.remove_network(&id).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the delete options from the gui_state, closing the delete popup if the command is a delete
+    fn take_delete_options(
+        gui_state: &Arc<Mutex<GuiState>>,
+        control: DockerCommand,
+    ) -> DeleteOptions {
+        let mut gui_state = gui_state.lock();
+        if matches!(control, DockerCommand::Delete | DockerCommand::Down) {
+            gui_state.set_delete_containers(vec![]);
+        }
+        gui_state.get_delete_options()
+    }
+
     /// Execute docker commands (start, stop etc) on it's own tokio thread
-    async fn execute_command(&mut self, control: DockerCommand, id: ContainerId) {
+    async fn execute_command(
+        &mut self,
+        control: DockerCommand,
+        id: ContainerId,
+        signal: Option<Signal>,
+    ) {
         let (app_data, docker, gui_state) = (
             Arc::clone(&self.app_data),
             Arc::clone(&self.docker),
             Arc::clone(&self.gui_state),
         );
+        let stop_timeout = self.config.stop_timeout;
+        let projects = Self::get_down_projects(&app_data, control, std::slice::from_ref(&id));
         tokio::spawn(async move {
             let uuid = Uuid::new_v4();
             GuiState::start_loading_animation(&gui_state, uuid);
-            if match control {
-                DockerCommand::Delete => {
-                    gui_state.lock().set_delete_container(None);
-                    docker
-                        .remove_container(
-                            id.get(),
-                            Some(RemoveContainerOptions {
-                                v: false,
-                                force: true,
-                                link: false,
-                            }),
-                        )
-                        .await
-                }
-                DockerCommand::Pause => docker.pause_container(id.get()).await,
-                DockerCommand::Restart => {
-                    docker
-                        .restart_container(id.get(), None::<RestartContainerOptions>)
-                        .await
-                }
-                DockerCommand::Resume => docker.unpause_container(id.get()).await,
-                DockerCommand::Start => {
-                    docker
-                        .start_container(id.get(), None::<StartContainerOptions>)
-                        .await
-                }
-                DockerCommand::Stop => {
-                    docker
-                        .stop_container(id.get(), None::<StopContainerOptions>)
-                        .await
-                }
-            }
-            .is_err()
+            let options = Self::take_delete_options(&gui_state, control);
+            if Self::run_command(&docker, control, &id, signal, options, stop_timeout)
+                .await
+                .is_err()
+                || Self::remove_project_networks(&docker, &projects)
+                    .await
+                    .is_err()
             {
                 Self::set_error(&app_data, control, &gui_state);
             }
@@ -400,26 +1685,126 @@ impl DockerData {
         self.update_everything().await;
     }
 
+    /// Execute a docker command on multiple containers at once, on it's own tokio thread
+    /// Once every command has completed, the id of each failed container is sent back to the DockerRouter, which merges the results of every host
+    async fn execute_batch(
+        &mut self,
+        control: DockerCommand,
+        ids: Vec<ContainerId>,
+        signal: Option<Signal>,
+        result_tx: tokio::sync::oneshot::Sender<Vec<ContainerId>>,
+    ) {
+        let (app_data, docker, gui_state) = (
+            Arc::clone(&self.app_data),
+            Arc::clone(&self.docker),
+            Arc::clone(&self.gui_state),
+        );
+        let stop_timeout = self.config.stop_timeout;
+        let projects = Self::get_down_projects(&app_data, control, &ids);
+        tokio::spawn(async move {
+            let options = Self::take_delete_options(&gui_state, control);
+            let results =
+                join_all(ids.iter().map(|id| {
+                    Self::run_command(&docker, control, id, signal, options, stop_timeout)
+                }))
+                .await;
+            let failed = ids
+                .into_iter()
+                .zip(results)
+                .filter(|(_, result)| result.is_err())
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>();
+            if Self::remove_project_networks(&docker, &projects)
+                .await
+                .is_err()
+            {
+                Self::set_error(&app_data, control, &gui_state);
+            }
+            result_tx.send(failed).ok();
+        });
+
+        self.update_everything().await;
+    }
+
     /// Handle incoming messages, container controls & all container information update
     /// Spawn Docker commands off into own thread
     async fn message_handler(&mut self) {
         while let Some(message) = self.receiver.recv().await {
             match message {
+                DockerMessage::HostBatch((command, signal, ids, result_tx)) => {
+                    self.execute_batch(command, ids, signal, result_tx).await;
+                }
                 DockerMessage::ConfirmDelete(id) => {
-                    self.gui_state.lock().set_delete_container(Some(id));
+                    let options = DeleteOptions {
+                        force: self.config.delete_force,
+                        volumes: self.config.delete_volumes,
+                    };
+                    let mut gui_state = self.gui_state.lock();
+                    gui_state.set_delete_options(options);
+                    gui_state.set_delete_containers(vec![id]);
+                }
+                DockerMessage::CopyFrom((id, path)) => self.copy_from(id, path),
+                DockerMessage::CopyTo((id, source, dir)) => self.copy_to(id, source, dir),
+                DockerMessage::Stop(stop_tx) => {
+                    self.stop();
+                    stop_tx.send(()).ok();
+                    break;
                 }
-                DockerMessage::Control((command, id)) => self.execute_command(command, id).await,
-                DockerMessage::Exec(docker_tx) => {
+                DockerMessage::Control((command, id)) => {
+                    self.execute_command(command, id, None).await;
+                }
+                DockerMessage::Event => self.handle_events().await,
+                DockerMessage::Exec((_, docker_tx)) => {
                     docker_tx.send(Arc::clone(&self.docker)).ok();
                 }
+                DockerMessage::DiskUsage => {
+                    let uuid = Uuid::new_v4();
+                    GuiState::start_loading_animation(&self.gui_state, uuid);
+                    self.update_disk_usage(false).await;
+                    self.gui_state.lock().stop_loading_animation(uuid);
+                }
+                DockerMessage::Changes(id) => self.changes(id).await,
+                DockerMessage::ImageInspect(id) => self.inspect_image(id).await,
+                DockerMessage::ImageRemove(reference) => self.remove_image(reference).await,
+                DockerMessage::Images => self.update_images().await,
+                DockerMessage::NetworkConnect((id, container)) => {
+                    self.connect_network(id, container, true).await;
+                }
+                DockerMessage::NetworkCreate(name) => self.create_network(name).await,
+                DockerMessage::NetworkDisconnect((id, container)) => {
+                    self.connect_network(id, container, false).await;
+                }
+                DockerMessage::NetworkRemove(id) => self.remove_network(id).await,
+                DockerMessage::Networks => self.update_networks().await,
+                DockerMessage::ProcessKill((signal, id, process)) => {
+                    self.kill_process(signal, id, process).await;
+                }
+                DockerMessage::Processes(id) => {
+                    let uuid = Uuid::new_v4();
+                    GuiState::start_loading_animation(&self.gui_state, uuid);
+                    self.update_processes(&id).await;
+                    self.gui_state.lock().stop_loading_animation(uuid);
+                }
+                DockerMessage::Prune(preview) => self.prune(preview).await,
+                DockerMessage::PrunePreview(kind) => self.prune_preview(kind).await,
+                DockerMessage::Logs => self.watch_selected_logs(),
                 DockerMessage::Update => self.update_everything().await,
+                DockerMessage::VolumeRemove(name) => self.remove_volume(name).await,
+                DockerMessage::Volumes => {
+                    self.update_volume_sizes().await;
+                    self.update_volumes().await;
+                }
+                DockerMessage::Kill((signal, id)) => {
+                    self.execute_command(DockerCommand::Kill, id, Some(signal))
+                        .await;
+                }
                 DockerMessage::Inspect(id) => {
                     let t = self
                         .docker
                         .inspect_container(id.get(), Some(InspectContainerOptions { size: true }))
                         .await;
                     if let Ok(t) = t {
-                        self.app_data.lock().set_inspect_data(t);
+                        self.app_data.lock().set_inspect_data(InspectData::from(t));
                         self.gui_state.lock().status_push(Status::Inspect);
                     } else {
                         // Set error here, can't inspect container
@@ -429,8 +1814,17 @@ impl DockerData {
         }
     }
 
+    /// Abort every spawn, and remove all of this hosts containers, used when switching Docker context
+    fn stop(&self) {
+        for handle in self.tasks.iter().chain(self.spawns.lock().values()) {
+            handle.abort();
+        }
+        self.spawns.lock().clear();
+        self.app_data.lock().remove_host(&self.host);
+    }
+
     /// Send an update message every x ms, where x is the args.docker_interval
-    fn heartbeat(config: &Config, docker_tx: Sender<DockerMessage>) {
+    fn heartbeat(config: &Config, docker_tx: Sender<DockerMessage>) -> AbortHandle {
         let update_duration =
             std::time::Duration::from_millis(u64::from(config.docker_interval_ms));
         let mut now = std::time::Instant::now();
@@ -442,13 +1836,16 @@ impl DockerData {
                 }
                 now = std::time::Instant::now();
             }
-        });
+        })
+        .abort_handle()
     }
 
     /// Initialise self, and start the message receiving loop
+    /// Each Docker host has its own DockerData, which will only handle the containers of that host
     pub async fn start(
         app_data: Arc<Mutex<AppData>>,
         docker: Docker,
+        host: ContainerHost,
         docker_rx: Receiver<DockerMessage>,
         docker_tx: Sender<DockerMessage>,
         gui_state: Arc<Mutex<GuiState>>,
@@ -458,14 +1855,30 @@ impl DockerData {
             let mut inner = Self {
                 app_data,
                 config: args,
-                binate: Binate::One,
                 docker: Arc::new(docker),
+                events_connected: Arc::new(AtomicBool::new(false)),
                 gui_state,
+                host,
+                last_disk_usage: None,
+                last_relist: None,
+                pending_events: Arc::new(Mutex::new(HashMap::new())),
                 receiver: docker_rx,
-                spawns: Arc::new(Mutex::new(HashSet::new())),
+                reconnect: None,
+                spawns: Arc::new(Mutex::new(HashMap::new())),
+                summaries: vec![],
+                tasks: vec![],
+                volume_sizes: HashMap::new(),
             };
+            // Subscribe before the initial container list, so that no events are missed, they'll queue until the message handler is started
+            inner.tasks.push(Self::events(
+                Arc::clone(&inner.docker),
+                docker_tx.clone(),
+                Arc::clone(&inner.events_connected),
+                Arc::clone(&inner.pending_events),
+                inner.host.clone(),
+            ));
             inner.initialise_container_data().await;
-            Self::heartbeat(&inner.config, docker_tx);
+            inner.tasks.push(Self::heartbeat(&inner.config, docker_tx));
             inner.message_handler().await;
         }
     }
@@ -476,7 +1889,9 @@ impl DockerData {
 #[allow(clippy::float_cmp)]
 mod tests {
 
-    use bollard::secret::{ContainerCpuStats, ContainerCpuUsage};
+    use bollard::secret::{
+        ContainerBlkioStatEntry, ContainerBlkioStats, ContainerCpuUsage, ContainerThrottlingData,
+    };
 
     use super::*;
 
@@ -662,4 +2077,85 @@ mod tests {
         let cpu_percentage = DockerData::calculate_usage(&stats);
         assert_eq!(175.0, cpu_percentage);
     }
+
+    #[test]
+    /// Block I/O is summed across every device, regardless of op casing, with other ops ignored
+    fn test_calculate_disk_io() {
+        let mut stats = gen_stats();
+        assert_eq!(DockerData::calculate_disk_io(&stats), (0, 0));
+
+        let entry = |op: &str, value: u64| ContainerBlkioStatEntry {
+            major: Some(8),
+            minor: Some(0),
+            op: Some(op.to_owned()),
+            value: Some(value),
+        };
+        stats.blkio_stats = Some(ContainerBlkioStats {
+            io_service_bytes_recursive: Some(vec![
+                entry("Read", 100),
+                entry("Write", 200),
+                entry("read", 1000),
+                entry("write", 2000),
+                entry("Total", 3300),
+            ]),
+            io_serviced_recursive: None,
+            io_queue_recursive: None,
+            io_service_time_recursive: None,
+            io_wait_time_recursive: None,
+            io_merged_recursive: None,
+            io_time_recursive: None,
+            sectors_recursive: None,
+        });
+        assert_eq!(DockerData::calculate_disk_io(&stats), (1100, 2200));
+    }
+
+    #[test]
+    /// Throttling totals taken from cpu_stats, recent values are the difference from precpu_stats
+    fn test_calculate_throttling() {
+        let mut stats = gen_stats();
+        assert_eq!(
+            DockerData::calculate_throttling(&stats),
+            CpuThrottling::default()
+        );
+
+        let throttling_data = |periods: u64, throttled_periods: u64, throttled_time: u64| {
+            Some(ContainerThrottlingData {
+                periods: Some(periods),
+                throttled_periods: Some(throttled_periods),
+                throttled_time: Some(throttled_time),
+            })
+        };
+        if let Some(cpu_stats) = stats.cpu_stats.as_mut() {
+            cpu_stats.throttling_data = throttling_data(150, 60, 3_000_000_000);
+        }
+        if let Some(precpu_stats) = stats.precpu_stats.as_mut() {
+            precpu_stats.throttling_data = throttling_data(100, 20, 1_000_000_000);
+        }
+        let result = DockerData::calculate_throttling(&stats);
+        assert_eq!(
+            result,
+            CpuThrottling {
+                throttled_periods: 60,
+                throttled_time: 3_000_000_000,
+                recent_periods: 50,
+                recent_throttled_periods: 40,
+            }
+        );
+        assert_eq!(result.recent_percentage(), Some(80));
+        assert_eq!(result.to_string(), "3.00s/60");
+    }
+
+    #[test]
+    /// Reconnect delay doubles after every failed attempt, up to the maximum
+    fn test_reconnect_backoff() {
+        let mut reconnect = Reconnect::new();
+        assert_eq!(reconnect.delay, RECONNECT_MIN);
+        assert!(reconnect.next > Instant::now());
+
+        let expected = [2, 4, 8, 16, 30, 30];
+        for secs in expected {
+            reconnect.backoff();
+            assert_eq!(reconnect.delay, Duration::from_secs(secs));
+        }
+    }
 }
@@ -0,0 +1,374 @@
+use std::{collections::HashMap, sync::Arc};
+
+use bollard::Docker;
+use parking_lot::Mutex;
+use tokio::sync::mpsc::{Receiver, Sender};
+use uuid::Uuid;
+
+use super::{ConnectDiagnostics, DockerData, DockerMessage, RouterMessage};
+use crate::{
+    DOCKER_HOST,
+    app_data::{AppData, ContainerHost, ContainerId, DockerCommand, Signal},
+    app_error::AppError,
+    config::{Config, DockerContext},
+    ui::{GuiState, Status},
+};
+
+/// Forward messages from the input handler to the DockerData of the correct host
+/// Container specific messages are sent to the host that the container belongs to, host specific messages to that host, and an Update is sent to every host
+/// Also in charge of connecting to the Docker hosts, and switching between Docker contexts
+pub struct DockerRouter {
+    app_data: Arc<Mutex<AppData>>,
+    config: Config,
+    gui_state: Arc<Mutex<GuiState>>,
+    receiver: Receiver<RouterMessage>,
+    senders: HashMap<ContainerHost, Sender<DockerMessage>>,
+}
+
+impl DockerRouter {
+    /// Get the container id of a message, if the message is container specific
+    const fn get_id(message: &DockerMessage) -> Option<&ContainerId> {
+        match message {
+            DockerMessage::Changes(id)
+            | DockerMessage::ConfirmDelete(id)
+            | DockerMessage::CopyFrom((id, _))
+            | DockerMessage::CopyTo((id, _, _))
+            | DockerMessage::Control((_, id))
+            | DockerMessage::Exec((id, _))
+            | DockerMessage::Inspect(id)
+            | DockerMessage::Kill((_, id))
+            | DockerMessage::ProcessKill((_, id, _))
+            | DockerMessage::Processes(id) => Some(id),
+            DockerMessage::HostBatch(_)
+            | DockerMessage::DiskUsage
+            | DockerMessage::Event
+            | DockerMessage::ImageInspect(_)
+            | DockerMessage::ImageRemove(_)
+            | DockerMessage::Images
+            | DockerMessage::Logs
+            | DockerMessage::NetworkConnect(_)
+            | DockerMessage::NetworkCreate(_)
+            | DockerMessage::NetworkDisconnect(_)
+            | DockerMessage::NetworkRemove(_)
+            | DockerMessage::Networks
+            | DockerMessage::Prune(_)
+            | DockerMessage::PrunePreview(_)
+            | DockerMessage::Stop(_)
+            | DockerMessage::Update
+            | DockerMessage::VolumeRemove(_)
+            | DockerMessage::Volumes => None,
+        }
+    }
+
+    /// Get the message to send to every host, for a message that isn't container specific
+    const fn get_broadcast(message: &DockerMessage) -> DockerMessage {
+        match message {
+            DockerMessage::DiskUsage => DockerMessage::DiskUsage,
+            DockerMessage::Images => DockerMessage::Images,
+            DockerMessage::Logs => DockerMessage::Logs,
+            DockerMessage::Networks => DockerMessage::Networks,
+            DockerMessage::Volumes => DockerMessage::Volumes,
+            _ => DockerMessage::Update,
+        }
+    }
+
+    /// Split a batch of containers by host, and send each host only the containers that belong to it
+    /// The results of every host are merged, so that a single info box, or error, is shown once every host has finished
+    /// Names are read before the commands are sent, as a deleted container will no longer have a name
+    async fn send_batch(
+        &self,
+        command: DockerCommand,
+        signal: Option<Signal>,
+        ids: Vec<ContainerId>,
+    ) {
+        let mut names = HashMap::new();
+        let mut failed = vec![];
+        let mut by_host: HashMap<ContainerHost, Vec<ContainerId>> = HashMap::new();
+        for id in &ids {
+            let mut app_data = self.app_data.lock();
+            names.insert(
+                id.clone(),
+                app_data
+                    .get_container_name_by_id(id)
+                    .map_or_else(|| id.get().to_owned(), |name| name.get().to_owned()),
+            );
+            if let Some(host) = app_data.get_host_by_id(id) {
+                by_host.entry(host).or_default().push(id.clone());
+            } else {
+                failed.push(id.clone());
+            }
+        }
+
+        let uuid = Uuid::new_v4();
+        GuiState::start_loading_animation(&self.gui_state, uuid);
+        let mut results = vec![];
+        for (host, host_ids) in by_host {
+            let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+            if let Some(sender) = self.senders.get(&host)
+                && sender
+                    .send(DockerMessage::HostBatch((
+                        command,
+                        signal,
+                        host_ids.clone(),
+                        result_tx,
+                    )))
+                    .await
+                    .is_ok()
+            {
+                results.push((host_ids, result_rx));
+            } else {
+                failed.extend(host_ids);
+            }
+        }
+
+        let (app_data, gui_state) = (Arc::clone(&self.app_data), Arc::clone(&self.gui_state));
+        tokio::spawn(async move {
+            for (host_ids, result_rx) in results {
+                // A host that went away before replying has failed every one of its containers
+                failed.extend(result_rx.await.unwrap_or(host_ids));
+            }
+            let get_names = |ids: &[ContainerId]| {
+                ids.iter()
+                    .filter_map(|id| names.get(id).cloned())
+                    .collect::<Vec<_>>()
+            };
+            if failed.is_empty() {
+                gui_state.lock().set_info_box(&format!(
+                    "✓ {command} {} containers: {}",
+                    ids.len(),
+                    get_names(&ids).join(", ")
+                ));
+            } else {
+                app_data.lock().set_error(
+                    AppError::DockerBatch((command, ids.len(), get_names(&failed))),
+                    &gui_state,
+                    Status::Error,
+                );
+            }
+            gui_state.lock().stop_loading_animation(uuid);
+        });
+    }
+
+    /// Read the docker host paths, a `None` host uses the bollard defaults
+    /// Bollard will use DOCKER_HOST env, so might be pointless here, although it will fix it's priority over any config setting
+    fn get_hosts(config: &Config) -> Vec<ContainerHost> {
+        if !config.hosts.is_empty() {
+            config
+                .hosts
+                .iter()
+                .map(|i| ContainerHost::from(Some(i.to_owned())))
+                .collect()
+        } else if let Ok(env) = std::env::var(DOCKER_HOST)
+            && !env.trim().is_empty()
+        {
+            vec![ContainerHost::from(Some(env))]
+        } else {
+            vec![ContainerHost::default()]
+        }
+    }
+
+    /// Create a client for a host, only returning it if the API version can be negotiated, and a ping returns non-error
+    async fn ping(host: &ContainerHost, config: &Config) -> Option<Docker> {
+        let docker = super::connect(host, config)
+            .ok()?
+            .negotiate_version()
+            .await
+            .ok()?;
+        docker.ping().await.is_ok().then_some(docker)
+    }
+
+    /// Connect to every host in the config, only keeping those where a ping returns non-error
+    /// If the default host can't be connected to, the well-known rootless Docker & Podman sockets are tried in turn
+    /// Also returns the hosts that couldn't be connected to
+    async fn connect(config: &Config) -> (Vec<(ContainerHost, Docker)>, Vec<ContainerHost>) {
+        let mut connected = vec![];
+        let mut failed = vec![];
+        for host in Self::get_hosts(config) {
+            if let Some(docker) = Self::ping(&host, config).await {
+                connected.push((host, docker));
+            } else if host == ContainerHost::default()
+                && let Some(discovered) = Self::discover(config).await
+            {
+                connected.push(discovered);
+            } else {
+                failed.push(host);
+            }
+        }
+        (connected, failed)
+    }
+
+    /// Connect to the first well-known socket that responds to a ping
+    async fn discover(config: &Config) -> Option<(ContainerHost, Docker)> {
+        for host in super::discover_sockets() {
+            if let Some(docker) = Self::ping(&host, config).await {
+                return Some((host, docker));
+            }
+        }
+        None
+    }
+
+    /// Get the socket that was discovered, rather than configured, if any, so that it can be displayed to the user
+    fn get_discovered(config: &Config, connected: &[(ContainerHost, Docker)]) -> Option<String> {
+        let hosts = Self::get_hosts(config);
+        connected
+            .iter()
+            .find(|(host, _)| !hosts.contains(host))
+            .and_then(|(host, _)| host.get().map(ToOwned::to_owned))
+    }
+
+    /// Spawn a DockerData for each connected host
+    /// Every host is marked as initialising before any are spawned, so that Status::Init isn't cleared by whichever host finishes first
+    fn spawn(&mut self, connected: Vec<(ContainerHost, Docker)>) {
+        for (host, _) in &connected {
+            self.gui_state.lock().init_start(host);
+        }
+        for (host, docker) in connected {
+            let (host_tx, host_rx) = tokio::sync::mpsc::channel(32);
+            tokio::spawn(DockerData::start(
+                Arc::clone(&self.app_data),
+                docker,
+                host.clone(),
+                host_rx,
+                host_tx.clone(),
+                Arc::clone(&self.gui_state),
+            ));
+            self.senders.insert(host, host_tx);
+        }
+    }
+
+    /// Join the failed hosts into a single string, to be displayed in an info box
+    fn join_failed(failed: &[ContainerHost]) -> String {
+        failed
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Switch to a different Docker context
+    /// The new context is connected to before any current DockerData is stopped, so a failed switch leaves the current connections untouched
+    async fn switch_context(&mut self, name: String) {
+        let Some(context) = DockerContext::find(&name) else {
+            self.gui_state
+                .lock()
+                .set_info_box(&format!("✖ unknown context {name}"));
+            return;
+        };
+        if let Some(reason) = context.unsupported() {
+            self.gui_state.lock().set_info_box(&format!("✖ {reason}"));
+            return;
+        }
+        let config = context.apply(&self.config);
+        let (connected, failed) = Self::connect(&config).await;
+        if connected.is_empty() {
+            self.gui_state
+                .lock()
+                .set_info_box(&format!("✖ unable to connect to context {name}"));
+            return;
+        }
+
+        for (_, sender) in self.senders.drain() {
+            let (stop_tx, stop_rx) = tokio::sync::oneshot::channel();
+            if sender.send(DockerMessage::Stop(stop_tx)).await.is_ok() {
+                stop_rx.await.ok();
+            }
+        }
+
+        let discovered = Self::get_discovered(&config, &connected);
+        self.app_data.lock().config = config;
+        self.spawn(connected);
+        let text = if let Some(socket) = discovered {
+            format!("✓ context {name}, connected to {socket}")
+        } else if failed.is_empty() {
+            format!("✓ context {name}")
+        } else {
+            format!(
+                "✓ context {name}, unable to connect to {}",
+                Self::join_failed(&failed)
+            )
+        };
+        self.gui_state.lock().set_info_box(&text);
+    }
+
+    /// Connect to the Docker hosts, resolving the current Docker context, and then start the message forwarding loop
+    /// Only if no hosts can be connected to is an error set
+    pub async fn start(
+        app_data: Arc<Mutex<AppData>>,
+        gui_state: Arc<Mutex<GuiState>>,
+        receiver: Receiver<RouterMessage>,
+    ) {
+        let base_config = app_data.lock().config.clone();
+        let resolved = DockerContext::resolve(&base_config);
+        let unsupported = resolved.as_ref().and_then(DockerContext::unsupported);
+        let context = resolved.filter(|_| unsupported.is_none());
+        let config = context
+            .as_ref()
+            .map_or_else(|| base_config.clone(), |i| i.apply(&base_config));
+        app_data.lock().config = config.clone();
+
+        let (connected, failed) = Self::connect(&config).await;
+        if connected.is_empty() {
+            let diagnostics = ConnectDiagnostics::new(&failed, &config).await;
+            app_data.lock().set_error(
+                AppError::DockerConnect,
+                &gui_state,
+                Status::DockerConnect(diagnostics),
+            );
+            return;
+        }
+        if let Some(reason) = unsupported {
+            gui_state.lock().set_info_box(&format!("✖ {reason}"));
+        } else if base_config.hosts.is_empty()
+            && let Some(name) = base_config.context.as_ref()
+            && context.is_none()
+        {
+            gui_state
+                .lock()
+                .set_info_box(&format!("✖ unknown context {name}"));
+        } else if !failed.is_empty() {
+            gui_state.lock().set_info_box(&format!(
+                "✖ unable to connect to {}",
+                Self::join_failed(&failed)
+            ));
+        } else if let Some(socket) = Self::get_discovered(&config, &connected) {
+            gui_state
+                .lock()
+                .set_info_box(&format!("✓ connected to {socket}"));
+        }
+
+        let mut inner = Self {
+            app_data,
+            config: base_config,
+            gui_state,
+            receiver,
+            senders: HashMap::new(),
+        };
+        inner.spawn(connected);
+
+        while let Some(message) = inner.receiver.recv().await {
+            match message {
+                RouterMessage::Context(name) => inner.switch_context(name).await,
+                RouterMessage::Batch((command, signal, ids)) => {
+                    inner.send_batch(command, signal, ids).await;
+                }
+                RouterMessage::Host((host, message)) => {
+                    if let Some(sender) = inner.senders.get(&host) {
+                        sender.send(message).await.ok();
+                    }
+                }
+                RouterMessage::Docker(message) => {
+                    if let Some(id) = Self::get_id(&message) {
+                        let host = inner.app_data.lock().get_host_by_id(id);
+                        if let Some(sender) = host.and_then(|host| inner.senders.get(&host)) {
+                            sender.send(message).await.ok();
+                        }
+                    } else {
+                        for sender in inner.senders.values() {
+                            sender.send(Self::get_broadcast(&message)).await.ok();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -18,8 +18,10 @@ use tokio::{
 use tokio_util::sync::CancellationToken;
 
 use crate::{
-    app_data::{AppData, ContainerId, RunningState, State},
+    app_data::{AppData, ContainerHost, ContainerId, RunningState, State},
     app_error::AppError,
+    config::{Config, DockerScheme},
+    docker_data::{TlsPaths, get_scheme},
 };
 
 /// TTY location
@@ -41,6 +43,50 @@ mod command {
     pub const EXEC: &str = "exec";
     pub const SH: &str = "sh";
     pub const IT: &str = "-it";
+    pub const HOST: &str = "--host";
+    pub const UNIX: &str = "unix://";
+    pub const TCP: &str = "tcp://";
+    pub const TLS_VERIFY: &str = "--tlsverify";
+    pub const TLS_CA: &str = "--tlscacert";
+    pub const TLS_CERT: &str = "--tlscert";
+    pub const TLS_KEY: &str = "--tlskey";
+}
+
+/// Generate the docker cli global arguments needed to target a given host, with TLS arguments if the host uses https
+fn docker_cli_args(host: &ContainerHost, config: &Config) -> Vec<String> {
+    let mut output = vec![];
+    if let Some(host) = host.get() {
+        let host = if host.starts_with('/') {
+            format!("{}{host}", command::UNIX)
+        } else if let Some(addr) = host
+            .strip_prefix("https://")
+            .or_else(|| host.strip_prefix("http://"))
+        {
+            format!("{}{addr}", command::TCP)
+        } else {
+            host.to_owned()
+        };
+        output.extend([command::HOST.to_owned(), host]);
+    }
+    if get_scheme(host, config) == DockerScheme::Https {
+        let tls = TlsPaths::new(host, config);
+        output.push(command::TLS_VERIFY.to_owned());
+        for (arg, path) in [
+            (command::TLS_CA, tls.ca),
+            (command::TLS_CERT, tls.cert),
+            (command::TLS_KEY, tls.key),
+        ] {
+            output.extend([arg.to_owned(), path.display().to_string()]);
+        }
+    }
+    output
+}
+
+/// Create a docker cli command, with the given global arguments
+fn docker_cli(cli_args: &[String]) -> std::process::Command {
+    let mut cmd = std::process::Command::new(command::DOCKER);
+    cmd.args(cli_args);
+    cmd
 }
 
 /// Currently known byte output after writing KEYBOARD_PROTO to stdout
@@ -152,7 +198,7 @@ pub enum ExecMode {
     // use Bollard Rust library
     Internal((Arc<ContainerId>, Arc<Docker>)),
     // use the external `docker-cli`
-    External(Arc<ContainerId>),
+    External((Arc<ContainerId>, Arc<[String]>)),
 }
 
 impl ExecMode {
@@ -164,10 +210,13 @@ impl ExecMode {
             return None;
         }
 
-        let use_cli = app_data.lock().config.use_cli;
-        let container = app_data.lock().get_selected_container_id_state_name();
+        let config = app_data.lock().config.clone();
+        let container = app_data
+            .lock()
+            .get_selected_container()
+            .map(|i| (i.id.clone(), i.state, i.host.clone()));
 
-        if let Some((id, state, _)) = container
+        if let Some((id, state, host)) = container
             && [
                 State::Running(RunningState::Healthy),
                 State::Running(RunningState::Unhealthy),
@@ -175,7 +224,7 @@ impl ExecMode {
             .contains(&state)
         {
             if tty_readable()
-                && !use_cli
+                && !config.use_cli
                 && let Ok(exec) = docker
                     .create_exec(
                         id.get(),
@@ -195,23 +244,24 @@ impl ExecMode {
                 return Some(Self::Internal((Arc::new(id), Arc::clone(docker))));
             }
 
-            if let Ok(output) = std::process::Command::new(command::DOCKER)
+            let cli_args = docker_cli_args(&host, &config);
+            if let Ok(output) = docker_cli(&cli_args)
                 .args([command::EXEC, id.get(), command::PWD])
                 .output()
                 && let Ok(output) = String::from_utf8(output.stdout)
                 && !output.starts_with(OCI_ERROR)
             {
-                return Some(Self::External(Arc::new(id)));
+                return Some(Self::External((Arc::new(id), Arc::from(cli_args))));
             }
         }
         None
     }
 
     /// exec into the container using the external docker cli, the result it just piped into oxker
-    fn exec_external(id: &ContainerId) {
+    fn exec_external(id: &ContainerId, cli_args: &[String]) {
         let mut stdout = std::io::stdout();
         stdout.write_all(CURSOR_POS.as_bytes()).ok();
-        if let Ok(mut child) = std::process::Command::new(command::DOCKER)
+        if let Ok(mut child) = docker_cli(cli_args)
             .args([command::EXEC, command::IT, id.get(), command::SH])
             .stdin(std::process::Stdio::inherit())
             .stdout(std::process::Stdio::inherit())
@@ -349,8 +399,8 @@ impl ExecMode {
 
     pub async fn run(&self, tty_size: Option<Size>) -> Result<(), AppError> {
         match self {
-            Self::External(id) => {
-                Self::exec_external(id);
+            Self::External((id, cli_args)) => {
+                Self::exec_external(id, cli_args);
                 Ok(())
             }
 
@@ -19,12 +19,17 @@ use uuid::Uuid;
 
 mod message;
 use crate::{
-    app_data::{AppData, DockerCommand, Header, ScrollDirection},
+    app_data::{
+        AppData, ContainerHost, ContainerId, DiskUsageHeader, DockerCommand, Header, ProcessHeader,
+        PruneKind, ScrollDirection, Signal,
+    },
     app_error::AppError,
-    config,
-    docker_data::DockerMessage,
+    config::{self, DockerContext},
+    docker_data::{DockerMessage, RouterMessage, host_path},
     exec::{ExecMode, tty_readable},
-    ui::{DeleteButton, GuiState, SelectablePanel, Status, Ui},
+    ui::{
+        Confirm, DeleteButton, DeleteOptions, GuiState, PromptAction, SelectablePanel, Status, Ui,
+    },
 };
 pub use message::InputMessages;
 
@@ -32,7 +37,7 @@ pub use message::InputMessages;
 #[derive(Debug)]
 pub struct InputHandler {
     app_data: Arc<Mutex<AppData>>,
-    docker_tx: Sender<DockerMessage>,
+    docker_tx: Sender<RouterMessage>,
     keymap: config::Keymap,
     gui_state: Arc<Mutex<GuiState>>,
     is_running: Arc<AtomicBool>,
@@ -44,7 +49,7 @@ impl InputHandler {
     /// Initialize self, and running the message handling loop
     pub async fn start(
         app_data: Arc<Mutex<AppData>>,
-        docker_tx: Sender<DockerMessage>,
+        docker_tx: Sender<RouterMessage>,
         gui_state: Arc<Mutex<GuiState>>,
         is_running: Arc<AtomicBool>,
         rx: Receiver<InputMessages>,
@@ -63,8 +68,10 @@ impl InputHandler {
     }
 
     /// check for incoming messages
+    /// If the selected container has changed, every host is told to follow the logs of the newly selected container, and abort the stream of the previous one
     async fn message_handler(&mut self) {
         while let Some(message) = self.rx.recv().await {
+            let selected = self.app_data.lock().get_selected_container_id();
             match message {
                 InputMessages::ButtonPress(key) => self.button_press(key.0, key.1).await,
                 InputMessages::MouseEvent((mouse_event, modifider)) => {
@@ -73,17 +80,28 @@ impl InputHandler {
 
                     if contains(Status::DeleteConfirm) {
                         self.button_intersect(mouse_event).await;
+                    } else if contains(Status::Confirm) {
+                        self.confirm_intersect(mouse_event).await;
                     } else if !contains(Status::Error)
                         && !contains(Status::Help)
                         && !contains(Status::DeleteConfirm)
+                        && !contains(Status::Confirm)
                         && !contains(Status::Filter)
                         && !contains(Status::SearchLogs)
+                        && !contains(Status::ContextPicker)
+                        && !contains(Status::SignalPicker)
+                        && !contains(Status::NetworkPicker)
+                        && !contains(Status::BulkAction)
+                        && !contains(Status::Prompt)
                     {
                         // TODO handle state where you want to scroll log search results with the mouse wheel
                         self.mouse_press(mouse_event, modifider);
                     }
                 }
             }
+            if self.app_data.lock().get_selected_container_id() != selected {
+                self.docker_tx.send(DockerMessage::Logs.into()).await.ok();
+            }
         }
     }
 
@@ -103,38 +121,102 @@ impl InputHandler {
         }
     }
 
-    /// This is executed from the Delete Confirm dialog, and will send an internal message to actually remove the given container
+    /// This is executed from the Delete Confirm dialog, and will send an internal message to actually remove the given container(s)
+    /// If the dialog was opened by a project Down, the project networks are removed as well
     async fn confirm_delete(&self) {
-        let id = self.gui_state.lock().get_delete_container();
-        if let Some(id) = id {
-            self.docker_tx
-                .send(DockerMessage::Control((DockerCommand::Delete, id)))
-                .await
-                .ok();
-        }
+        let (ids, command) = {
+            let gui_state = self.gui_state.lock();
+            let command = if gui_state.is_delete_down() {
+                DockerCommand::Down
+            } else {
+                DockerCommand::Delete
+            };
+            (gui_state.get_delete_containers(), command)
+        };
+        self.send_command(command, None, ids).await;
     }
 
-    /// This is executed from the Delete Confirm dialog, and will clear the delete_container information (removes id and closes panel)
+    /// This is executed from the Delete Confirm dialog, and will clear the delete_containers information (removes ids and closes panel)
     fn clear_delete(&self) {
-        self.gui_state.lock().set_delete_container(None);
+        self.gui_state.lock().set_delete_containers(vec![]);
+    }
+
+    /// The delete options set in the config, used when a delete popup is opened
+    fn config_delete_options(&self) -> DeleteOptions {
+        let config = &self.app_data.lock().config;
+        DeleteOptions {
+            force: config.delete_force,
+            volumes: config.delete_volumes,
+        }
+    }
+
+    /// Send a docker command to the given containers, a single container is sent as a Control (or Kill) message, multiple containers as a Batch
+    async fn send_command(
+        &self,
+        command: DockerCommand,
+        signal: Option<Signal>,
+        mut ids: Vec<ContainerId>,
+    ) {
+        let message = if ids.len() > 1 {
+            RouterMessage::Batch((command, signal, ids))
+        } else if let Some(id) = ids.pop() {
+            signal
+                .map_or(DockerMessage::Control((command, id.clone())), |signal| {
+                    DockerMessage::Kill((signal, id))
+                })
+                .into()
+        } else {
+            return;
+        };
+        self.docker_tx.send(message).await.ok();
     }
 
     async fn inspect_key(&self) {
         self.app_data.lock().clear_inspect_data();
         let selected = self.app_data.lock().get_selected_container().cloned();
         if let Some(g) = selected {
-            self.docker_tx.send(DockerMessage::Inspect(g.id)).await.ok();
+            self.docker_tx
+                .send(DockerMessage::Inspect(g.id).into())
+                .await
+                .ok();
+        }
+    }
+
+    /// Get the filesystem changes of the selected container, the changes view is only shown once the changes have been received
+    async fn changes_key(&self) {
+        self.app_data.lock().clear_changes_data();
+        let selected = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = selected {
+            self.docker_tx
+                .send(DockerMessage::Changes(id).into())
+                .await
+                .ok();
+        }
+    }
+
+    /// Open the path prompt for copying a path from, or to, the selected container
+    fn copy_key(&self, action: fn(ContainerId) -> PromptAction) {
+        let selected = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = selected {
+            self.gui_state.lock().set_prompt(Some(action(id)));
         }
     }
 
     /// Validate that one can exec into a Docker container
     async fn exec_key(&self) {
         let is_oxker = self.app_data.lock().is_oxker();
-        if !is_oxker && tty_readable() {
+        let selected = self.app_data.lock().get_selected_container_id();
+        if !is_oxker
+            && tty_readable()
+            && let Some(id) = selected
+        {
             let uuid = Uuid::new_v4();
             GuiState::start_loading_animation(&self.gui_state, uuid);
             let (sx, rx) = tokio::sync::oneshot::channel();
-            self.docker_tx.send(DockerMessage::Exec(sx)).await.ok();
+            self.docker_tx
+                .send(DockerMessage::Exec((id, sx)).into())
+                .await
+                .ok();
 
             if let Ok(docker) = rx.await {
                 (ExecMode::new(&self.app_data, &docker).await).map_or_else(
@@ -190,7 +272,9 @@ impl InputHandler {
             && let Some(log_path) = args.dir_save
         {
             let (sx, rx) = tokio::sync::oneshot::channel();
-            self.docker_tx.send(DockerMessage::Exec(sx)).await?;
+            self.docker_tx
+                .send(DockerMessage::Exec((id.clone(), sx)).into())
+                .await?;
 
             let now = SystemTime::now()
                 .duration_since(SystemTime::UNIX_EPOCH)
@@ -264,6 +348,7 @@ impl InputHandler {
     }
 
     /// Send docker command, if the Commands panel is selected
+    /// If the Containers panel is selected, and grouped by project, collapse or expand the selected project
     async fn enter_key(&self) {
         // This isn't great, just means you can't send docker commands before full initialization of the program
         let panel = self.gui_state.lock().get_selected_panel();
@@ -271,27 +356,65 @@ impl InputHandler {
             let option_command = self.app_data.lock().selected_docker_controls();
 
             if let Some(command) = option_command {
+                // When a project header is selected, the command is sent to every container in the project
+                let project_ids = self.app_data.lock().get_selected_project_ids();
+                if !project_ids.is_empty() {
+                    if command == DockerCommand::Down {
+                        let options = DeleteOptions {
+                            force: true,
+                            ..self.config_delete_options()
+                        };
+                        let mut gui_state = self.gui_state.lock();
+                        gui_state.set_delete_options(options);
+                        gui_state.set_delete_containers(project_ids);
+                        gui_state.set_delete_down();
+                    } else {
+                        self.send_command(command, None, project_ids).await;
+                    }
+                    return;
+                }
                 // Poor way of disallowing commands to be sent to a containerised okxer
                 if self.app_data.lock().is_oxker_in_container() {
                     return;
                 }
+                // When containers are marked, the command is sent to every marked container, rather than the selected container
+                let marked = self.app_data.lock().get_marked();
+                if !marked.is_empty() {
+                    match command {
+                        DockerCommand::Delete => {
+                            let options = self.config_delete_options();
+                            let mut gui_state = self.gui_state.lock();
+                            gui_state.set_delete_options(options);
+                            gui_state.set_delete_containers(marked);
+                        }
+                        DockerCommand::Kill => self.gui_state.lock().set_kill_containers(marked),
+                        _ => self.send_command(command, None, marked).await,
+                    }
+                    return;
+                }
                 let option_id = self.app_data.lock().get_selected_container_id();
                 if let Some(id) = option_id {
                     match command {
                         DockerCommand::Delete => self
                             .docker_tx
-                            .send(DockerMessage::ConfirmDelete(id))
+                            .send(DockerMessage::ConfirmDelete(id).into())
                             .await
                             .ok(),
+                        DockerCommand::Kill => {
+                            self.gui_state.lock().set_kill_containers(vec![id]);
+                            None
+                        }
 
                         _ => self
                             .docker_tx
-                            .send(DockerMessage::Control((command, id)))
+                            .send(DockerMessage::Control((command, id)).into())
                             .await
                             .ok(),
                     };
                 }
             }
+        } else if panel == SelectablePanel::Containers {
+            self.app_data.lock().toggle_project_collapsed();
         }
     }
 
@@ -396,6 +519,102 @@ impl InputHandler {
             || self.keymap.clear.1 == Some(key_code)
         {
             self.clear_delete();
+        } else if self.keymap.delete_force.0 == key_code
+            || self.keymap.delete_force.1 == Some(key_code)
+        {
+            self.gui_state
+                .lock()
+                .toggle_delete_option(DeleteButton::Force);
+        } else if self.keymap.delete_volumes.0 == key_code
+            || self.keymap.delete_volumes.1 == Some(key_code)
+        {
+            self.gui_state
+                .lock()
+                .toggle_delete_option(DeleteButton::Volumes);
+        }
+    }
+
+    /// Actions to take when BulkAction status active, Enter sends the selected command to every filtered container
+    async fn handle_bulk_action(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Enter => {
+                let (ids, command) = {
+                    let gui_state = self.gui_state.lock();
+                    (
+                        gui_state.get_bulk_containers(),
+                        gui_state.get_selected_bulk_command(),
+                    )
+                };
+                self.gui_state.lock().set_bulk_containers(vec![]);
+                if let Some(command) = command {
+                    // The bulk action popup is the confirmation, so a delete uses the configured delete options
+                    if command == DockerCommand::Delete {
+                        let options = self.config_delete_options();
+                        self.gui_state.lock().set_delete_options(options);
+                    }
+                    self.send_command(command, None, ids).await;
+                }
+            }
+            _ if self.keymap.clear.0 == key_code || self.keymap.clear.1 == Some(key_code) => {
+                self.gui_state.lock().set_bulk_containers(vec![]);
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.gui_state
+                    .lock()
+                    .bulk_commands_scroll(&ScrollDirection::Down);
+            }
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.gui_state
+                    .lock()
+                    .bulk_commands_scroll(&ScrollDirection::Up);
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when SignalPicker status active, Enter sends the selected signal to the container(s), or to the selected process
+    async fn handle_signal_picker(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Enter => {
+                let (ids, process, signal) = {
+                    let gui_state = self.gui_state.lock();
+                    (
+                        gui_state.get_kill_containers(),
+                        gui_state.get_kill_process(),
+                        gui_state.get_selected_signal(),
+                    )
+                };
+                self.gui_state.lock().status_del(Status::SignalPicker);
+                if let Some(signal) = signal {
+                    if let Some((id, process)) = process {
+                        self.docker_tx
+                            .send(DockerMessage::ProcessKill((signal, id, process)).into())
+                            .await
+                            .ok();
+                    } else {
+                        self.send_command(DockerCommand::Kill, Some(signal), ids)
+                            .await;
+                    }
+                }
+            }
+            _ if self.keymap.clear.0 == key_code || self.keymap.clear.1 == Some(key_code) => {
+                self.gui_state.lock().status_del(Status::SignalPicker);
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().signals_scroll(&ScrollDirection::Down);
+            }
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().signals_scroll(&ScrollDirection::Up);
+            }
+            _ => (),
         }
     }
 
@@ -513,140 +732,868 @@ impl InputHandler {
         }
     }
 
-    /// Actions to take when Filter status active
-    fn handle_filter(&self, key_code: KeyCode) {
+    /// Actions to take when Changes status active, scrolling is shared with the inspect view
+    fn handle_changes(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
         match key_code {
-            KeyCode::Esc => {
-                self.app_data.lock().filter_term_clear();
-                self.gui_state.lock().status_del(Status::Filter);
-            }
-            _ if KeyCode::Enter == key_code
-                || self.keymap.filter_mode.0 == key_code
-                || self.keymap.filter_mode.1 == Some(key_code) =>
+            _ if self.keymap.changes.0 == key_code
+                || self.keymap.changes.1 == Some(key_code)
+                || self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code) =>
             {
-                self.gui_state.lock().status_del(Status::Filter);
-            }
-            KeyCode::Backspace => {
-                self.app_data.lock().filter_term_pop();
-            }
-            KeyCode::Char(x) => {
-                self.app_data.lock().filter_term_push(x);
+                self.app_data.lock().clear_changes_data();
+                self.gui_state.lock().clear_inspect_offset();
+                self.gui_state.lock().status_del(Status::Changes);
             }
-            KeyCode::Right => {
-                self.app_data.lock().filter_by_next();
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.inspect_scroll(modifier, &ScrollDirection::Down);
             }
-            KeyCode::Left => {
-                self.app_data.lock().filter_by_prev();
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.inspect_scroll(modifier, &ScrollDirection::Up);
             }
-            _ => (),
-        }
-    }
-
-    /// Handle input that refers to the sorting of columns
-    fn handle_sort(&self, key_code: KeyCode) {
-        match key_code {
-            _ if self.keymap.force_redraw.0 == key_code
-                || self.keymap.force_redraw.1 == Some(key_code) =>
+            _ if self.keymap.scroll_forward.0 == key_code
+                || self.keymap.scroll_forward.1 == Some(key_code) =>
             {
-                self.gui_state.lock().set_clear();
+                self.inspect_scroll(modifier, &ScrollDirection::Right);
             }
-            _ if self.keymap.sort_reset.0 == key_code
-                || self.keymap.sort_reset.1 == Some(key_code) =>
+            _ if self.keymap.scroll_back.0 == key_code
+                || self.keymap.scroll_back.1 == Some(key_code) =>
             {
-                self.app_data.lock().reset_sorted();
+                self.inspect_scroll(modifier, &ScrollDirection::Left);
             }
-
-            _ if self.keymap.sort_by_name.0 == key_code
-                || self.keymap.sort_by_name.1 == Some(key_code) =>
+            _ if self.keymap.toggle_mouse_capture.0 == key_code
+                || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
             {
-                self.sort(Header::Name);
+                self.mouse_capture_key();
             }
-
-            _ if self.keymap.sort_by_state.0 == key_code
-                || self.keymap.sort_by_state.1 == Some(key_code) =>
+            _ if self.keymap.scroll_start.0 == key_code
+                || self.keymap.scroll_start.1 == Some(key_code) =>
             {
-                self.sort(Header::State);
+                self.gui_state.lock().clear_inspect_offset();
             }
-
-            _ if self.keymap.sort_by_status.0 == key_code
-                || self.keymap.sort_by_status.1 == Some(key_code) =>
+            _ if self.keymap.scroll_end.0 == key_code
+                || self.keymap.scroll_end.1 == Some(key_code) =>
             {
-                self.sort(Header::Status);
+                self.gui_state.lock().set_inspect_offset_y_to_max();
             }
+            _ => (),
+        }
+    }
 
-            _ if self.keymap.sort_by_cpu.0 == key_code
-                || self.keymap.sort_by_cpu.1 == Some(key_code) =>
+    /// Scroll the images list, the scroll_many modifier scrolls 10 images at a time
+    fn images_scroll(&self, modifier: KeyModifiers, sd: &ScrollDirection) {
+        for _ in 0..self.get_modifier_total(modifier) {
+            self.app_data.lock().images_scroll(sd);
+        }
+    }
+
+    /// Inspect the selected image, the image is inspected on its own host, as the same image id can exist on multiple hosts
+    async fn image_inspect_key(&self) {
+        self.app_data.lock().clear_inspect_data();
+        let selected = self
+            .app_data
+            .lock()
+            .get_selected_image()
+            .map(|i| (i.host.clone(), i.id.clone()));
+        if let Some((host, id)) = selected {
+            self.docker_tx
+                .send(RouterMessage::Host((host, DockerMessage::ImageInspect(id))))
+                .await
+                .ok();
+        }
+    }
+
+    /// Actions to take when Images status active
+    async fn handle_images(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Enter => self.image_inspect_key().await,
+            _ if self.keymap.images.0 == key_code
+                || self.keymap.images.1 == Some(key_code)
+                || self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code) =>
             {
-                self.sort(Header::Cpu);
+                self.gui_state.lock().status_del(Status::Images);
             }
-            _ if self.keymap.sort_by_memory.0 == key_code
-                || self.keymap.sort_by_memory.1 == Some(key_code) =>
+            _ if self.keymap.inspect.0 == key_code || self.keymap.inspect.1 == Some(key_code) => {
+                self.image_inspect_key().await;
+            }
+            _ if self.keymap.remove.0 == key_code || self.keymap.remove.1 == Some(key_code) => {
+                let selected = self.app_data.lock().get_selected_image().cloned();
+                if let Some(image) = selected {
+                    self.gui_state
+                        .lock()
+                        .set_confirm(Some(Confirm::RemoveImage(image)));
+                }
+            }
+            _ if self.keymap.prune.0 == key_code || self.keymap.prune.1 == Some(key_code) => {
+                let host = self
+                    .app_data
+                    .lock()
+                    .get_selected_image()
+                    .map(|i| i.host.clone())
+                    .unwrap_or_default();
+                self.prune_key(host, PruneKind::Images).await;
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
             {
-                self.sort(Header::Memory);
+                self.images_scroll(modifier, &ScrollDirection::Down);
             }
-            _ if self.keymap.sort_by_id.0 == key_code
-                || self.keymap.sort_by_id.1 == Some(key_code) =>
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
             {
-                self.sort(Header::Id);
+                self.images_scroll(modifier, &ScrollDirection::Up);
             }
-            _ if self.keymap.sort_by_image.0 == key_code
-                || self.keymap.sort_by_image.1 == Some(key_code) =>
+            _ if self.keymap.scroll_start.0 == key_code
+                || self.keymap.scroll_start.1 == Some(key_code) =>
             {
-                self.sort(Header::Image);
+                self.app_data.lock().images_start();
             }
-
-            _ if self.keymap.sort_by_rx.0 == key_code
-                || self.keymap.sort_by_rx.1 == Some(key_code) =>
+            _ if self.keymap.scroll_end.0 == key_code
+                || self.keymap.scroll_end.1 == Some(key_code) =>
             {
-                self.sort(Header::Rx);
+                self.app_data.lock().images_end();
             }
-
-            _ if self.keymap.sort_by_tx.0 == key_code
-                || self.keymap.sort_by_tx.1 == Some(key_code) =>
+            _ if self.keymap.toggle_mouse_capture.0 == key_code
+                || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
             {
-                self.sort(Header::Tx);
+                self.mouse_capture_key();
             }
             _ => (),
         }
     }
 
-    // Increase the log panel height
-    fn log_panel_height_increase(&self) {
-        self.gui_state.lock().log_height_increase();
-    }
-
-    // Decrease the log panel height
-    fn log_panel_height_decrease(&self) {
-        self.gui_state.lock().log_height_decrease();
+    /// Ask the host for a preview of what would be pruned, the confirm popup is only shown once the preview has been received
+    async fn prune_key(&self, host: ContainerHost, kind: PruneKind) {
+        self.docker_tx
+            .send(RouterMessage::Host((
+                host,
+                DockerMessage::PrunePreview(kind),
+            )))
+            .await
+            .ok();
     }
 
-    // Toggle visibility of the log panel
-    fn log_panel_toggle(&self) {
-        self.gui_state.lock().toggle_show_logs();
+    /// Scroll the volumes list, the scroll_many modifier scrolls 10 volumes at a time
+    fn volumes_scroll(&self, modifier: KeyModifiers, sd: &ScrollDirection) {
+        for _ in 0..self.get_modifier_total(modifier) {
+            self.app_data.lock().volumes_scroll(sd);
+        }
     }
 
-    /// Handle button presses in all other scenarios
-    #[allow(clippy::cognitive_complexity)]
-    async fn handle_others(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
-        self.handle_sort(key_code);
-        // shift key plus arrows
+    /// Actions to take when Volumes status active
+    async fn handle_volumes(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
         match key_code {
-            _ if self.keymap.exec.0 == key_code || self.keymap.exec.1 == Some(key_code) => {
-                self.exec_key().await;
+            _ if self.keymap.volumes.0 == key_code
+                || self.keymap.volumes.1 == Some(key_code)
+                || self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_del(Status::Volumes);
+            }
+            _ if self.keymap.remove.0 == key_code || self.keymap.remove.1 == Some(key_code) => {
+                let selected = self.app_data.lock().get_selected_volume().cloned();
+                if let Some(volume) = selected {
+                    self.gui_state
+                        .lock()
+                        .set_confirm(Some(Confirm::RemoveVolume(volume)));
+                }
             }
-
-            _ if self.keymap.toggle_help.0 == key_code
-                || self.keymap.toggle_help.1 == Some(key_code) =>
+            _ if self.keymap.prune.0 == key_code || self.keymap.prune.1 == Some(key_code) => {
+                let host = self
+                    .app_data
+                    .lock()
+                    .get_selected_volume()
+                    .map(|i| i.host.clone())
+                    .unwrap_or_default();
+                self.prune_key(host, PruneKind::Volumes).await;
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
             {
-                self.gui_state.lock().status_push(Status::Help);
+                self.volumes_scroll(modifier, &ScrollDirection::Down);
+            }
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.volumes_scroll(modifier, &ScrollDirection::Up);
+            }
+            _ if self.keymap.scroll_start.0 == key_code
+                || self.keymap.scroll_start.1 == Some(key_code) =>
+            {
+                self.app_data.lock().volumes_start();
+            }
+            _ if self.keymap.scroll_end.0 == key_code
+                || self.keymap.scroll_end.1 == Some(key_code) =>
+            {
+                self.app_data.lock().volumes_end();
             }
-
             _ if self.keymap.toggle_mouse_capture.0 == key_code
                 || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
             {
                 self.mouse_capture_key();
             }
-            _ if self.keymap.log_section_height_decrease.0 == key_code
+            _ => (),
+        }
+    }
+
+    /// Scroll the disk usage categories, the scroll_many modifier scrolls 10 categories at a time
+    fn disk_usage_scroll(&self, modifier: KeyModifiers, sd: &ScrollDirection) {
+        for _ in 0..self.get_modifier_total(modifier) {
+            self.app_data.lock().disk_usage_scroll(sd);
+        }
+    }
+
+    /// Actions to take when DiskUsage status active
+    async fn handle_disk_usage(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        match key_code {
+            _ if self.keymap.disk_usage.0 == key_code
+                || self.keymap.disk_usage.1 == Some(key_code)
+                || self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_del(Status::DiskUsage);
+            }
+            _ if self.keymap.prune.0 == key_code || self.keymap.prune.1 == Some(key_code) => {
+                let selected = self
+                    .app_data
+                    .lock()
+                    .get_selected_disk_usage()
+                    .map(|i| (i.host.clone(), i.kind));
+                if let Some((host, kind)) = selected {
+                    self.prune_key(host, PruneKind::from(kind)).await;
+                }
+            }
+            _ if self.keymap.select_next_panel.0 == key_code
+                || self.keymap.select_next_panel.1 == Some(key_code)
+                || self.keymap.select_previous_panel.0 == key_code
+                || self.keymap.select_previous_panel.1 == Some(key_code) =>
+            {
+                self.app_data.lock().toggle_disk_usage_focus();
+            }
+            _ if self.keymap.sort_by_name.0 == key_code
+                || self.keymap.sort_by_name.1 == Some(key_code) =>
+            {
+                self.app_data
+                    .lock()
+                    .set_disk_usage_sort_by_header(DiskUsageHeader::Name);
+            }
+            _ if self.keymap.sort_by_memory.0 == key_code
+                || self.keymap.sort_by_memory.1 == Some(key_code) =>
+            {
+                self.app_data
+                    .lock()
+                    .set_disk_usage_sort_by_header(DiskUsageHeader::Size);
+            }
+            _ if self.keymap.sort_reset.0 == key_code
+                || self.keymap.sort_reset.1 == Some(key_code) =>
+            {
+                self.app_data.lock().set_disk_usage_sorted(None);
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.disk_usage_scroll(modifier, &ScrollDirection::Down);
+            }
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.disk_usage_scroll(modifier, &ScrollDirection::Up);
+            }
+            _ if self.keymap.scroll_start.0 == key_code
+                || self.keymap.scroll_start.1 == Some(key_code) =>
+            {
+                self.app_data.lock().disk_usage_start();
+            }
+            _ if self.keymap.scroll_end.0 == key_code
+                || self.keymap.scroll_end.1 == Some(key_code) =>
+            {
+                self.app_data.lock().disk_usage_end();
+            }
+            _ if self.keymap.toggle_mouse_capture.0 == key_code
+                || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
+            {
+                self.mouse_capture_key();
+            }
+            _ => (),
+        }
+    }
+
+    /// Scroll the networks list, the scroll_many modifier scrolls 10 networks at a time
+    fn networks_scroll(&self, modifier: KeyModifiers, sd: &ScrollDirection) {
+        for _ in 0..self.get_modifier_total(modifier) {
+            self.app_data.lock().networks_scroll(sd);
+        }
+    }
+
+    /// Open the network container picker, listing every container on the same host as the selected network
+    fn network_connect_key(&self) {
+        let ids = {
+            let app_data = self.app_data.lock();
+            let Some(network) = app_data.get_selected_network() else {
+                return;
+            };
+            app_data.get_host_container_ids(&network.host)
+        };
+        if ids.is_empty() {
+            self.gui_state
+                .lock()
+                .set_info_box("✖ no containers on the same host as the network");
+        } else {
+            self.gui_state.lock().set_network_containers(ids);
+        }
+    }
+
+    /// Actions to take when NetworkPicker status active
+    /// The picker stays open, so that several containers can be connected, or disconnected, in turn
+    async fn handle_network_picker(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Enter => {
+                let selected = self.gui_state.lock().get_selected_network_container();
+                let network = self.app_data.lock().get_selected_network().cloned();
+                if let (Some(id), Some(network)) = (selected, network) {
+                    let message = if network.contains(&id) {
+                        DockerMessage::NetworkDisconnect((network.id, id))
+                    } else {
+                        DockerMessage::NetworkConnect((network.id, id))
+                    };
+                    self.docker_tx
+                        .send(RouterMessage::Host((network.host, message)))
+                        .await
+                        .ok();
+                }
+            }
+            _ if self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code)
+                || self.keymap.connect.0 == key_code
+                || self.keymap.connect.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_del(Status::NetworkPicker);
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.gui_state
+                    .lock()
+                    .network_containers_scroll(&ScrollDirection::Down);
+            }
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.gui_state
+                    .lock()
+                    .network_containers_scroll(&ScrollDirection::Up);
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when Networks status active
+    async fn handle_networks(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        match key_code {
+            _ if self.keymap.networks.0 == key_code
+                || self.keymap.networks.1 == Some(key_code)
+                || self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_del(Status::Networks);
+            }
+            _ if self.keymap.create.0 == key_code || self.keymap.create.1 == Some(key_code) => {
+                // New networks are created on the host of the selected network, or the default host if there aren't any networks
+                let host = self
+                    .app_data
+                    .lock()
+                    .get_selected_network()
+                    .map(|i| i.host.clone())
+                    .unwrap_or_default();
+                self.gui_state
+                    .lock()
+                    .set_prompt(Some(PromptAction::CreateNetwork(host)));
+            }
+            _ if self.keymap.remove.0 == key_code || self.keymap.remove.1 == Some(key_code) => {
+                let selected = self.app_data.lock().get_selected_network().cloned();
+                if let Some(network) = selected {
+                    if network.is_predefined() {
+                        self.gui_state.lock().set_info_box(&format!(
+                            "✖ {} is a predefined network and can't be removed",
+                            network.name
+                        ));
+                    } else {
+                        self.gui_state
+                            .lock()
+                            .set_confirm(Some(Confirm::RemoveNetwork(network)));
+                    }
+                }
+            }
+            _ if self.keymap.connect.0 == key_code || self.keymap.connect.1 == Some(key_code) => {
+                self.network_connect_key();
+            }
+            _ if self.keymap.prune.0 == key_code || self.keymap.prune.1 == Some(key_code) => {
+                let host = self
+                    .app_data
+                    .lock()
+                    .get_selected_network()
+                    .map(|i| i.host.clone())
+                    .unwrap_or_default();
+                self.prune_key(host, PruneKind::Networks).await;
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.networks_scroll(modifier, &ScrollDirection::Down);
+            }
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.networks_scroll(modifier, &ScrollDirection::Up);
+            }
+            _ if self.keymap.scroll_start.0 == key_code
+                || self.keymap.scroll_start.1 == Some(key_code) =>
+            {
+                self.app_data.lock().networks_start();
+            }
+            _ if self.keymap.scroll_end.0 == key_code
+                || self.keymap.scroll_end.1 == Some(key_code) =>
+            {
+                self.app_data.lock().networks_end();
+            }
+            _ if self.keymap.toggle_mouse_capture.0 == key_code
+                || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
+            {
+                self.mouse_capture_key();
+            }
+            _ => (),
+        }
+    }
+
+    /// Scroll the processes list, the scroll_many modifier scrolls 10 processes at a time
+    fn processes_scroll(&self, modifier: KeyModifiers, sd: &ScrollDirection) {
+        for _ in 0..self.get_modifier_total(modifier) {
+            self.app_data.lock().processes_scroll(sd);
+        }
+    }
+
+    /// Close the processes view, and stop refreshing the processes
+    fn processes_close(&self) {
+        self.gui_state.lock().status_del(Status::Processes);
+        self.app_data.lock().set_processes_container(None);
+    }
+
+    /// Open the processes view for the selected container
+    async fn processes_key(&self) {
+        let id = self
+            .app_data
+            .lock()
+            .get_selected_container()
+            .map(|i| i.id.clone());
+        if let Some(id) = id {
+            self.app_data
+                .lock()
+                .set_processes_container(Some(id.clone()));
+            self.gui_state.lock().status_push(Status::Processes);
+            self.docker_tx
+                .send(DockerMessage::Processes(id).into())
+                .await
+                .ok();
+        }
+    }
+
+    /// Actions to take when Processes status active, the sort keys sort the processes rather than the containers
+    /// Enter opens the signal picker for the selected process
+    fn handle_processes(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        let sort = |header: ProcessHeader| {
+            self.app_data.lock().set_process_sort_by_header(header);
+        };
+        match key_code {
+            KeyCode::Enter => {
+                let selected = {
+                    let app_data = self.app_data.lock();
+                    app_data
+                        .get_processes_container()
+                        .cloned()
+                        .zip(app_data.get_selected_process().cloned())
+                };
+                if selected.is_some() {
+                    self.gui_state.lock().set_kill_process(selected);
+                }
+            }
+            _ if self.keymap.processes.0 == key_code
+                || self.keymap.processes.1 == Some(key_code)
+                || self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code) =>
+            {
+                self.processes_close();
+            }
+            _ if self.keymap.sort_reset.0 == key_code
+                || self.keymap.sort_reset.1 == Some(key_code) =>
+            {
+                self.app_data.lock().set_process_sorted(None);
+            }
+            _ if self.keymap.sort_by_id.0 == key_code
+                || self.keymap.sort_by_id.1 == Some(key_code) =>
+            {
+                sort(ProcessHeader::Pid);
+            }
+            _ if self.keymap.sort_by_name.0 == key_code
+                || self.keymap.sort_by_name.1 == Some(key_code) =>
+            {
+                sort(ProcessHeader::User);
+            }
+            _ if self.keymap.sort_by_cpu.0 == key_code
+                || self.keymap.sort_by_cpu.1 == Some(key_code) =>
+            {
+                sort(ProcessHeader::Cpu);
+            }
+            _ if self.keymap.sort_by_memory.0 == key_code
+                || self.keymap.sort_by_memory.1 == Some(key_code) =>
+            {
+                sort(ProcessHeader::Memory);
+            }
+            _ if self.keymap.sort_by_image.0 == key_code
+                || self.keymap.sort_by_image.1 == Some(key_code) =>
+            {
+                sort(ProcessHeader::Command);
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.processes_scroll(modifier, &ScrollDirection::Down);
+            }
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.processes_scroll(modifier, &ScrollDirection::Up);
+            }
+            _ if self.keymap.scroll_start.0 == key_code
+                || self.keymap.scroll_start.1 == Some(key_code) =>
+            {
+                self.app_data.lock().processes_start();
+            }
+            _ if self.keymap.scroll_end.0 == key_code
+                || self.keymap.scroll_end.1 == Some(key_code) =>
+            {
+                self.app_data.lock().processes_end();
+            }
+            _ if self.keymap.toggle_mouse_capture.0 == key_code
+                || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
+            {
+                self.mouse_capture_key();
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when Prompt status active, every char is part of the user input, so only Esc and Enter are special
+    async fn handle_prompt(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => self.gui_state.lock().set_prompt(None),
+            KeyCode::Enter => {
+                let prompt = self.gui_state.lock().get_prompt();
+                let Some(prompt) = prompt else {
+                    return;
+                };
+                let text = prompt.text.trim().to_owned();
+                if text.is_empty() {
+                    return;
+                }
+                self.gui_state.lock().set_prompt(None);
+                let message = match prompt.action {
+                    PromptAction::CopyFromContainer(id) => {
+                        DockerMessage::CopyFrom((id, text)).into()
+                    }
+                    // Check the host path before asking for the directory in the container to copy it into
+                    PromptAction::CopyToContainer(id) => {
+                        let source = host_path(&text);
+                        if source.exists() {
+                            self.gui_state
+                                .lock()
+                                .set_prompt(Some(PromptAction::CopyToContainerDir((id, source))));
+                        } else {
+                            self.gui_state
+                                .lock()
+                                .set_info_box(&format!("✖ {text} not found"));
+                        }
+                        return;
+                    }
+                    PromptAction::CopyToContainerDir((id, source)) => {
+                        DockerMessage::CopyTo((id, source, text)).into()
+                    }
+                    PromptAction::CreateNetwork(host) => {
+                        RouterMessage::Host((host, DockerMessage::NetworkCreate(text)))
+                    }
+                };
+                self.docker_tx.send(message).await.ok();
+            }
+            KeyCode::Backspace => self.gui_state.lock().prompt_pop(),
+            KeyCode::Char(c) => self.gui_state.lock().prompt_push(c),
+            _ => (),
+        }
+    }
+
+    /// Send the confirmed action to Docker, and close the confirm popup
+    async fn confirm_action(&self) {
+        let confirm = self.gui_state.lock().get_confirm();
+        self.gui_state.lock().set_confirm(None);
+        let message = match confirm {
+            Some(Confirm::Prune(preview)) => (preview.host.clone(), DockerMessage::Prune(preview)),
+            Some(Confirm::RemoveImage(image)) => (
+                image.host.clone(),
+                DockerMessage::ImageRemove(image.get_reference().to_owned()),
+            ),
+            Some(Confirm::RemoveNetwork(network)) => {
+                (network.host, DockerMessage::NetworkRemove(network.id))
+            }
+            Some(Confirm::RemoveVolume(volume)) => {
+                (volume.host, DockerMessage::VolumeRemove(volume.name))
+            }
+            None => return,
+        };
+        self.docker_tx.send(RouterMessage::Host(message)).await.ok();
+    }
+
+    /// Actions to take when Confirm status active
+    async fn handle_confirm(&self, key_code: KeyCode) {
+        if self.keymap.delete_confirm.0 == key_code
+            || self.keymap.delete_confirm.1 == Some(key_code)
+        {
+            self.confirm_action().await;
+        } else if self.keymap.delete_deny.0 == key_code
+            || self.keymap.delete_deny.1 == Some(key_code)
+            || self.keymap.clear.0 == key_code
+            || self.keymap.clear.1 == Some(key_code)
+        {
+            self.gui_state.lock().set_confirm(None);
+        } else if self.keymap.scroll_down.0 == key_code
+            || self.keymap.scroll_down.1 == Some(key_code)
+        {
+            self.gui_state
+                .lock()
+                .set_confirm_offset(&ScrollDirection::Down);
+        } else if self.keymap.scroll_up.0 == key_code || self.keymap.scroll_up.1 == Some(key_code) {
+            self.gui_state
+                .lock()
+                .set_confirm_offset(&ScrollDirection::Up);
+        }
+    }
+
+    /// Open the context picker, with the currently active context selected
+    fn context_picker_key(&self) {
+        let contexts = DockerContext::list()
+            .into_iter()
+            .map(|i| i.name)
+            .collect::<Vec<_>>();
+        let current = self
+            .app_data
+            .lock()
+            .config
+            .context
+            .clone()
+            .or_else(DockerContext::current_name);
+        self.gui_state
+            .lock()
+            .set_contexts(contexts, current.as_deref());
+    }
+
+    /// Actions to take when ContextPicker status active
+    async fn handle_context_picker(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Enter => {
+                let selected = self.gui_state.lock().get_selected_context();
+                self.gui_state.lock().status_del(Status::ContextPicker);
+                if let Some(name) = selected {
+                    self.docker_tx.send(RouterMessage::Context(name)).await.ok();
+                }
+            }
+            _ if self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code)
+                || self.keymap.context_picker.0 == key_code
+                || self.keymap.context_picker.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_del(Status::ContextPicker);
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.gui_state
+                    .lock()
+                    .contexts_scroll(&ScrollDirection::Down);
+            }
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().contexts_scroll(&ScrollDirection::Up);
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when Filter status active
+    fn handle_filter(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => {
+                self.app_data.lock().filter_term_clear();
+                self.gui_state.lock().status_del(Status::Filter);
+            }
+            _ if KeyCode::Enter == key_code
+                || self.keymap.filter_mode.0 == key_code
+                || self.keymap.filter_mode.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_del(Status::Filter);
+            }
+            KeyCode::Backspace => {
+                self.app_data.lock().filter_term_pop();
+            }
+            KeyCode::Char(x) => {
+                self.app_data.lock().filter_term_push(x);
+            }
+            KeyCode::Right => {
+                self.app_data.lock().filter_by_next();
+            }
+            KeyCode::Left => {
+                self.app_data.lock().filter_by_prev();
+            }
+            _ => (),
+        }
+    }
+
+    /// Handle input that refers to the sorting of columns
+    fn handle_sort(&self, key_code: KeyCode) {
+        match key_code {
+            _ if self.keymap.force_redraw.0 == key_code
+                || self.keymap.force_redraw.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().set_clear();
+            }
+            _ if self.keymap.sort_reset.0 == key_code
+                || self.keymap.sort_reset.1 == Some(key_code) =>
+            {
+                self.app_data.lock().reset_sorted();
+            }
+
+            _ if self.keymap.sort_by_name.0 == key_code
+                || self.keymap.sort_by_name.1 == Some(key_code) =>
+            {
+                self.sort(Header::Name);
+            }
+
+            _ if self.keymap.sort_by_state.0 == key_code
+                || self.keymap.sort_by_state.1 == Some(key_code) =>
+            {
+                self.sort(Header::State);
+            }
+
+            _ if self.keymap.sort_by_status.0 == key_code
+                || self.keymap.sort_by_status.1 == Some(key_code) =>
+            {
+                self.sort(Header::Status);
+            }
+
+            _ if self.keymap.sort_by_cpu.0 == key_code
+                || self.keymap.sort_by_cpu.1 == Some(key_code) =>
+            {
+                self.sort(Header::Cpu);
+            }
+            _ if self.keymap.sort_by_memory.0 == key_code
+                || self.keymap.sort_by_memory.1 == Some(key_code) =>
+            {
+                self.sort(Header::Memory);
+            }
+            _ if self.keymap.sort_by_id.0 == key_code
+                || self.keymap.sort_by_id.1 == Some(key_code) =>
+            {
+                self.sort(Header::Id);
+            }
+            _ if self.keymap.sort_by_image.0 == key_code
+                || self.keymap.sort_by_image.1 == Some(key_code) =>
+            {
+                self.sort(Header::Image);
+            }
+
+            _ if self.keymap.sort_by_rx.0 == key_code
+                || self.keymap.sort_by_rx.1 == Some(key_code) =>
+            {
+                self.sort(Header::Rx);
+            }
+
+            _ if self.keymap.sort_by_tx.0 == key_code
+                || self.keymap.sort_by_tx.1 == Some(key_code) =>
+            {
+                self.sort(Header::Tx);
+            }
+
+            _ if self.keymap.sort_by_disk_read.0 == key_code
+                || self.keymap.sort_by_disk_read.1 == Some(key_code) =>
+            {
+                self.sort(Header::DiskRead);
+            }
+
+            _ if self.keymap.sort_by_disk_write.0 == key_code
+                || self.keymap.sort_by_disk_write.1 == Some(key_code) =>
+            {
+                self.sort(Header::DiskWrite);
+            }
+
+            _ if self.keymap.sort_by_host.0 == key_code
+                || self.keymap.sort_by_host.1 == Some(key_code) =>
+            {
+                self.sort(Header::Host);
+            }
+
+            _ if self.keymap.sort_by_pids.0 == key_code
+                || self.keymap.sort_by_pids.1 == Some(key_code) =>
+            {
+                self.sort(Header::Pids);
+            }
+
+            _ if self.keymap.sort_by_throttled.0 == key_code
+                || self.keymap.sort_by_throttled.1 == Some(key_code) =>
+            {
+                self.sort(Header::Throttled);
+            }
+            _ => (),
+        }
+    }
+
+    // Increase the log panel height
+    fn log_panel_height_increase(&self) {
+        self.gui_state.lock().log_height_increase();
+    }
+
+    // Decrease the log panel height
+    fn log_panel_height_decrease(&self) {
+        self.gui_state.lock().log_height_decrease();
+    }
+
+    // Toggle visibility of the log panel
+    fn log_panel_toggle(&self) {
+        self.gui_state.lock().toggle_show_logs();
+    }
+
+    /// Handle button presses in all other scenarios
+    #[allow(clippy::cognitive_complexity)]
+    async fn handle_others(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        self.handle_sort(key_code);
+        // shift key plus arrows
+        match key_code {
+            _ if self.keymap.exec.0 == key_code || self.keymap.exec.1 == Some(key_code) => {
+                self.exec_key().await;
+            }
+
+            _ if self.keymap.toggle_help.0 == key_code
+                || self.keymap.toggle_help.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_push(Status::Help);
+            }
+
+            _ if self.keymap.toggle_mouse_capture.0 == key_code
+                || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
+            {
+                self.mouse_capture_key();
+            }
+            _ if self.keymap.log_section_height_decrease.0 == key_code
                 || self.keymap.log_section_height_decrease.1 == Some(key_code) =>
             {
                 self.log_panel_height_decrease();
@@ -674,6 +1621,84 @@ impl InputHandler {
                 self.inspect_key().await;
             }
 
+            _ if self.keymap.context_picker.0 == key_code
+                || self.keymap.context_picker.1 == Some(key_code) =>
+            {
+                self.context_picker_key();
+            }
+
+            _ if self.keymap.bulk_action.0 == key_code
+                || self.keymap.bulk_action.1 == Some(key_code) =>
+            {
+                let filtered = self.app_data.lock().get_filtered();
+                self.gui_state.lock().set_bulk_containers(filtered);
+            }
+
+            _ if self.keymap.group_projects.0 == key_code
+                || self.keymap.group_projects.1 == Some(key_code) =>
+            {
+                self.app_data.lock().toggle_grouped();
+            }
+
+            _ if self.keymap.images.0 == key_code || self.keymap.images.1 == Some(key_code) => {
+                self.gui_state.lock().status_push(Status::Images);
+                self.docker_tx.send(DockerMessage::Images.into()).await.ok();
+            }
+
+            _ if self.keymap.disk_usage.0 == key_code
+                || self.keymap.disk_usage.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_push(Status::DiskUsage);
+                self.docker_tx
+                    .send(DockerMessage::DiskUsage.into())
+                    .await
+                    .ok();
+            }
+
+            _ if self.keymap.networks.0 == key_code || self.keymap.networks.1 == Some(key_code) => {
+                self.gui_state.lock().status_push(Status::Networks);
+                self.docker_tx
+                    .send(DockerMessage::Networks.into())
+                    .await
+                    .ok();
+            }
+
+            _ if self.keymap.processes.0 == key_code
+                || self.keymap.processes.1 == Some(key_code) =>
+            {
+                self.processes_key().await;
+            }
+
+            _ if self.keymap.changes.0 == key_code || self.keymap.changes.1 == Some(key_code) => {
+                self.changes_key().await;
+            }
+
+            _ if self.keymap.copy_from.0 == key_code
+                || self.keymap.copy_from.1 == Some(key_code) =>
+            {
+                self.copy_key(PromptAction::CopyFromContainer);
+            }
+
+            _ if self.keymap.copy_to.0 == key_code || self.keymap.copy_to.1 == Some(key_code) => {
+                self.copy_key(PromptAction::CopyToContainer);
+            }
+
+            _ if self.keymap.volumes.0 == key_code || self.keymap.volumes.1 == Some(key_code) => {
+                self.gui_state.lock().status_push(Status::Volumes);
+                self.docker_tx
+                    .send(DockerMessage::Volumes.into())
+                    .await
+                    .ok();
+            }
+
+            _ if self.keymap.mark.0 == key_code || self.keymap.mark.1 == Some(key_code) => {
+                self.app_data.lock().toggle_marked();
+            }
+
+            _ if self.keymap.clear.0 == key_code || self.keymap.clear.1 == Some(key_code) => {
+                self.app_data.lock().clear_marked();
+            }
+
             _ if self.keymap.select_next_panel.0 == key_code
                 || self.keymap.select_next_panel.1 == Some(key_code) =>
             {
@@ -714,7 +1739,7 @@ impl InputHandler {
                 || self.keymap.filter_mode.1 == Some(key_code) =>
             {
                 self.gui_state.lock().status_push(Status::Filter);
-                self.docker_tx.send(DockerMessage::Update).await.ok();
+                self.docker_tx.send(DockerMessage::Update.into()).await.ok();
             }
 
             _ if self.keymap.log_search_mode.0 == key_code
@@ -756,18 +1781,32 @@ impl InputHandler {
         let contains_delete = contains(Status::DeleteConfirm);
         let contains_search_logs = contains(Status::SearchLogs);
         let contains_inspect = contains(Status::Inspect);
+        let contains_changes = contains(Status::Changes);
+        let contains_context_picker = contains(Status::ContextPicker);
+        let contains_signal_picker = contains(Status::SignalPicker);
+        let contains_bulk_action = contains(Status::BulkAction);
+        let contains_confirm = contains(Status::Confirm);
+        let contains_disk_usage = contains(Status::DiskUsage);
+        let contains_images = contains(Status::Images);
+        let contains_volumes = contains(Status::Volumes);
+        let contains_networks = contains(Status::Networks);
+        let contains_network_picker = contains(Status::NetworkPicker);
+        let contains_processes = contains(Status::Processes);
+        let contains_prompt = contains(Status::Prompt);
 
         if !contains_exec {
             let is_q = || key_code == self.keymap.quit.0 || Some(key_code) == self.keymap.quit.1;
             if key_modifier == KeyModifiers::CONTROL && key_code == KeyCode::Char('c')
-                || is_q() && !contains_filter && !contains_search_logs
+                || is_q() && !contains_filter && !contains_search_logs && !contains_prompt
             {
-                // Always just quit on Ctrl + c/C or q/Q, unless in filter/search_logs mode, i.e. when user inmput can include the q key
+                // Always just quit on Ctrl + c/C or q/Q, unless in filter/search_logs/prompt mode, i.e. when user inmput can include the q key
                 self.quit();
             }
 
             if contains_error {
                 self.handle_error(key_code);
+            } else if contains_prompt {
+                self.handle_prompt(key_code).await;
             } else if contains_help {
                 self.handle_help(key_code);
             } else if contains_filter {
@@ -776,8 +1815,30 @@ impl InputHandler {
                 self.handle_search_logs(key_code, key_modifier);
             } else if contains_delete {
                 self.handle_delete(key_code).await;
+            } else if contains_confirm {
+                self.handle_confirm(key_code).await;
             } else if contains_inspect {
                 self.handle_inspect(key_code, key_modifier);
+            } else if contains_changes {
+                self.handle_changes(key_code, key_modifier);
+            } else if contains_context_picker {
+                self.handle_context_picker(key_code).await;
+            } else if contains_signal_picker {
+                self.handle_signal_picker(key_code).await;
+            } else if contains_bulk_action {
+                self.handle_bulk_action(key_code).await;
+            } else if contains_network_picker {
+                self.handle_network_picker(key_code).await;
+            } else if contains_disk_usage {
+                self.handle_disk_usage(key_code, key_modifier).await;
+            } else if contains_images {
+                self.handle_images(key_code, key_modifier).await;
+            } else if contains_networks {
+                self.handle_networks(key_code, key_modifier).await;
+            } else if contains_processes {
+                self.handle_processes(key_code, key_modifier);
+            } else if contains_volumes {
+                self.handle_volumes(key_code, key_modifier).await;
             } else {
                 self.handle_others(key_code, key_modifier).await;
             }
@@ -798,16 +1859,36 @@ impl InputHandler {
                 match button {
                     DeleteButton::Confirm => self.confirm_delete().await,
                     DeleteButton::Cancel => self.clear_delete(),
+                    DeleteButton::Force | DeleteButton::Volumes => {
+                        self.gui_state.lock().toggle_delete_option(button);
+                    }
                 }
             }
         }
     }
 
+    /// Check if a button press interacts with either the yes or no buttons in the confirm window
+    async fn confirm_intersect(&self, mouse_event: MouseEvent) {
+        if mouse_event.kind == MouseEventKind::Down(MouseButton::Left) {
+            let intersect = self.gui_state.lock().get_intersect_button(Rect::new(
+                mouse_event.column,
+                mouse_event.row,
+                1,
+                1,
+            ));
+            match intersect {
+                Some(DeleteButton::Confirm) => self.confirm_action().await,
+                Some(DeleteButton::Cancel) => self.gui_state.lock().set_confirm(None),
+                _ => (),
+            }
+        }
+    }
+
     /// Handle mouse button events
     fn mouse_press(&self, mouse_event: MouseEvent, modifier: KeyModifiers) {
         let status = self.gui_state.lock().get_status();
 
-        if status.contains(&Status::Inspect) {
+        if status.contains(&Status::Inspect) || status.contains(&Status::Changes) {
             match mouse_event.kind {
                 MouseEventKind::ScrollDown => self.inspect_scroll(modifier, &ScrollDirection::Down),
                 MouseEventKind::ScrollUp => self.inspect_scroll(modifier, &ScrollDirection::Up),
@@ -817,6 +1898,44 @@ impl InputHandler {
                 MouseEventKind::ScrollLeft => self.inspect_scroll(modifier, &ScrollDirection::Left),
                 _ => (),
             }
+        } else if status.contains(&Status::DiskUsage) {
+            match mouse_event.kind {
+                MouseEventKind::ScrollDown => {
+                    self.disk_usage_scroll(modifier, &ScrollDirection::Down);
+                }
+                MouseEventKind::ScrollUp => self.disk_usage_scroll(modifier, &ScrollDirection::Up),
+                _ => (),
+            }
+        } else if status.contains(&Status::Images) {
+            match mouse_event.kind {
+                MouseEventKind::ScrollDown => self.images_scroll(modifier, &ScrollDirection::Down),
+                MouseEventKind::ScrollUp => self.images_scroll(modifier, &ScrollDirection::Up),
+                _ => (),
+            }
+        } else if status.contains(&Status::Networks) {
+            match mouse_event.kind {
+                MouseEventKind::ScrollDown => {
+                    self.networks_scroll(modifier, &ScrollDirection::Down);
+                }
+                MouseEventKind::ScrollUp => self.networks_scroll(modifier, &ScrollDirection::Up),
+                _ => (),
+            }
+        } else if status.contains(&Status::Processes) {
+            match mouse_event.kind {
+                MouseEventKind::ScrollDown => {
+                    self.processes_scroll(modifier, &ScrollDirection::Down);
+                }
+                MouseEventKind::ScrollUp => self.processes_scroll(modifier, &ScrollDirection::Up),
+                _ => (),
+            }
+        } else if status.contains(&Status::Volumes) {
+            match mouse_event.kind {
+                MouseEventKind::ScrollDown => {
+                    self.volumes_scroll(modifier, &ScrollDirection::Down);
+                }
+                MouseEventKind::ScrollUp => self.volumes_scroll(modifier, &ScrollDirection::Up),
+                _ => (),
+            }
         } else if status.contains(&Status::Help) {
             let mouse_point = Rect::new(mouse_event.column, mouse_event.row, 1, 1);
             let help_intersect = self.gui_state.lock().get_intersect_help(mouse_point);
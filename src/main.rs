@@ -2,10 +2,7 @@
 // Zigbuild is stuck on 1.87.0, which means Mac builds won't work when using collapsible ifs
 
 use app_data::AppData;
-use app_error::AppError;
-use bollard::{API_DEFAULT_VERSION, Docker};
-use config::Config;
-use docker_data::DockerData;
+use docker_data::DockerRouter;
 use input_handler::InputMessages;
 use parking_lot::Mutex;
 use std::{
@@ -26,9 +23,9 @@ mod exec;
 mod input_handler;
 mod ui;
 
-use ui::{GuiState, Rerender, Status, Ui};
+use ui::{GuiState, Rerender, Ui};
 
-use crate::docker_data::DockerMessage;
+use crate::docker_data::RouterMessage;
 
 /// This is the entry point when running as a Docker Container, and is used, in conjunction with the `CONTAINER_ENV` ENV, to check if we are running as a Docker Container
 const ENTRY_POINT: &str = "/app/oxker";
@@ -42,56 +39,24 @@ fn setup_tracing() {
     tracing_subscriber::fmt().with_max_level(Level::INFO).init();
 }
 
-/// Read the optional docker_host path
-/// Bollard will use DOCKER_HOST env, so might be pointless here, although it will fix it's priority over any config setting
-fn read_docker_host(config: &Config) -> Option<String> {
-    if let Some(x) = &config.host {
-        Some(x.to_string())
-    } else if let Ok(env) = std::env::var(DOCKER_HOST)
-        && !env.trim().is_empty()
-    {
-        Some(env)
-    } else {
-        None
-    }
-}
-
-/// Create docker daemon handler, and only spawn up the docker data handler if a ping returns non-error
-async fn docker_init(
+/// Spawn the docker router, which will connect to every Docker host, and spawn up a docker data handler for each host that can be connected to
+/// Messages from the input handler are routed to the correct docker data handler
+fn docker_init(
     app_data: &Arc<Mutex<AppData>>,
-    docker_rx: Receiver<DockerMessage>,
-    docker_tx: Sender<DockerMessage>,
+    docker_rx: Receiver<RouterMessage>,
     gui_state: &Arc<Mutex<GuiState>>,
 ) {
-    let host = read_docker_host(&app_data.lock().config);
-
-    if let Ok(docker) = host
-        .as_ref()
-        .map_or_else(Docker::connect_with_defaults, |host| {
-            Docker::connect_with_socket(host, 120, API_DEFAULT_VERSION)
-        })
-        && docker.ping().await.is_ok()
-    {
-        tokio::spawn(DockerData::start(
-            Arc::clone(app_data),
-            docker,
-            docker_rx,
-            docker_tx,
-            Arc::clone(gui_state),
-        ));
-    } else {
-        app_data.lock().set_error(
-            AppError::DockerConnect,
-            gui_state,
-            Status::DockerConnect(host),
-        );
-    }
+    tokio::spawn(DockerRouter::start(
+        Arc::clone(app_data),
+        Arc::clone(gui_state),
+        docker_rx,
+    ));
 }
 
 /// Create data for, and then spawn a tokio thread, for the input handler
 fn handler_init(
     app_data: &Arc<Mutex<AppData>>,
-    docker_sx: &Sender<DockerMessage>,
+    docker_sx: &Sender<RouterMessage>,
     gui_state: &Arc<Mutex<GuiState>>,
     input_rx: Receiver<InputMessages>,
     is_running: &Arc<AtomicBool>,
@@ -116,7 +81,7 @@ async fn main() {
     let is_running = Arc::new(AtomicBool::new(true));
     let (docker_tx, docker_rx) = tokio::sync::mpsc::channel(32);
 
-    docker_init(&app_data, docker_rx, docker_tx.clone(), &gui_state).await;
+    docker_init(&app_data, docker_rx, &gui_state);
 
     if config.gui {
         let (input_tx, input_rx) = tokio::sync::mpsc::channel(32);
@@ -160,14 +125,18 @@ async fn main() {
 #[allow(clippy::unwrap_used)]
 mod tests {
 
-    use std::{str::FromStr, sync::Arc};
+    use std::{
+        collections::{HashMap, HashSet},
+        str::FromStr,
+        sync::Arc,
+    };
 
     use bollard::service::{ContainerSummary, PortSummary};
 
     use crate::{
         app_data::{
-            AppData, ContainerId, ContainerItem, ContainerPorts, ContainerStatus, Filter,
-            RunningState, State, StatefulList,
+            AppData, ContainerId, ContainerItem, ContainerPorts, ContainerStatus, DiskUsageItems,
+            Filter, Projects, RunningState, State, StatefulList,
         },
         config::{AppColors, Config, Keymap},
         ui::Rerender,
@@ -178,21 +147,34 @@ mod tests {
         Config {
             app_colors: AppColors::new(),
             color_logs: false,
+            context: None,
+            delete_force: true,
+            delete_volumes: false,
             dir_save: None,
             dir_config: None,
             docker_interval_ms: 1000,
+            docker_scheme: None,
+            docker_timeout: 120,
             gui: true,
-            host: None,
+            hosts: vec![],
+            host_settings: HashMap::new(),
             in_container: false,
             keymap: Keymap::new(),
             log_search_case_sensitive: true,
             raw_logs: false,
+            show_disk_io: false,
             show_logs: true,
+            show_pids: false,
             show_self: false,
             show_std_err: false,
+            show_throttling: false,
             show_timestamp: false,
+            stop_timeout: None,
             timestamp_format: "HH:MM:SS.NNNNN dd-mm-yyyy".to_owned(),
             timezone: None,
+            tls_ca: None,
+            tls_cert: None,
+            tls_key: None,
             use_cli: false,
         }
     }
@@ -216,15 +198,26 @@ mod tests {
 
     pub fn gen_appdata(containers: &[ContainerItem]) -> AppData {
         AppData {
+            changes_data: None,
             containers: StatefulList::new(containers.to_vec()),
             hidden_containers: vec![],
+            images: StatefulList::new(vec![]),
             current_sorted_id: vec![],
+            disconnected: HashSet::new(),
             inspect_data: None,
+            networks: StatefulList::new(vec![]),
+            processes: StatefulList::new(vec![]),
+            processes_container: None,
+            processes_sorted_by: None,
+            disk_usage: StatefulList::new(vec![]),
+            disk_usage_items: DiskUsageItems::default(),
             error: None,
+            projects: Projects::new(),
             sorted_by: None,
             rerender: Arc::new(Rerender::new()),
             filter: Filter::new(),
             config: gen_config(),
+            volumes: StatefulList::new(vec![]),
         }
     }
 
@@ -0,0 +1,167 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, Padding, Paragraph},
+};
+
+use super::{SELECT_ARROW, popup};
+use crate::{
+    app_data::ContainerName,
+    config::AppColors,
+    ui::{FrameData, gui_state::BoxLocation},
+};
+
+/// The maximum number of container names to list, any extra containers are summarised on a single line
+const MAX_NAMES: usize = 10;
+
+/// Draw the bulk action popup in the centre of the screen, listing every filtered container, and the commands that can be applied to all of them
+pub fn draw(colors: AppColors, f: &mut Frame, fd: &FrameData, names: &[ContainerName]) {
+    let mut lines = vec![Line::from(format!("{} filtered containers:", names.len()))];
+    lines.extend(
+        names
+            .iter()
+            .take(MAX_NAMES)
+            .map(|i| Line::from(format!("  {}", i.get()))),
+    );
+    if names.len() > MAX_NAMES {
+        lines.push(Line::from(format!(
+            "  ... and {} more",
+            names.len() - MAX_NAMES
+        )));
+    }
+
+    let items = fd
+        .bulk_commands
+        .items
+        .iter()
+        .map(|i| ListItem::new(i.to_string()).style(Style::default().fg(i.get_color(colors))))
+        .collect::<Vec<_>>();
+
+    let title = " Bulk action ";
+    let max_line_width = lines
+        .iter()
+        .map(Line::width)
+        .max()
+        .unwrap_or_default()
+        .max(title.chars().count())
+        + 4;
+    let names_height = lines.len();
+    let commands_height = items.len();
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .borders(Borders::ALL)
+        .padding(Padding::horizontal(1))
+        .style(
+            Style::default()
+                .bg(colors.popup_help.background)
+                .fg(colors.popup_help.text),
+        );
+
+    let list = List::new(items)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .highlight_symbol(SELECT_ARROW);
+
+    let area = popup::draw(
+        names_height + commands_height + 3,
+        max_line_width,
+        f.area(),
+        BoxLocation::MiddleCentre,
+    );
+    let inner_area = block.inner(area);
+
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(u16::try_from(names_height).unwrap_or_default()),
+            Constraint::Length(1),
+            Constraint::Min(1),
+        ])
+        .split(inner_area);
+
+    let mut state = fd.bulk_commands.state;
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(lines), split_popup[0]);
+    f.render_stateful_widget(list, split_popup[2], &mut state);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Modifier;
+
+    use crate::{
+        app_data::{ContainerId, ContainerName},
+        ui::{
+            FrameData,
+            draw_blocks::tests::{get_result, test_setup},
+        },
+    };
+
+    #[test]
+    /// Bulk action popup lists every container name, with the first command selected
+    fn test_draw_blocks_bulk_action() {
+        let mut setup = test_setup(40, 15, true, true);
+        setup
+            .gui_state
+            .lock()
+            .set_bulk_containers(vec![ContainerId::from("1"), ContainerId::from("2")]);
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    colors,
+                    f,
+                    &fd,
+                    &[
+                        ContainerName::from("worker_1"),
+                        ContainerName::from("worker_2"),
+                    ],
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if row_index == 7 && (11..=16).contains(&result_cell_index) {
+                    assert_eq!(result_cell.fg, colors.commands.stop);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When there are more containers than can be listed, the remaining containers are summarised
+    fn test_draw_blocks_bulk_action_many() {
+        let mut setup = test_setup(40, 24, true, true);
+        let names = (1..=12)
+            .map(|i| ContainerName::from(format!("worker_{i}").as_str()))
+            .collect::<Vec<_>>();
+        setup
+            .gui_state
+            .lock()
+            .set_bulk_containers(vec![ContainerId::from("1")]);
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(colors, f, &fd, &names);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
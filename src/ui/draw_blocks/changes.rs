@@ -0,0 +1,225 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Stylize},
+    text::Line,
+    widgets::Paragraph,
+};
+
+use super::{
+    inspect::{generate_lower_title, title_block},
+    key_text,
+};
+use crate::{
+    app_data::{ChangeKind, ChangeLine, ChangesData},
+    config::{AppColors, Keymap},
+    ui::{GuiState, gui_state::ScrollOffset},
+};
+
+/// Create the upper title, with container name, id, the total of each kind of change, and keymap to clear
+fn generate_upper_title(data: &ChangesData, keymap: &Keymap) -> String {
+    format!(
+        " changes: {} {} - {} added, {} modified, {} deleted - {} or {} to exit ",
+        data.name,
+        data.id,
+        data.added,
+        data.modified,
+        data.deleted,
+        key_text(keymap.clear).replace(" | ", " or "),
+        key_text(keymap.changes).replace(" | ", " or ")
+    )
+}
+
+/// Added, modified, and deleted paths use the same colors as the start, pause, and stop commands
+const fn line_color(colors: AppColors, kind: Option<ChangeKind>) -> Color {
+    match kind {
+        Some(ChangeKind::Added) => colors.commands.start,
+        Some(ChangeKind::Modified) => colors.commands.pause,
+        Some(ChangeKind::Deleted) => colors.commands.stop,
+        None => colors.containers.text,
+    }
+}
+
+/// Generate the Lines, remove lines & chars based on the offset and viewport
+fn gen_lines<'a>(
+    colors: AppColors,
+    lines: &[ChangeLine],
+    offset: ScrollOffset,
+    rect: Rect,
+) -> Vec<Line<'a>> {
+    lines
+        .iter()
+        .skip(offset.y)
+        .take(usize::from(rect.height))
+        .map(|line| {
+            Line::from(
+                line.to_string()
+                    .chars()
+                    .skip(offset.x)
+                    .take(usize::from(rect.width.saturating_sub(2)))
+                    .collect::<String>(),
+            )
+            .fg(line_color(colors, line.kind))
+        })
+        .collect()
+}
+
+/// Draw the filesystem changes of a container, as a tree, to the entire screen
+/// The changes view and the inspect view can't be open at the same time, so they share the same scroll offset
+pub fn draw(
+    f: &mut Frame,
+    colors: AppColors,
+    data: &ChangesData,
+    gui_state: &Arc<Mutex<GuiState>>,
+    keymap: &Keymap,
+) {
+    let rect = f.area();
+    let offset = gui_state.lock().get_inspect_offset();
+    // +2 to account for the border
+    let height = data
+        .height
+        .saturating_sub(usize::from(rect.height))
+        .saturating_add(2);
+    let width = data
+        .width
+        .saturating_sub(usize::from(rect.width))
+        .saturating_add(2);
+    let upper_title = generate_upper_title(data, keymap);
+    let lower_title = generate_lower_title(height, width, offset);
+
+    gui_state.lock().set_inspect_offset_max(ScrollOffset {
+        x: width,
+        y: height,
+    });
+
+    let block = title_block(&upper_title, &lower_title, &colors);
+    if data.lines.is_empty() {
+        let paragraph = Paragraph::new("no filesystem changes")
+            .block(block)
+            .fg(colors.containers.text)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, rect);
+        return;
+    }
+
+    let paragraph = Paragraph::new(gen_lines(colors, &data.lines, offset, rect)).block(block);
+    f.render_widget(paragraph, rect);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::secret::{ChangeType, FilesystemChange};
+    use insta::assert_snapshot;
+
+    use crate::{
+        app_data::{ChangesData, ContainerId, ScrollDirection},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    /// Generate the changes of a container that has written to its log, config, and tmp directories
+    fn gen_changes_data() -> ChangesData {
+        let change = |path: &str, kind: ChangeType| FilesystemChange {
+            path: path.to_owned(),
+            kind,
+        };
+        ChangesData::new(
+            vec![
+                change("/etc", ChangeType::_0),
+                change("/etc/nginx.conf", ChangeType::_0),
+                change("/etc/hosts.old", ChangeType::_2),
+                change("/tmp", ChangeType::_0),
+                change("/var/log/nginx/access.log", ChangeType::_1),
+            ],
+            "container_1".to_owned(),
+            &ContainerId::from("1"),
+        )
+    }
+
+    #[test]
+    /// Changes view is drawn as a tree, each kind of change in a different color
+    fn test_draw_blocks_changes() {
+        let mut setup = test_setup(90, 12, true, true);
+        let colors = AppColors::new();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    f,
+                    colors,
+                    &gen_changes_data(),
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if (1..=9).contains(&result_cell_index) {
+                    match row_index {
+                        1 | 3 | 4 => assert_eq!(result_cell.fg, colors.commands.pause),
+                        2 => assert_eq!(result_cell.fg, colors.commands.stop),
+                        5 => assert_eq!(result_cell.fg, colors.containers.text),
+                        8 => assert_eq!(result_cell.fg, colors.commands.start),
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Changes view is scrolled both vertically and horizontally with the inspect offset
+    fn test_draw_blocks_changes_offset() {
+        let mut setup = test_setup(90, 6, true, true);
+        let data = gen_changes_data();
+
+        // The first draw sets the maximum offset, so scroll past it, which should stop at the maximum
+        for scroll in [false, true] {
+            if scroll {
+                let mut gui_state = setup.gui_state.lock();
+                for _ in 0..10 {
+                    gui_state.set_inspect_offset(&ScrollDirection::Down);
+                }
+                gui_state.set_inspect_offset(&ScrollDirection::Right);
+                gui_state.set_inspect_offset(&ScrollDirection::Right);
+            }
+            setup
+                .terminal
+                .draw(|f| {
+                    super::draw(f, AppColors::new(), &data, &setup.gui_state, &Keymap::new());
+                })
+                .unwrap();
+        }
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// Changes view for a container without any changes
+    fn test_draw_blocks_changes_empty() {
+        let mut setup = test_setup(90, 6, true, true);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    f,
+                    AppColors::new(),
+                    &ChangesData::new(vec![], "container_1".to_owned(), &ContainerId::from("1")),
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
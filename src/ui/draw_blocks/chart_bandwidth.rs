@@ -9,16 +9,47 @@ use ratatui::{
     widgets::{Axis, Block, BorderType, Borders, Chart, Dataset, GraphType},
 };
 
-use super::FrameData;
+use super::{DOWN_ARROW, FrameData, UP_ARROW};
 use crate::{
-    app_data::{State, Stats},
+    app_data::{InterfaceRate, State, Stats},
     config::AppColors,
 };
 
+/// Generate the per-interface breakdown, only when there is more than one network interface, e.g. `eth0 ↓1.00 kb/s ↑2.00 kb/s`
+fn interface_breakdown<'a>(
+    interfaces: &[InterfaceRate],
+    colors: AppColors,
+    gen_color: impl Fn(Color) -> Color,
+) -> Option<Line<'a>> {
+    if interfaces.len() < 2 {
+        return None;
+    }
+    let mut spans = vec![];
+    for i in interfaces {
+        spans.extend([
+            Span::styled(
+                format!(" {} ", i.name),
+                Style::default().fg(gen_color(colors.chart_bandwidth.y_axis)),
+            ),
+            Span::styled(
+                format!("{DOWN_ARROW}{} ", i.rx),
+                Style::default().fg(gen_color(colors.chart_bandwidth.title_rx)),
+            ),
+            Span::styled(
+                format!("{UP_ARROW}{} ", i.tx),
+                Style::default().fg(gen_color(colors.chart_bandwidth.title_tx)),
+            ),
+        ]);
+    }
+    Some(Line::from(spans))
+}
+
+#[allow(clippy::too_many_arguments)]
 fn make_chart<'a, T: Stats + Display>(
     state: State,
     colors: AppColors,
     dataset: Vec<Dataset<'a>>,
+    interfaces: &[InterfaceRate],
     current_rx: &'a T,
     max_rx: &'a T,
     current_tx: &'a T,
@@ -54,10 +85,17 @@ fn make_chart<'a, T: Stats + Display>(
         labels.reverse();
     }
 
+    let mut block = Block::default();
+    if let Some(breakdown) =
+        interface_breakdown(interfaces, colors, |default| gen_color(&state, default))
+    {
+        block = block.title_bottom(breakdown);
+    }
+
     Chart::new(dataset)
         .bg(colors.chart_bandwidth.background)
         .block(
-            Block::default()
+            block
                 .title_alignment(Alignment::Center)
                 .title(Line::from(vec![
                     Span::styled(
@@ -112,6 +150,7 @@ pub fn draw(area: Rect, colors: AppColors, f: &mut Frame, fd: &FrameData) {
             x.state,
             colors,
             dataset,
+            &x.interfaces,
             &x.rx.current,
             &x.rx.max,
             &x.tx.current,
@@ -125,11 +164,13 @@ pub fn draw(area: Rect, colors: AppColors, f: &mut Frame, fd: &FrameData) {
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use insta::assert_snapshot;
     use ratatui::style::Color;
 
     use crate::{
-        app_data::{ContainerId, NetworkBandwidth, State},
+        app_data::{Bandwidth, ContainerId, CpuThrottling, State},
         config::AppColors,
         ui::{
             FrameData,
@@ -265,7 +306,7 @@ mod tests {
     /// Test with TX data
     fn test_draw_blocks_charts_running_with_data_tx() {
         let mut setup = test_setup(40, 10, true, true);
-        let mut tx = NetworkBandwidth::new();
+        let mut tx = Bandwidth::new();
 
         for i in 0..=20 {
             tx.push(1000 * i * (10 + 5 * i));
@@ -327,7 +368,7 @@ mod tests {
                         assert_eq!(result_cell.fg, COLOR_RX);
                     }
                     // TX dots
-                    x if TX_DOTS.contains(&(row_index, result_cell_index)) => {
+                    _ if TX_DOTS.contains(&(row_index, result_cell_index)) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, COLOR_TX);
                     }
@@ -344,7 +385,7 @@ mod tests {
     /// Test with RX data
     fn test_draw_blocks_charts_running_with_data_rx() {
         let mut setup = test_setup(40, 10, true, true);
-        let mut rx = NetworkBandwidth::new();
+        let mut rx = Bandwidth::new();
 
         for i in 0..=20 {
             rx.push(2000 * i * (10 + 7 * i));
@@ -406,7 +447,7 @@ mod tests {
                         assert_eq!(result_cell.fg, COLOR_TX);
                     }
                     // RX dots
-                    x if RX_DOTS.contains(&(row_index, result_cell_index)) => {
+                    _ if RX_DOTS.contains(&(row_index, result_cell_index)) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, COLOR_RX);
                     }
@@ -423,8 +464,8 @@ mod tests {
     /// Test with RX & TX data
     fn test_draw_blocks_charts_running_with_data_tx_and_rx() {
         let mut setup = test_setup(40, 10, true, true);
-        let mut rx = NetworkBandwidth::new();
-        let mut tx = NetworkBandwidth::new();
+        let mut rx = Bandwidth::new();
+        let mut tx = Bandwidth::new();
         for i in 0..=20 {
             rx.push(2000 * i * (10 + 7 * i));
             tx.push(200 * i * (10 + 7 * i));
@@ -487,12 +528,12 @@ mod tests {
                         assert_eq!(result_cell.fg, COLOR_TX);
                     }
                     // TX dots
-                    x if COMBINED_DOTS_TX.contains(&(row_index, result_cell_index)) => {
+                    _ if COMBINED_DOTS_TX.contains(&(row_index, result_cell_index)) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, COLOR_TX);
                     }
                     // RX dots
-                    x if COMBINED_DOTS_RX.contains(&(row_index, result_cell_index)) => {
+                    _ if COMBINED_DOTS_RX.contains(&(row_index, result_cell_index)) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, COLOR_RX);
                     }
@@ -614,8 +655,8 @@ mod tests {
 
         let mut setup = test_setup(40, 10, true, true);
 
-        let mut rx = NetworkBandwidth::new();
-        let mut tx = NetworkBandwidth::new();
+        let mut rx = Bandwidth::new();
+        let mut tx = Bandwidth::new();
         for i in 0..=20 {
             rx.push(2000 * i * (10 + 7 * i));
             tx.push(200 * i * (10 + 7 * i));
@@ -680,12 +721,12 @@ mod tests {
                         assert_eq!(result_cell.fg, Color::Magenta);
                     }
                     // TX dots
-                    x if COMBINED_DOTS_TX.contains(&(row_index, result_cell_index)) => {
+                    _ if COMBINED_DOTS_TX.contains(&(row_index, result_cell_index)) => {
                         assert_eq!(result_cell.bg, Color::White);
                         assert_eq!(result_cell.fg, Color::Blue);
                     }
                     // RX dots
-                    x if COMBINED_DOTS_RX.contains(&(row_index, result_cell_index)) => {
+                    _ if COMBINED_DOTS_RX.contains(&(row_index, result_cell_index)) => {
                         assert_eq!(result_cell.bg, Color::White);
                         assert_eq!(result_cell.fg, Color::Black);
                     }
@@ -697,4 +738,55 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    /// When a container has multiple network interfaces, the current speed of each is shown at the bottom of the chart
+    fn test_draw_blocks_charts_running_multiple_interfaces() {
+        let mut setup = test_setup(70, 10, true, true);
+
+        for i in 0..=2 {
+            setup.app_data.lock().update_stats_by_id(
+                &ContainerId::from("1"),
+                None,
+                None,
+                0,
+                BTreeMap::from([
+                    ("eth0".to_owned(), (i * 1000, i * 2000)),
+                    ("eth1".to_owned(), (i * 30000, i * 40000)),
+                ]),
+                (0, 0),
+                None,
+                CpuThrottling::default(),
+            );
+        }
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    // Interface names
+                    (9, 6..=11 | 34..=39) => {
+                        assert_eq!(result_cell.fg, Color::White);
+                    }
+                    // Interface rx
+                    (9, 12..=22 | 40..=51) => {
+                        assert_eq!(result_cell.fg, COLOR_RX);
+                    }
+                    // Interface tx
+                    (9, 23..=33 | 52..=63) => {
+                        assert_eq!(result_cell.fg, COLOR_TX);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
 }
@@ -81,6 +81,7 @@ fn make_chart<'a, T: Stats + Display>(
     dataset: Vec<Dataset<'a>>,
     max: &'a T,
     state: State,
+    suffix: &str,
 ) -> Chart<'a> {
     let max_color = chart_variant.get_max_color(colors, state);
 
@@ -91,7 +92,7 @@ fn make_chart<'a, T: Stats + Display>(
                 .style(Style::default().bg(chart_variant.get_bg_color(colors)))
                 .title_alignment(Alignment::Center)
                 .title(Span::styled(
-                    format!(" {} {current} ", chart_variant.name()),
+                    format!(" {} {current}{suffix} ", chart_variant.name()),
                     Style::default()
                         .fg(chart_variant.get_title_color(colors, state))
                         .add_modifier(Modifier::BOLD),
@@ -142,6 +143,15 @@ pub fn draw(area: Rect, colors: AppColors, f: &mut Frame, fd: &FrameData) {
         // let cpu_stats = CpuStats::new(cpu.0.last().map_or(0.00, |f| f.1));
         // #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
         // let mem_stats = ByteStats::new(mem.0.last().map_or(0, |f| f.1 as u64));
+        // Process count & recent throttling, only shown when known, a container stuck at its CPU quota will show a non-zero throttled percentage
+        let mut cpu_suffix = String::new();
+        if x.pids > 0 {
+            cpu_suffix.push_str(&format!(" pids {}", x.pids));
+        }
+        if let Some(throttled) = x.throttling.recent_percentage().filter(|i| *i > 0) {
+            cpu_suffix.push_str(&format!(" throttled {throttled}%"));
+        }
+
         let cpu_chart = make_chart(
             ChartVariant::Cpu,
             colors,
@@ -149,6 +159,7 @@ pub fn draw(area: Rect, colors: AppColors, f: &mut Frame, fd: &FrameData) {
             cpu_dataset,
             &x.cpu.max,
             x.state,
+            &cpu_suffix,
         );
         let mem_chart = make_chart(
             ChartVariant::Memory,
@@ -157,6 +168,7 @@ pub fn draw(area: Rect, colors: AppColors, f: &mut Frame, fd: &FrameData) {
             mem_dataset,
             &x.memory.max,
             x.state,
+            "",
         );
 
         f.render_widget(cpu_chart, area[0]);
@@ -171,7 +183,7 @@ mod tests {
     use ratatui::style::{Color, Modifier};
 
     use crate::{
-        app_data::State,
+        app_data::{CpuThrottling, State},
         config::AppColors,
         ui::{
             FrameData,
@@ -318,6 +330,42 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Process count & recent throttling percentage are appended to the cpu chart title
+    fn test_draw_blocks_charts_running_pids_throttled() {
+        let mut setup = test_setup(80, 10, true, true);
+
+        insert_all_chart_data(&setup);
+        if let Some(item) = setup.app_data.lock().containers.items.first_mut() {
+            item.pids = 42;
+            item.throttling = CpuThrottling {
+                throttled_periods: 60,
+                throttled_time: 3_000_000_000,
+                recent_periods: 50,
+                recent_throttled_periods: 40,
+            };
+        }
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if let (0, 3..=36 | 51..=67) = (row_index, result_cell_index) {
+                    assert_eq!(result_cell.fg, Color::Green);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+    }
+
     #[test]
     /// Whens status paused, some text is now Yellow
     fn test_draw_blocks_charts_paused() {
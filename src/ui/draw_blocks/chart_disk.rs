@@ -0,0 +1,310 @@
+use std::fmt::Display;
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Block, BorderType, Borders, Chart, Dataset, GraphType},
+};
+
+use super::FrameData;
+use crate::{
+    app_data::{State, Stats},
+    config::AppColors,
+};
+
+fn make_chart<'a, T: Stats + Display>(
+    state: State,
+    colors: AppColors,
+    dataset: Vec<Dataset<'a>>,
+    current_read: &'a T,
+    max_read: &'a T,
+    current_write: &'a T,
+    max_write: &'a T,
+) -> Chart<'a> {
+    let gen_color = |state: &State, default: Color| {
+        if state.is_healthy() {
+            default
+        } else {
+            state.get_color(colors)
+        }
+    };
+
+    let mut labels = [
+        Span::raw(""),
+        Span::styled(
+            format!("{max_read}"),
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(gen_color(&state, colors.chart_disk.max_read)),
+        ),
+        Span::styled(
+            format!("{max_write}"),
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(gen_color(&state, colors.chart_disk.max_write)),
+        ),
+        Span::raw(""),
+    ];
+
+    // Set the order of read/write on the y axis, based on which is the highest value
+    if max_read.get_value() > max_write.get_value() {
+        labels.reverse();
+    }
+
+    Chart::new(dataset)
+        .bg(colors.chart_disk.background)
+        .block(
+            Block::default()
+                .title_alignment(Alignment::Center)
+                .title(Line::from(vec![
+                    Span::styled(
+                        format!(" r: {current_read}"),
+                        Style::default()
+                            .add_modifier(Modifier::BOLD)
+                            .fg(gen_color(&state, colors.chart_disk.title_read)),
+                    ),
+                    Span::styled(
+                        format!(" w: {current_write} "),
+                        Style::default()
+                            .add_modifier(Modifier::BOLD)
+                            .fg(gen_color(&state, colors.chart_disk.title_write)),
+                    ),
+                ]))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(colors.chart_disk.border)),
+        )
+        .x_axis(Axis::default().bounds([0.0, 60.0]))
+        .y_axis(
+            Axis::default()
+                .labels(labels)
+                .style(Style::default().fg(colors.chart_disk.y_axis))
+                .bounds([
+                    0.0,
+                    (max_read.get_value()).max(max_write.get_value()) + 0.01,
+                ]),
+        )
+}
+
+/// Draw disk I/O chart
+pub fn draw(area: Rect, colors: AppColors, f: &mut Frame, fd: &FrameData) {
+    if let Some(x) = fd.chart_data.as_ref() {
+        let dataset = vec![
+            Dataset::default()
+                .marker(Marker::Dot)
+                .style(Style::default().fg(colors.chart_disk.points_write))
+                .graph_type(GraphType::Line)
+                .data(&x.disk_write.dataset),
+            Dataset::default()
+                .marker(Marker::Dot)
+                .style(Style::default().fg(colors.chart_disk.points_read))
+                .graph_type(GraphType::Line)
+                .data(&x.disk_read.dataset),
+        ];
+
+        let chart = make_chart(
+            x.state,
+            colors,
+            dataset,
+            &x.disk_read.current,
+            &x.disk_read.max,
+            &x.disk_write.current,
+            &x.disk_write.max,
+        );
+
+        f.render_widget(chart, area);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{Bandwidth, ContainerId, State},
+        config::AppColors,
+        ui::{
+            FrameData,
+            draw_blocks::tests::{
+                COLOR_DISK_READ, COLOR_DISK_WRITE, TuiTestSetup, get_result, test_setup,
+            },
+        },
+    };
+
+    /// Insert read & write data into the first container
+    fn insert_disk_data(setup: &TuiTestSetup) {
+        let mut read = Bandwidth::new();
+        let mut write = Bandwidth::new();
+        for i in 0..=20 {
+            read.push(2000 * i * (10 + 7 * i));
+            write.push(200 * i * (10 + 7 * i));
+        }
+
+        if let Some(item) = setup
+            .app_data
+            .lock()
+            .get_container_by_id(&ContainerId::from("1"))
+        {
+            item.disk_read = read;
+            item.disk_write = write;
+        }
+    }
+
+    #[test]
+    /// When status is Running, but no data, chart drawn without dots, colours correct
+    fn test_draw_blocks_charts_disk_running_none() {
+        let mut setup = test_setup(40, 10, true, true);
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    // border
+                    (9, _) | (1..=9, 0 | 39) | (0, 0..=5 | 33..=39) => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                        assert_eq!(result_cell.fg, Color::White);
+                    }
+                    // Title read
+                    (0, 6..=18) => {
+                        assert_eq!(result_cell.fg, COLOR_DISK_READ);
+                    }
+                    // Title write
+                    (0, 19..=32) => {
+                        assert_eq!(result_cell.fg, COLOR_DISK_WRITE);
+                    }
+                    // Y axis
+                    (1..=8, 10) => {
+                        assert_eq!(result_cell.fg, Color::White);
+                    }
+                    // Write max
+                    (4, 1..=9) => {
+                        assert_eq!(result_cell.fg, COLOR_DISK_WRITE);
+                    }
+                    // Read max
+                    (6, 1..=9) => {
+                        assert_eq!(result_cell.fg, COLOR_DISK_READ);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Reset);
+                        assert_eq!(result_cell.bg, Color::Reset);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Test with read & write data, the larger of the two maximums is placed at the top of the y axis
+    fn test_draw_blocks_charts_disk_running_with_data() {
+        let mut setup = test_setup(40, 10, true, true);
+        insert_disk_data(&setup);
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    // Read max
+                    (4, 1..=9) => {
+                        assert_eq!(result_cell.fg, COLOR_DISK_READ);
+                    }
+                    // Write max
+                    (6, 1..=9) => {
+                        assert_eq!(result_cell.fg, COLOR_DISK_WRITE);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When status paused, title & max values are now Yellow
+    fn test_draw_blocks_charts_disk_paused() {
+        let mut setup = test_setup(40, 10, true, true);
+        setup.app_data.lock().containers.items[0].state = State::Paused;
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if let (0, 6..=32) | (4 | 6, 1..=9) = (row_index, result_cell_index) {
+                    assert_eq!(result_cell.fg, Color::Yellow);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Custom colours correctly applied to each part of the chart
+    fn test_draw_blocks_charts_disk_custom_colors() {
+        let mut colors = AppColors::new();
+
+        colors.chart_disk.background = Color::White;
+        colors.chart_disk.border = Color::Red;
+        colors.chart_disk.max_read = Color::Green;
+        colors.chart_disk.max_write = Color::Magenta;
+        colors.chart_disk.title_read = Color::LightGreen;
+        colors.chart_disk.title_write = Color::LightRed;
+        colors.chart_disk.points_read = Color::Black;
+        colors.chart_disk.points_write = Color::Blue;
+        colors.chart_disk.y_axis = Color::Yellow;
+
+        let mut setup = test_setup(40, 10, true, true);
+        insert_disk_data(&setup);
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, colors, f, &fd);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                assert_eq!(result_cell.bg, Color::White);
+                match (row_index, result_cell_index) {
+                    // border
+                    (9, _) | (1..=9, 0 | 39) => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                    }
+                    // Read max
+                    (4, 1..=9) => {
+                        assert_eq!(result_cell.fg, Color::Green);
+                    }
+                    // Write max
+                    (6, 1..=9) => {
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+}
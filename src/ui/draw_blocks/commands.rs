@@ -59,7 +59,7 @@ mod tests {
     use ratatui::style::{Color, Modifier};
 
     use crate::{
-        app_data::ScrollDirection,
+        app_data::{ContainerHost, ScrollDirection},
         config::AppColors,
         tests::gen_container_summary,
         ui::{
@@ -97,7 +97,7 @@ mod tests {
     /// Test that when DockerCommands are available, they are drawn correctly, dependant on container state
     /// In this test, container is running
     fn test_draw_blocks_commands_some() {
-        let mut setup = test_setup(12, 6, true, true);
+        let mut setup = test_setup(12, 7, true, true);
 
         let colors = setup.app_data.lock().config.app_colors;
         setup
@@ -121,7 +121,7 @@ mod tests {
                 assert_eq!(result_cell.bg, Color::Reset);
                 match (row_index, result_cell_index) {
                     // Borders & delete
-                    (0 | 5, _) | (1..=4, 0 | 11) | (4, 3..=8) => {
+                    (0 | 6, _) | (1..=5, 0 | 11) | (5, 3..=8) => {
                         assert_eq!(result_cell.fg, Color::Gray);
                     }
                     // pause
@@ -136,6 +136,10 @@ mod tests {
                     (3, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Red);
                     }
+                    // kill
+                    (4, 3..=6) => {
+                        assert_eq!(result_cell.fg, Color::LightRed);
+                    }
                     _ => {
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
@@ -148,7 +152,7 @@ mod tests {
     /// Test that when DockerCommands are available, they are drawn correctly, dependant on container state
     /// In this test, container is paused
     fn test_draw_blocks_commands_some_paused() {
-        let mut setup = test_setup(12, 6, true, true);
+        let mut setup = test_setup(12, 7, true, true);
 
         let colors = setup.app_data.lock().config.app_colors;
         setup
@@ -166,10 +170,10 @@ mod tests {
             .unwrap();
 
         // Set the container state to paused
-        setup
-            .app_data
-            .lock()
-            .update_containers(vec![gen_container_summary(1, "paused")]);
+        setup.app_data.lock().update_containers(
+            &ContainerHost::default(),
+            vec![gen_container_summary(1, "paused")],
+        );
         setup
             .app_data
             .lock()
@@ -203,8 +207,12 @@ mod tests {
                     (2, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Red);
                     }
+                    // kill
+                    (3, 3..=6) => {
+                        assert_eq!(result_cell.fg, Color::LightRed);
+                    }
                     // delete
-                    (0 | 5, _) | (1..=4, 0 | 11) | (3, 3..=8) => {
+                    (0 | 6, _) | (1..=5, 0 | 11) | (4, 3..=8) => {
                         assert_eq!(result_cell.fg, Color::Gray);
                     }
                     _ => {
@@ -285,7 +293,7 @@ mod tests {
     #[test]
     /// Custom colors are rendered correctly
     fn test_draw_blocks_commands_custom_colors_running() {
-        let mut setup = test_setup(12, 6, true, true);
+        let mut setup = test_setup(12, 7, true, true);
         let mut colors = AppColors::new();
         colors.commands.background = Color::White;
         colors.commands.pause = Color::Black;
@@ -294,6 +302,7 @@ mod tests {
         colors.commands.delete = Color::Magenta;
         colors.commands.resume = Color::Yellow;
         colors.commands.start = Color::Cyan;
+        colors.commands.kill = Color::Red;
 
         setup
             .terminal
@@ -326,8 +335,12 @@ mod tests {
                     (3, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Blue);
                     }
+                    // kill
+                    (4, 3..=6) => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                    }
                     // delete
-                    (4, 3..=8) => {
+                    (5, 3..=8) => {
                         assert_eq!(result_cell.fg, Color::Magenta);
                     }
                     _ => (),
@@ -338,7 +351,7 @@ mod tests {
     #[test]
     /// Custom colors are rendered correctly
     fn test_draw_blocks_commands_custom_colors_paused() {
-        let mut setup = test_setup(12, 6, true, true);
+        let mut setup = test_setup(12, 7, true, true);
         let mut colors = AppColors::new();
         colors.commands.background = Color::White;
         colors.commands.pause = Color::Black;
@@ -347,6 +360,7 @@ mod tests {
         colors.commands.delete = Color::Magenta;
         colors.commands.resume = Color::Yellow;
         colors.commands.start = Color::Cyan;
+        colors.commands.kill = Color::Red;
 
         setup
             .terminal
@@ -363,10 +377,10 @@ mod tests {
             .unwrap();
 
         // Set the controls state
-        setup
-            .app_data
-            .lock()
-            .update_containers(vec![gen_container_summary(1, "paused")]);
+        setup.app_data.lock().update_containers(
+            &ContainerHost::default(),
+            vec![gen_container_summary(1, "paused")],
+        );
         setup
             .app_data
             .lock()
@@ -401,8 +415,12 @@ mod tests {
                     (2, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Blue);
                     }
+                    // kill
+                    (3, 3..=6) => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                    }
                     // delete
-                    (3, 3..=8) => {
+                    (4, 3..=8) => {
                         assert_eq!(result_cell.fg, Color::Magenta);
                     }
                     _ => (),
@@ -0,0 +1,319 @@
+use std::sync::Arc;
+
+use crossterm::event::KeyCode;
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::{CONSTRAINT_BUTTONS, CONSTRAINT_POPUP, MARGIN, popup};
+use crate::{
+    app_data::PrunePreview,
+    config::{AppColors, Keymap},
+    ui::{
+        Confirm, DeleteButton, GuiState,
+        gui_state::{BoxLocation, Region},
+    },
+};
+
+/// The maximum number of prune preview items to list at once, the list can be scrolled to show any remaining items
+const MAX_PREVIEW_ITEMS: usize = 10;
+
+/// The maximum width of a prune preview item name, build cache descriptions can be very long
+const MAX_NAME_WIDTH: usize = 48;
+
+/// List the items that a prune would remove, with their sizes, padded to the same width so that they line up once centred
+/// Only a window of the items, starting at the offset, is listed, with a final line showing the position and how to scroll
+fn preview_lines<'a>(preview: &PrunePreview, offset: usize, keymap: &Keymap) -> Vec<Line<'a>> {
+    let names = preview
+        .items
+        .iter()
+        .skip(offset)
+        .take(MAX_PREVIEW_ITEMS)
+        .map(|i| {
+            if i.name.chars().count() > MAX_NAME_WIDTH {
+                format!(
+                    "{}…",
+                    i.name.chars().take(MAX_NAME_WIDTH - 1).collect::<String>()
+                )
+            } else {
+                i.name.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    let sizes = preview
+        .items
+        .iter()
+        .skip(offset)
+        .take(MAX_PREVIEW_ITEMS)
+        .map(|i| i.size.map_or_else(String::new, |i| format!("{i}{MARGIN}")))
+        .collect::<Vec<_>>();
+    let name_width = names
+        .iter()
+        .map(|i| i.chars().count())
+        .max()
+        .unwrap_or_default();
+    let size_width = sizes
+        .iter()
+        .map(|i| i.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = sizes
+        .into_iter()
+        .zip(names)
+        .map(|(size, name)| Line::from(format!("{size:>size_width$}{name:<name_width$}")))
+        .collect::<Vec<_>>();
+    let total = preview.items.len();
+    if total > MAX_PREVIEW_ITEMS {
+        let last = (offset + MAX_PREVIEW_ITEMS).min(total);
+        lines.push(Line::from(format!(
+            "{}-{last} of {total}, ( {} | {} ) scroll",
+            offset + 1,
+            keymap.scroll_up.0,
+            keymap.scroll_down.0
+        )));
+    }
+    lines
+}
+
+/// Draw a confirm box in the centre of the screen, for any action, other than deleting a container, that needs confirming
+pub fn draw(
+    colors: AppColors,
+    confirm: &Confirm,
+    f: &mut Frame,
+    gui_state: &Arc<Mutex<GuiState>>,
+    keymap: &Keymap,
+) {
+    let (title, prefix, target) = confirm.get_text();
+
+    let block = Block::default()
+        .title(title)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_delete.background)
+                .fg(colors.popup_delete.text),
+        )
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let text = Line::from(vec![
+        Span::from(prefix),
+        Span::styled(
+            target,
+            Style::default()
+                .fg(colors.popup_delete.text_highlight)
+                .bg(colors.popup_delete.background)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+
+    let button_text = |keys: (KeyCode, Option<KeyCode>), text: &str| {
+        keys.1.map_or_else(
+            || format!("( {} ) {text}", keys.0),
+            |secondary| format!("( {} | {secondary} ) {text}", keys.0),
+        )
+    };
+
+    // A prune lists exactly what will be removed, between the description and the buttons
+    let details = match confirm {
+        Confirm::Prune(preview) => {
+            let offset_max = preview.items.len().saturating_sub(MAX_PREVIEW_ITEMS);
+            let offset = gui_state.lock().get_confirm_offset().min(offset_max);
+            gui_state.lock().set_confirm_offset_max(offset_max);
+            preview_lines(preview, offset, keymap)
+        }
+        _ => vec![],
+    };
+
+    // Find the maximum line width & height, and add some padding
+    let max_line_width = u16::try_from(
+        details
+            .iter()
+            .map(Line::width)
+            .max()
+            .unwrap_or_default()
+            .max(text.width()),
+    )
+    .unwrap_or(64)
+        + 12;
+    let lines = if details.is_empty() {
+        10
+    } else {
+        details.len() + 9
+    };
+
+    let text_para = Paragraph::new(text).alignment(Alignment::Center);
+
+    let button_block = || {
+        Block::default()
+            .border_type(BorderType::Rounded)
+            .borders(Borders::ALL)
+            .style(Style::default().bg(colors.popup_delete.background))
+    };
+
+    let yes_para = Paragraph::new(button_text(keymap.delete_confirm, "yes"))
+        .alignment(Alignment::Center)
+        .block(button_block());
+
+    let no_para = Paragraph::new(button_text(keymap.delete_deny, "no"))
+        .alignment(Alignment::Center)
+        .block(button_block());
+
+    let area = popup::draw(
+        lines,
+        max_line_width.into(),
+        f.area(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let split_popup = if details.is_empty() {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(CONSTRAINT_POPUP)
+            .split(area)
+    } else {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(u16::try_from(details.len()).unwrap_or(u16::MAX)),
+                Constraint::Length(1),
+                Constraint::Length(3),
+                Constraint::Min(1),
+            ])
+            .split(area)
+    };
+
+    let split_buttons = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(CONSTRAINT_BUTTONS)
+        .split(split_popup[5]);
+
+    let no_area = split_buttons[1];
+    let yes_area = split_buttons[3];
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(text_para, split_popup[1]);
+    if !details.is_empty() {
+        f.render_widget(
+            Paragraph::new(details).alignment(Alignment::Center),
+            split_popup[3],
+        );
+    }
+    f.render_widget(no_para, no_area);
+    f.render_widget(yes_para, yes_area);
+    // Insert button areas into region map, so can interact with them on click
+    let mut gui_state = gui_state.lock();
+    gui_state.update_region_map(Region::Delete(DeleteButton::Cancel), no_area);
+    gui_state.update_region_map(Region::Delete(DeleteButton::Confirm), yes_area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{
+        app_data::{ByteStats, ContainerHost, PruneItem, PruneKind, PrunePreview, ScrollDirection},
+        ui::{
+            Confirm,
+            draw_blocks::tests::{get_result, test_setup},
+        },
+    };
+
+    #[test]
+    /// Prune confirm popup is drawn correctly, with the target highlighted, and every item that will be removed listed with its size
+    fn test_draw_blocks_confirm_prune() {
+        let mut setup = test_setup(82, 13, true, true);
+
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = &setup.app_data.lock().config.keymap;
+        let item = |name: &str, size: u64| PruneItem {
+            id: name.to_owned(),
+            name: name.to_owned(),
+            size: Some(ByteStats::new(size)),
+        };
+        let confirm = Confirm::Prune(PrunePreview {
+            host: ContainerHost::default(),
+            items: vec![item("redis:old", 2_000_000), item("aaaaaaaa", 500_000)],
+            kind: PruneKind::Images,
+            reclaimable: Some(ByteStats::new(2_500_000)),
+        });
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(colors, &confirm, f, &setup.gui_state, keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if row_index == 3 && (38..=74).contains(&result_cell_index) {
+                    assert_eq!(result_cell.bg, Color::White);
+                    assert_eq!(result_cell.fg, Color::Red);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Prune confirm popup only lists ten items at once, and can be scrolled to show the remaining items, networks don't have a size
+    fn test_draw_blocks_confirm_prune_many() {
+        let mut setup = test_setup(82, 24, true, true);
+
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = &setup.app_data.lock().config.keymap;
+        let confirm = Confirm::Prune(PrunePreview {
+            host: ContainerHost::default(),
+            items: (1..=12)
+                .map(|i| PruneItem {
+                    id: i.to_string(),
+                    name: format!("network_{i}"),
+                    size: None,
+                })
+                .collect(),
+            kind: PruneKind::Networks,
+            reclaimable: None,
+        });
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(colors, &confirm, f, &setup.gui_state, keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        // Scrolling is limited to the last full window of items
+        for _ in 0..5 {
+            setup
+                .gui_state
+                .lock()
+                .set_confirm_offset(&ScrollDirection::Down);
+        }
+        assert_eq!(setup.gui_state.lock().get_confirm_offset(), 2);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(colors, &confirm, f, &setup.gui_state, keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
@@ -11,18 +11,24 @@ use ratatui::{
 };
 
 use crate::{
-    app_data::{AppData, ByteStats, Columns, ContainerItem, CpuStats},
+    app_data::{AppData, ByteStats, Columns, ContainerItem, CpuStats, ProjectRow},
     config::AppColors,
     ui::{FrameData, GuiState, SelectablePanel},
 };
 
-use super::{CIRCLE, generate_block};
+use super::{CIRCLE, DOWN_ARROW, RIGHT_ARROW, generate_block};
 
 /// Format the container data to display nicely on the screen
-fn format_containers<'a>(colors: AppColors, i: &ContainerItem, widths: &Columns) -> Line<'a> {
+/// If the containers host has been disconnected, the last known state is displayed, greyed out
+fn format_containers<'a>(
+    colors: AppColors,
+    i: &ContainerItem,
+    widths: &Columns,
+    disconnected: bool,
+) -> Line<'a> {
     let state_style = Style::default().fg(i.state.get_color(colors));
 
-    Line::from(vec![
+    let mut spans = vec![
         Span::styled(
             format!(
                 "{:<width$}{MARGIN}",
@@ -97,10 +103,83 @@ fn format_containers<'a>(colors: AppColors, i: &ContainerItem, widths: &Columns)
             ),
             Style::default().fg(colors.containers.text_tx),
         ),
-    ])
+    ];
+
+    if let Some((_, width)) = widths.host {
+        spans.insert(
+            1,
+            Span::styled(
+                format!(
+                    "{:<width$}{MARGIN}",
+                    i.host.to_string(),
+                    width = width.into()
+                ),
+                colors.containers.text,
+            ),
+        );
+    }
+    if let Some((_, width)) = widths.disk_read {
+        spans.push(Span::styled(
+            format!(
+                "{:>width$}{MARGIN}",
+                i.disk_read.current_total(),
+                width = width.into()
+            ),
+            Style::default().fg(colors.containers.text_disk_read),
+        ));
+    }
+    if let Some((_, width)) = widths.disk_write {
+        spans.push(Span::styled(
+            format!(
+                "{:>width$}{MARGIN}",
+                i.disk_write.current_total(),
+                width = width.into()
+            ),
+            Style::default().fg(colors.containers.text_disk_write),
+        ));
+    }
+    if let Some((_, width)) = widths.pids {
+        spans.push(Span::styled(
+            format!("{:>width$}{MARGIN}", i.pids, width = width.into()),
+            state_style,
+        ));
+    }
+    if let Some((_, width)) = widths.throttled {
+        spans.push(Span::styled(
+            format!("{:>width$}{MARGIN}", i.throttling, width = width.into()),
+            state_style,
+        ));
+    }
+    if disconnected {
+        for span in &mut spans {
+            span.style = Style::default().fg(colors.containers.text_disconnected);
+        }
+    }
+    Line::from(spans)
+}
+
+/// Format a project header, with the combined cpu & memory usage of every container in the project
+fn format_project<'a>(
+    colors: AppColors,
+    project: &str,
+    summary: (usize, CpuStats, ByteStats),
+    collapsed: bool,
+) -> Line<'a> {
+    let arrow = if collapsed { RIGHT_ARROW } else { DOWN_ARROW };
+    let (count, cpu, mem) = summary;
+    let suffix = if count == 1 { "" } else { "s" };
+    Line::from(Span::styled(
+        format!(
+            "{arrow} {project}{MARGIN}{count} container{suffix}{MARGIN}cpu {cpu}{MARGIN}mem {mem}"
+        ),
+        Style::default()
+            .fg(colors.containers.text)
+            .add_modifier(Modifier::BOLD),
+    ))
 }
 
 /// Draw the containers panel
+/// When grouped by Docker Compose project, containers are nested under a header for each project
 pub fn draw(
     app_data: &Arc<Mutex<AppData>>,
     area: Rect,
@@ -112,12 +191,46 @@ pub fn draw(
     let block = generate_block(area, colors, fd, gui_state, SelectablePanel::Containers)
         .bg(colors.containers.background);
 
-    let items = app_data
-        .lock()
-        .get_container_items()
-        .iter()
-        .map(|i| ListItem::new(format_containers(colors, i, &fd.columns)))
-        .collect::<Vec<_>>();
+    let container_item = |i: &ContainerItem| {
+        let item = ListItem::new(format_containers(
+            colors,
+            i,
+            &fd.columns,
+            fd.disconnected.contains(&i.host),
+        ));
+        if i.marked {
+            item.style(Style::default().bg(colors.containers.background_marked))
+        } else {
+            item
+        }
+    };
+
+    let mut app_data = app_data.lock();
+    let (items, grouped_index) = if app_data.is_grouped() {
+        let rows = app_data.get_project_rows();
+        let index = app_data.get_project_row_index(&rows);
+        let containers = app_data.get_container_items();
+        let items = rows
+            .iter()
+            .filter_map(|row| match row {
+                ProjectRow::Project(project) => Some(ListItem::new(format_project(
+                    colors,
+                    project,
+                    app_data.get_project_summary(project),
+                    app_data.is_project_collapsed(project),
+                ))),
+                ProjectRow::Container(index) => containers.get(*index).map(container_item),
+            })
+            .collect::<Vec<_>>();
+        (items, Some(index))
+    } else {
+        let items = app_data
+            .get_container_items()
+            .iter()
+            .map(container_item)
+            .collect::<Vec<_>>();
+        (items, None)
+    };
 
     if items.is_empty() {
         let text = if fd.filter_term.is_some() {
@@ -137,7 +250,11 @@ pub fn draw(
             .block(block)
             .highlight_style(Style::default().add_modifier(Modifier::BOLD))
             .highlight_symbol(CIRCLE);
-        f.render_stateful_widget(items, area, app_data.lock().get_container_state());
+        if let Some(index) = grouped_index {
+            f.render_stateful_widget(items, area, app_data.get_project_state(index));
+        } else {
+            f.render_stateful_widget(items, area, app_data.get_container_state());
+        }
     }
 }
 
@@ -148,13 +265,15 @@ mod tests {
     use ratatui::style::{Color, Modifier};
 
     use crate::{
-        app_data::{ContainerImage, ContainerName, ContainerStatus, State, StatefulList},
+        app_data::{
+            ContainerHost, ContainerImage, ContainerName, ContainerStatus, State, StatefulList,
+        },
         config::AppColors,
         ui::{
             FrameData,
             draw_blocks::tests::{
-                BORDER_CHARS, COLOR_ORANGE, COLOR_RX, COLOR_TX, TuiTestSetup, get_result,
-                test_setup,
+                BORDER_CHARS, COLOR_DISK_READ, COLOR_DISK_WRITE, COLOR_ORANGE, COLOR_RX, COLOR_TX,
+                TuiTestSetup, get_result, test_setup,
             },
         },
     };
@@ -379,6 +498,234 @@ mod tests {
         assert_snapshot!(setup.terminal.backend());
     }
 
+    #[test]
+    /// Optional pids & throttled columns are drawn when enabled in the config
+    fn test_draw_blocks_containers_pids_throttled() {
+        let mut setup = test_setup(180, 6, true, true);
+        setup.app_data.lock().config.show_pids = true;
+        setup.app_data.lock().config.show_throttling = true;
+        setup.app_data.lock().containers.items[0].pids = 42;
+        setup.app_data.lock().containers.items[0]
+            .throttling
+            .throttled_periods = 60;
+        setup.app_data.lock().containers.items[0]
+            .throttling
+            .throttled_time = 3_000_000_000;
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    colors,
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// Optional disk read & write columns are drawn, in their own colours, when enabled in the config
+    fn test_draw_blocks_containers_disk_io() {
+        let mut setup = test_setup(160, 6, true, true);
+        setup.app_data.lock().config.show_disk_io = true;
+        setup.app_data.lock().containers.items[0]
+            .disk_read
+            .push(1_000_000);
+        setup.app_data.lock().containers.items[0]
+            .disk_write
+            .push(2_000);
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    colors,
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let row = (0..160)
+            .map(|x| setup.terminal.backend().buffer()[(x, 1)].fg)
+            .collect::<Vec<_>>();
+        assert!(row.contains(&COLOR_DISK_READ));
+        assert!(row.contains(&COLOR_DISK_WRITE));
+    }
+
+    #[test]
+    /// Host column is shown after the name column when monitoring multiple hosts
+    fn test_draw_blocks_containers_hosts() {
+        let mut setup = test_setup(180, 6, true, true);
+        setup.app_data.lock().config.hosts = vec!["host_a".to_owned(), "host_b".to_owned()];
+        setup.app_data.lock().containers.items[0].host = ContainerHost::from("host_a");
+        setup.app_data.lock().containers.items[1].host = ContainerHost::from("host_b");
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    colors,
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// Containers of a disconnected host are greyed out, whilst other containers are unchanged
+    fn test_draw_blocks_containers_disconnected() {
+        let mut setup = test_setup(180, 6, true, true);
+        setup.app_data.lock().config.hosts = vec!["host_a".to_owned(), "host_b".to_owned()];
+        setup.app_data.lock().containers.items[0].host = ContainerHost::from("host_a");
+        setup.app_data.lock().containers.items[1].host = ContainerHost::from("host_b");
+        setup.app_data.lock().containers.items[2].host = ContainerHost::from("host_b");
+        setup
+            .app_data
+            .lock()
+            .set_host_connected(&ContainerHost::from("host_b"), false);
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    colors,
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (1, 4..=17) => assert_eq!(result_cell.fg, Color::Blue),
+                    (2..=3, 4..=120) => assert_eq!(result_cell.fg, Color::DarkGray),
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When grouped by project, containers are nested under a bold project header, with the combined cpu & memory usage
+    fn test_draw_blocks_containers_grouped() {
+        let mut setup = test_setup(130, 7, true, true);
+        for i in &mut setup.app_data.lock().containers.items[..2] {
+            i.labels.insert(
+                "com.docker.compose.project".to_owned(),
+                "project_1".to_owned(),
+            );
+        }
+        setup.app_data.lock().toggle_grouped();
+        setup.app_data.lock().containers_start();
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    colors,
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if row_index == 1 && (3..=50).contains(&result_cell_index) {
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+
+        // Collapsing the project hides its containers
+        setup.app_data.lock().toggle_project_collapsed();
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    colors,
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// A marked container has the marked background color, and the number of marked containers is in the title
+    fn test_draw_blocks_containers_marked() {
+        let mut setup = test_setup(130, 6, true, true);
+        setup.app_data.lock().containers.items[1].marked = true;
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    colors,
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (2, 1..=128) => assert_eq!(result_cell.bg, Color::DarkGray),
+                    _ => assert_eq!(result_cell.bg, Color::Reset),
+                }
+            }
+        }
+    }
+
     // Check that the correct colour is applied to the state/status/cpu/memory section
 
     fn check_colour(setup: &TuiTestSetup, color: Color) {
@@ -0,0 +1,110 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Modifier, Style},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem},
+};
+
+use super::{SELECT_ARROW, popup};
+use crate::{
+    config::AppColors,
+    ui::{FrameData, gui_state::BoxLocation},
+};
+
+/// Draw the Docker context picker in the centre of the screen, the currently active context is marked with a tick
+pub fn draw(colors: AppColors, f: &mut Frame, fd: &FrameData) {
+    let items = fd
+        .contexts
+        .items
+        .iter()
+        .map(|name| {
+            if fd.context.as_ref() == Some(name) {
+                format!("{name} ✓")
+            } else {
+                name.to_owned()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let title = " Docker Context ";
+    let max_line_width = items
+        .iter()
+        .map(|i| i.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(title.chars().count())
+        + SELECT_ARROW.chars().count()
+        + 4;
+    let lines = items.len() + 2;
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(colors.popup_help.background)
+                .fg(colors.popup_help.text),
+        );
+
+    let list = List::new(items.into_iter().map(ListItem::new).collect::<Vec<_>>())
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .fg(colors.popup_help.text_highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(SELECT_ARROW);
+
+    let area = popup::draw(lines, max_line_width, f.area(), BoxLocation::MiddleCentre);
+    let mut state = fd.contexts.state;
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Modifier;
+
+    use crate::ui::{
+        FrameData,
+        draw_blocks::tests::{get_result, test_setup},
+    };
+
+    #[test]
+    /// Context picker is drawn in the centre of the screen, with the current context marked and selected
+    fn test_draw_blocks_context_picker() {
+        let mut setup = test_setup(40, 9, true, true);
+        setup.app_data.lock().config.context = Some("remote".to_owned());
+        setup.gui_state.lock().set_contexts(
+            vec![
+                "default".to_owned(),
+                "local_rootless".to_owned(),
+                "remote".to_owned(),
+            ],
+            Some("remote"),
+        );
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(colors, f, &fd);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if row_index == 5 && (10..=29).contains(&result_cell_index) {
+                    assert_eq!(result_cell.fg, colors.popup_help.text_highlight);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+    }
+}
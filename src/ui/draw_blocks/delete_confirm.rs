@@ -1,15 +1,16 @@
 use std::sync::Arc;
 
+use crossterm::event::KeyCode;
 use parking_lot::Mutex;
 use ratatui::{
     Frame,
-    layout::{Alignment, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Clear, Paragraph},
 };
 
-use super::{CONSTRAINT_BUTTONS, CONSTRAINT_POPUP};
+use super::CONSTRAINT_BUTTONS;
 use crate::{
     app_data::ContainerName,
     config::{AppColors, Keymap},
@@ -21,14 +22,32 @@ use crate::{
 
 use super::popup;
 
+/// Maximum width of each line of container names, when deleting multiple marked containers
+const NAMES_WIDTH: usize = 64;
+
+/// Join the names of multiple containers into lines, each no wider than the given width, unless a single name is wider
+fn name_lines(names: &[ContainerName], width: usize) -> Vec<String> {
+    let mut output: Vec<String> = vec![];
+    for name in names.iter().map(ContainerName::get) {
+        match output.last_mut() {
+            Some(line) if line.chars().count() + name.chars().count() + 2 <= width => {
+                line.push_str(", ");
+                line.push_str(name);
+            }
+            _ => output.push(name.to_owned()),
+        }
+    }
+    output
+}
+
 /// Draw the delete confirm box in the centre of the screen
-/// take in container id and container name here?
+/// When deleting multiple marked containers, the number of containers is shown, followed by the name of every container
 pub fn draw(
     colors: AppColors,
     f: &mut Frame,
     gui_state: &Arc<Mutex<GuiState>>,
     keymap: &Keymap,
-    name: &ContainerName,
+    names: &[ContainerName],
 ) {
     let block = Block::default()
         .title(" Confirm Delete ")
@@ -41,16 +60,31 @@ pub fn draw(
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL);
 
-    let confirm = Line::from(vec![
-        Span::from("Are you sure you want to delete container: "),
-        Span::styled(
-            name.get(),
-            Style::default()
-                .fg(colors.popup_delete.text_highlight)
-                .bg(colors.popup_delete.background)
-                .add_modifier(Modifier::BOLD),
+    let highlight = Style::default()
+        .fg(colors.popup_delete.text_highlight)
+        .bg(colors.popup_delete.background)
+        .add_modifier(Modifier::BOLD);
+
+    let (confirm, names) = match names {
+        [name] => (
+            Line::from(vec![
+                Span::from("Are you sure you want to delete container: "),
+                Span::styled(name.get().to_owned(), highlight),
+            ]),
+            vec![],
+        ),
+        _ => (
+            Line::from(vec![
+                Span::from("Are you sure you want to delete "),
+                Span::styled(format!("{} containers", names.len()), highlight),
+                Span::from(":"),
+            ]),
+            name_lines(names, NAMES_WIDTH)
+                .into_iter()
+                .map(|i| Line::from(Span::styled(i, highlight)))
+                .collect::<Vec<_>>(),
         ),
-    ]);
+    };
 
     let yes_text = if keymap.delete_confirm == Keymap::new().delete_confirm {
         "( y ) yes".to_owned()
@@ -68,11 +102,50 @@ pub fn draw(
         format!("( {} ) no", keymap.delete_deny.0)
     };
 
-    // Find the maximum line width & height, and add some padding
-    let max_line_width = u16::try_from(confirm.width()).unwrap_or(64) + 12;
-    let lines = 8;
+    let key_text = |keys: (KeyCode, Option<KeyCode>)| {
+        keys.1.map_or_else(
+            || format!("( {} )", keys.0),
+            |secondary| format!("( {} | {secondary} )", keys.0),
+        )
+    };
 
-    let confirm_para = Paragraph::new(confirm).alignment(Alignment::Center);
+    // An enabled option is highlighted
+    let toggle = |keys: (KeyCode, Option<KeyCode>), enabled: bool, text: &str| {
+        let check = if enabled { "[x]" } else { "[ ]" };
+        let style = if enabled {
+            Style::default()
+                .fg(colors.popup_delete.text_highlight)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        Paragraph::new(Line::from(vec![
+            Span::from(format!("{} ", key_text(keys))),
+            Span::styled(format!("{check} {text}"), style),
+        ]))
+        .alignment(Alignment::Center)
+    };
+
+    let options = gui_state.lock().get_delete_options();
+    let force_para = toggle(keymap.delete_force, options.force, "force");
+    let volumes_para = toggle(keymap.delete_volumes, options.volumes, "volumes");
+
+    // Find the maximum line width & height, and add some padding
+    let max_line_width = u16::try_from(
+        names
+            .iter()
+            .map(Line::width)
+            .chain(std::iter::once(confirm.width()))
+            .max()
+            .unwrap_or_default(),
+    )
+    .unwrap_or(64)
+        + 12;
+    let name_count = u16::try_from(names.len()).unwrap_or_default();
+    let lines = 10 + usize::from(name_count);
+
+    let confirm_para = Paragraph::new(std::iter::once(confirm).chain(names).collect::<Vec<_>>())
+        .alignment(Alignment::Center);
 
     let button_block = || {
         Block::default()
@@ -98,30 +171,45 @@ pub fn draw(
 
     let split_popup = Layout::default()
         .direction(Direction::Vertical)
-        .constraints(CONSTRAINT_POPUP)
+        .constraints([
+            Constraint::Min(2),
+            Constraint::Max(1 + name_count),
+            Constraint::Max(1),
+            Constraint::Max(1),
+            Constraint::Max(1),
+            Constraint::Max(3),
+            Constraint::Min(1),
+        ])
         .split(area);
 
-    let split_buttons = Layout::default()
+    let split_options = Layout::default()
         .direction(Direction::Horizontal)
         .constraints(CONSTRAINT_BUTTONS)
         .split(split_popup[3]);
 
+    let split_buttons = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(CONSTRAINT_BUTTONS)
+        .split(split_popup[5]);
+
+    let force_area = split_options[1];
+    let volumes_area = split_options[3];
     let no_area = split_buttons[1];
     let yes_area = split_buttons[3];
 
     f.render_widget(Clear, area);
     f.render_widget(block, area);
     f.render_widget(confirm_para, split_popup[1]);
+    f.render_widget(force_para, force_area);
+    f.render_widget(volumes_para, volumes_area);
     f.render_widget(no_para, no_area);
     f.render_widget(yes_para, yes_area);
     // Insert button areas into region map, so can interact with them on click
-    gui_state
-        .lock()
-        .update_region_map(Region::Delete(DeleteButton::Cancel), no_area);
-
-    gui_state
-        .lock()
-        .update_region_map(Region::Delete(DeleteButton::Confirm), yes_area);
+    let mut gui_state = gui_state.lock();
+    gui_state.update_region_map(Region::Delete(DeleteButton::Cancel), no_area);
+    gui_state.update_region_map(Region::Delete(DeleteButton::Confirm), yes_area);
+    gui_state.update_region_map(Region::Delete(DeleteButton::Force), force_area);
+    gui_state.update_region_map(Region::Delete(DeleteButton::Volumes), volumes_area);
 }
 
 #[cfg(test)]
@@ -134,13 +222,16 @@ mod tests {
     use crate::{
         app_data::ContainerName,
         config::{AppColors, Keymap},
-        ui::draw_blocks::tests::{get_result, test_setup},
+        ui::{
+            DeleteOptions,
+            draw_blocks::tests::{get_result, test_setup},
+        },
     };
 
     #[test]
     /// Delete container popup is drawn correctly
     fn test_draw_blocks_delete() {
-        let mut setup = test_setup(82, 10, true, true);
+        let mut setup = test_setup(82, 12, true, true);
 
         let colors = setup.app_data.lock().config.app_colors;
         let keymap = &setup.app_data.lock().config.keymap;
@@ -153,7 +244,7 @@ mod tests {
                     f,
                     &setup.gui_state,
                     keymap,
-                    &ContainerName::from("container_1"),
+                    &[ContainerName::from("container_1")],
                 );
             })
             .unwrap();
@@ -162,7 +253,7 @@ mod tests {
         for (row_index, result_row) in get_result(&setup) {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
-                    (0 | 9, _) | (1..=8, 0..=7 | 74..=81) => {
+                    (0 | 11, _) | (1..=10, 0..=7 | 74..=81) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
@@ -180,10 +271,57 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Enabled delete options are highlighted
+    fn test_draw_blocks_delete_options() {
+        let mut setup = test_setup(82, 12, true, true);
+        setup.gui_state.lock().set_delete_options(DeleteOptions {
+            force: true,
+            volumes: true,
+        });
+
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = &setup.app_data.lock().config.keymap;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    colors,
+                    f,
+                    &setup.gui_state,
+                    keymap,
+                    &[ContainerName::from("container_1")],
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (0 | 11, _) | (1..=10, 0..=7 | 74..=81) => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                        assert_eq!(result_cell.fg, Color::Reset);
+                    }
+                    (3, 57..=67) | (5, 25..=33 | 53..=63) => {
+                        assert_eq!(result_cell.bg, Color::White);
+                        assert_eq!(result_cell.fg, Color::Red);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.bg, Color::White);
+                        assert_eq!(result_cell.fg, Color::Black);
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     /// Delete container popup is drawn correctly
     fn test_draw_blocks_delete_long_name() {
-        let mut setup = test_setup(106, 10, true, true);
+        let mut setup = test_setup(106, 12, true, true);
         let name = ContainerName::from("container_1_container_1_container_1");
         setup.app_data.lock().containers.items[0].name = name.clone();
 
@@ -193,14 +331,20 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(colors, f, &setup.gui_state, keymap, &name);
+                super::draw(
+                    colors,
+                    f,
+                    &setup.gui_state,
+                    keymap,
+                    std::slice::from_ref(&name),
+                );
             })
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
         for (row_index, result_row) in get_result(&setup) {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
-                    (0 | 9, _) | (1..=8, 0..=7 | 98..=106) => {
+                    (0 | 11, _) | (1..=10, 0..=7 | 98..=106) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
@@ -221,7 +365,7 @@ mod tests {
     #[test]
     /// Custom colors applied correctly to delete popup
     fn test_draw_blocks_delete_custom_colors() {
-        let mut setup = test_setup(82, 10, true, true);
+        let mut setup = test_setup(82, 12, true, true);
         let mut colors = AppColors::new();
         colors.popup_delete.background = Color::Black;
         colors.popup_delete.text = Color::Yellow;
@@ -235,7 +379,7 @@ mod tests {
                     f,
                     &setup.gui_state,
                     &Keymap::new(),
-                    &ContainerName::from("container_1"),
+                    &[ContainerName::from("container_1")],
                 );
             })
             .unwrap();
@@ -245,7 +389,7 @@ mod tests {
         for (row_index, result_row) in get_result(&setup) {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
-                    (0 | 9, _) | (1..=8, 0..=7 | 74..=81) => {
+                    (0 | 11, _) | (1..=10, 0..=7 | 74..=81) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
@@ -266,7 +410,7 @@ mod tests {
     #[test]
     /// Custom keymap, with multiple definitions for each button, applied correctly to delete popup
     fn test_draw_blocks_delete_custom_keymap_one_definition() {
-        let mut setup = test_setup(82, 10, true, true);
+        let mut setup = test_setup(82, 12, true, true);
         let mut keymap = Keymap::new();
         keymap.delete_confirm = (KeyCode::F(10), None);
         keymap.delete_deny = (KeyCode::End, None);
@@ -278,7 +422,7 @@ mod tests {
                     f,
                     &setup.gui_state,
                     &keymap,
-                    &ContainerName::from("container_1"),
+                    &[ContainerName::from("container_1")],
                 );
             })
             .unwrap();
@@ -287,7 +431,7 @@ mod tests {
     #[test]
     /// Custom keymap, with multiple definitions for each button, applied correctly to delete popup
     fn test_draw_blocks_delete_custom_keymap_two_definition() {
-        let mut setup = test_setup(82, 10, true, true);
+        let mut setup = test_setup(82, 12, true, true);
         let mut keymap = Keymap::new();
         keymap.delete_confirm = (KeyCode::F(10), Some(KeyCode::Char('L')));
         keymap.delete_deny = (KeyCode::End, Some(KeyCode::Up));
@@ -299,7 +443,7 @@ mod tests {
                     f,
                     &setup.gui_state,
                     &keymap,
-                    &ContainerName::from("container_1"),
+                    &[ContainerName::from("container_1")],
                 );
             })
             .unwrap();
@@ -308,7 +452,7 @@ mod tests {
     #[test]
     /// Custom keymap, with multiple definitions for each button, applied correctly to delete popup
     fn test_draw_blocks_delete_custom_keymap_one_two_definition() {
-        let mut setup = test_setup(82, 10, true, true);
+        let mut setup = test_setup(82, 12, true, true);
         let mut keymap = Keymap::new();
         keymap.delete_confirm = (KeyCode::F(10), None);
         keymap.delete_deny = (KeyCode::End, Some(KeyCode::Up));
@@ -320,10 +464,64 @@ mod tests {
                     f,
                     &setup.gui_state,
                     &keymap,
-                    &ContainerName::from("container_1"),
+                    &[ContainerName::from("container_1")],
                 );
             })
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
     }
+
+    #[test]
+    /// When multiple containers are to be deleted, the number of containers is shown, followed by the name of every container
+    fn test_draw_blocks_delete_multiple() {
+        let mut setup = test_setup(82, 13, true, true);
+
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = &setup.app_data.lock().config.keymap;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    colors,
+                    f,
+                    &setup.gui_state,
+                    keymap,
+                    &[
+                        ContainerName::from("container_1"),
+                        ContainerName::from("container_2"),
+                        ContainerName::from("container_3"),
+                    ],
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if (row_index == 3 && (51..=62).contains(&result_cell_index))
+                    || (row_index == 4 && (23..=59).contains(&result_cell_index))
+                {
+                    assert_eq!(result_cell.fg, Color::Red);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Names are joined into lines no wider than the given width, a single name wider than the width is kept whole
+    fn test_draw_blocks_delete_name_lines() {
+        let names =
+            ["container_1", "container_2", "a_much_longer_container_name"].map(ContainerName::from);
+        assert_eq!(
+            super::name_lines(&names, 26),
+            ["container_1, container_2", "a_much_longer_container_name"]
+        );
+        assert_eq!(
+            super::name_lines(&names, 64),
+            ["container_1, container_2, a_much_longer_container_name"]
+        );
+        assert!(super::name_lines(&[], 64).is_empty());
+    }
 }
@@ -0,0 +1,442 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, HighlightSpacing, List, ListItem, Paragraph},
+};
+
+use super::{CIRCLE, MARGIN, key_text};
+use crate::{
+    app_data::{AppData, ByteStats, DiskUsageCategory, DiskUsageHeader, SortedOrder},
+    config::{AppColors, Keymap},
+};
+
+/// The text of each column, for a single category
+struct Row<'a> {
+    category: &'a DiskUsageCategory,
+    size: String,
+    reclaimable: String,
+}
+
+impl<'a> Row<'a> {
+    fn new(category: &'a DiskUsageCategory) -> Self {
+        Self {
+            category,
+            size: category.size.to_string(),
+            reclaimable: format!(
+                "{} ({}%)",
+                category.reclaimable, category.reclaimable_percent
+            ),
+        }
+    }
+}
+
+/// The widths of each column, calculated from the longest value in each column
+#[derive(Debug, Clone, Copy)]
+struct Widths {
+    kind: usize,
+    host: Option<usize>,
+    total: usize,
+    active: usize,
+    size: usize,
+}
+
+impl Widths {
+    fn new(rows: &[Row], show_host: bool) -> Self {
+        let max = |header: &str, f: &dyn Fn(&Row) -> usize| {
+            rows.iter()
+                .map(f)
+                .max()
+                .unwrap_or_default()
+                .max(header.chars().count())
+        };
+        Self {
+            kind: max("type", &|i| i.category.kind.to_string().chars().count()),
+            host: show_host.then(|| max("host", &|i| i.category.host.to_string().chars().count())),
+            total: max("total", &|i| i.category.total.to_string().chars().count()),
+            active: max("active", &|i| i.category.active.to_string().chars().count()),
+            size: max("size", &|i| i.size.chars().count()),
+        }
+    }
+}
+
+/// Format a single category, padded to the column widths
+fn format_category<'a>(colors: AppColors, row: Row, w: Widths) -> Line<'a> {
+    let mut spans = vec![
+        Span::from(format!(
+            "{:<width$}{MARGIN}",
+            row.category.kind.to_string(),
+            width = w.kind
+        )),
+        Span::from(format!(
+            "{:>width$}{MARGIN}",
+            row.category.total,
+            width = w.total
+        )),
+        Span::from(format!(
+            "{:>width$}{MARGIN}",
+            row.category.active,
+            width = w.active
+        )),
+        Span::from(format!("{:>width$}{MARGIN}", row.size, width = w.size)),
+        Span::from(row.reclaimable),
+    ];
+    if let Some(width) = w.host {
+        spans.insert(
+            1,
+            Span::from(format!("{:<width$}{MARGIN}", row.category.host.to_string())),
+        );
+    }
+    Line::from(spans).fg(colors.containers.text)
+}
+
+/// Format the column headers, indented to line up with the list items
+fn format_header<'a>(colors: AppColors, w: Widths) -> Line<'a> {
+    let mut text = format!(
+        "{}{:<kind$}{MARGIN}",
+        " ".repeat(Span::from(CIRCLE).width()),
+        "type",
+        kind = w.kind
+    );
+    if let Some(width) = w.host {
+        text.push_str(&format!("{:<width$}{MARGIN}", "host"));
+    }
+    text.push_str(&format!(
+        "{:>total$}{MARGIN}{:>active$}{MARGIN}{:>size$}{MARGIN}reclaimable",
+        "total",
+        "active",
+        "size",
+        total = w.total,
+        active = w.active,
+        size = w.size
+    ));
+    Line::from(text)
+        .fg(colors.containers.text)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// The header text, with an arrow if the items are currently sorted by that header
+fn header_text(
+    header: DiskUsageHeader,
+    sorted_by: Option<(DiskUsageHeader, SortedOrder)>,
+) -> String {
+    let text = match header {
+        DiskUsageHeader::Size => "size",
+        DiskUsageHeader::Name => "name",
+    };
+    match sorted_by {
+        Some((h, SortedOrder::Asc)) if h == header => format!("{text} ▲"),
+        Some((h, SortedOrder::Desc)) if h == header => format!("{text} ▼"),
+        _ => text.to_owned(),
+    }
+}
+
+/// Draw the items of the selected category, largest first unless sorted, items not in use are dimmed as they are the ones that can be reclaimed
+/// The border is only highlighted when focused, as the scroll keys then move through the items rather than the categories
+fn draw_items(
+    app_data: &mut AppData,
+    area: Rect,
+    colors: AppColors,
+    f: &mut Frame,
+    category: Option<&DiskUsageCategory>,
+) {
+    let focused = app_data.is_disk_usage_items_focused();
+    let sorted_by = app_data.get_disk_usage_sorted();
+    let title = category.map_or_else(String::new, |i| {
+        if sorted_by.is_some() {
+            format!(" {} ", i.kind)
+        } else {
+            format!(" Largest {} ", i.kind)
+        }
+    });
+    let border = if focused {
+        colors.borders.selected
+    } else {
+        colors.borders.unselected
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(border))
+        .title(title);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let items = app_data.get_disk_usage_sorted_items();
+    if items.is_empty() {
+        let paragraph = Paragraph::new("nothing to show")
+            .fg(colors.containers.text)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, inner);
+        return;
+    }
+
+    let size_header = header_text(DiskUsageHeader::Size, sorted_by);
+    let sizes = items.iter().map(|i| i.size.to_string()).collect::<Vec<_>>();
+    let size_width = sizes
+        .iter()
+        .map(|i| i.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(size_header.chars().count());
+
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)])
+        .split(inner);
+
+    let header = Line::from(format!(
+        "{}{size_header:>size_width$}{MARGIN}{}",
+        " ".repeat(Span::from(CIRCLE).width()),
+        header_text(DiskUsageHeader::Name, sorted_by)
+    ))
+    .fg(colors.containers.text)
+    .add_modifier(Modifier::BOLD);
+    f.render_widget(Paragraph::new(header), split[0]);
+
+    let lines = items
+        .iter()
+        .zip(sizes)
+        .map(|(item, size)| {
+            let color = if item.in_use {
+                colors.containers.text
+            } else {
+                colors.containers.text_disconnected
+            };
+            ListItem::new(Line::from(format!("{size:>size_width$}{MARGIN}{}", item.name)).fg(color))
+        })
+        .collect::<Vec<_>>();
+    // Only the focused list has a selected item, the spacing is kept so that the items line up with the header
+    let list = List::new(lines)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .highlight_symbol(CIRCLE)
+        .highlight_spacing(HighlightSpacing::Always);
+    f.render_stateful_widget(list, split[1], app_data.get_disk_usage_items_state());
+}
+
+/// Draw the disk usage view to the entire screen
+pub fn draw(app_data: &Arc<Mutex<AppData>>, colors: AppColors, f: &mut Frame, keymap: &Keymap) {
+    let mut app_data = app_data.lock();
+    let show_host = app_data.config.hosts.len() > 1;
+
+    let items = app_data.get_disk_usage_items().to_vec();
+    let selected = app_data.get_selected_disk_usage().cloned();
+    let rows = items.iter().map(Row::new).collect::<Vec<_>>();
+    let widths = Widths::new(&rows, show_host);
+
+    let title = if items.is_empty() {
+        " Disk Usage ".to_owned()
+    } else {
+        format!(
+            " Disk Usage{} - total {}, reclaimable {} ",
+            app_data.get_disk_usage_title(),
+            items.iter().map(|i| i.size).sum::<ByteStats>(),
+            items.iter().map(|i| i.reclaimable).sum::<ByteStats>(),
+        )
+    };
+    let keys = format!(
+        " ( {} ) categories|items  ( {} ) sort name  ( {} ) sort size  ( {} ) prune  ( {} ) close ",
+        key_text(keymap.select_next_panel),
+        key_text(keymap.sort_by_name),
+        key_text(keymap.sort_by_memory),
+        key_text(keymap.prune),
+        key_text(keymap.disk_usage)
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(colors.borders.selected))
+        .title(Line::from(title).left_aligned())
+        .title_bottom(Line::from(keys).right_aligned())
+        .bg(colors.containers.background);
+
+    let area = f.area();
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if rows.is_empty() {
+        let paragraph = Paragraph::new("no disk usage data")
+            .fg(colors.containers.text)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, inner);
+        return;
+    }
+
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(1),
+            Constraint::Length(u16::try_from(rows.len()).unwrap_or(u16::MAX)),
+            Constraint::Min(1),
+        ])
+        .split(inner);
+
+    f.render_widget(Paragraph::new(format_header(colors, widths)), split[0]);
+
+    let list = List::new(
+        rows.into_iter()
+            .map(|row| ListItem::new(format_category(colors, row, widths)))
+            .collect::<Vec<_>>(),
+    )
+    .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+    .highlight_symbol(CIRCLE);
+
+    f.render_stateful_widget(list, split[1], app_data.get_disk_usage_state());
+    draw_items(&mut app_data, split[2], colors, f, selected.as_ref());
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::secret::{
+        ImageSummary, ImagesDiskUsage, SystemDataUsageResponse, Volume, VolumeUsageData,
+        VolumesDiskUsage,
+    };
+    use insta::assert_snapshot;
+    use ratatui::style::Modifier;
+
+    use crate::{
+        app_data::{ContainerHost, DiskUsageCategory, DiskUsageHeader},
+        ui::draw_blocks::tests::{TuiTestSetup, get_result, test_setup},
+    };
+
+    /// Insert the disk usage of two images, one in use, and one volume that isn't in use
+    fn insert_disk_usage(setup: &TuiTestSetup) {
+        let host = ContainerHost::default();
+        let image = |tag: &str, size: i64, containers: i64| {
+            serde_json::to_value(ImageSummary {
+                id: format!("sha256:{tag}"),
+                repo_tags: vec![tag.to_owned()],
+                size,
+                containers,
+                ..Default::default()
+            })
+            .unwrap()
+        };
+        let response = SystemDataUsageResponse {
+            images_disk_usage: Some(ImagesDiskUsage {
+                active_count: Some(1),
+                total_count: Some(2),
+                total_size: Some(3_000_000),
+                reclaimable: Some(1_000_000),
+                items: Some(vec![
+                    image("redis:latest", 1_000_000, 0),
+                    image("postgres:latest", 2_000_000, 1),
+                ]),
+            }),
+            volumes_disk_usage: Some(VolumesDiskUsage {
+                active_count: Some(0),
+                total_count: Some(1),
+                total_size: Some(500_000),
+                reclaimable: Some(500_000),
+                items: Some(vec![
+                    serde_json::to_value(Volume {
+                        name: "postgres_data".to_owned(),
+                        usage_data: Some(VolumeUsageData {
+                            size: 500_000,
+                            ref_count: 0,
+                        }),
+                        ..Default::default()
+                    })
+                    .unwrap(),
+                ]),
+            }),
+            ..Default::default()
+        };
+        let categories = DiskUsageCategory::from_response(response, &host);
+        setup.app_data.lock().update_disk_usage(&host, categories);
+    }
+
+    #[test]
+    /// Disk usage view is drawn with the totals in the title, and the largest items of the selected category listed
+    fn test_draw_blocks_disk_usage() {
+        let mut setup = test_setup(100, 12, true, true);
+        insert_disk_usage(&setup);
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (1 | 2, 4..=10) => {
+                        assert_eq!(result_cell.fg, colors.containers.text);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    (3, 4..=10) => {
+                        assert!(!result_cell.modifier.contains(Modifier::BOLD));
+                    }
+                    (6, 5..=29) => assert_eq!(result_cell.fg, colors.containers.text),
+                    (7, 5..=26) => {
+                        assert_eq!(result_cell.fg, colors.containers.text_disconnected);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When the items are focused and sorted, the items border is highlighted, the sorted header has an arrow, and the selected item is bold
+    fn test_draw_blocks_disk_usage_items_focused() {
+        let mut setup = test_setup(100, 12, true, true);
+        insert_disk_usage(&setup);
+        setup.app_data.lock().toggle_disk_usage_focus();
+        setup
+            .app_data
+            .lock()
+            .set_disk_usage_sort_by_header(DiskUsageHeader::Name);
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (4, 1) => assert_eq!(result_cell.fg, colors.borders.selected),
+                    (6, 5..=26) => assert_eq!(result_cell.modifier, Modifier::BOLD),
+                    (7, 5..=29) => {
+                        assert!(!result_cell.modifier.contains(Modifier::BOLD));
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Disk usage view before any data has been received
+    fn test_draw_blocks_disk_usage_empty() {
+        let mut setup = test_setup(100, 8, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
@@ -9,6 +9,7 @@ use super::{NAME, VERSION, max_line_width};
 use crate::{
     app_error::AppError,
     config::{AppColors, Keymap},
+    docker_data::ConnectDiagnostics,
     ui::gui_state::BoxLocation,
 };
 
@@ -17,12 +18,38 @@ use super::popup;
 const SUFFIX_CLEAR: &str = "clear error";
 const SUFFIX_QUIT: &str = "quit oxker";
 
+/// Generate the text of every host that was tried, why each failed, with a hint of how to fix it, and the relevant env vars
+fn gen_diagnostics_text(diagnostics: &ConnectDiagnostics) -> String {
+    let mut text = String::new();
+    if !diagnostics.attempts.is_empty() {
+        text.push('\n');
+    }
+    for attempt in &diagnostics.attempts {
+        text.push_str(&format!(
+            "\n{}: {}\n{}",
+            attempt.host,
+            attempt.failure,
+            attempt.failure.hint()
+        ));
+    }
+    if !diagnostics.env.is_empty() {
+        text.push('\n');
+    }
+    for (key, value) in &diagnostics.env {
+        text.push_str(&format!(
+            "\n${key}: {}",
+            value.as_deref().unwrap_or("not set")
+        ));
+    }
+    text
+}
+
 /// Draw an error popup over whole screen
 pub fn draw(
     colors: AppColors,
     error: &AppError,
     f: &mut Frame,
-    host: Option<String>,
+    diagnostics: Option<&ConnectDiagnostics>,
     keymap: &Keymap,
     seconds: Option<u8>,
 ) {
@@ -35,18 +62,15 @@ pub fn draw(
     let mut text = format!("\n{error}");
 
     if error == &AppError::DockerConnect {
-        let s = if let Some(host) = host {
-            format!(" @ \"{host}\"")
-        } else {
-            String::new()
-        };
+        if let Some(diagnostics) = diagnostics {
+            text.push_str(&gen_diagnostics_text(diagnostics));
+        }
         text.push_str(&format!(
-            "{}\n\n {}::v{} closing in {:02} seconds",
-            s,
+            "\n\n {}::v{} closing in {:02} seconds",
             NAME,
             VERSION,
             seconds.unwrap_or(5),
-        ))
+        ));
     } else {
         let clear_text = if keymap.clear == Keymap::new().clear {
             format!("( {} ) {SUFFIX_CLEAR}", keymap.clear.0)
@@ -103,6 +127,7 @@ mod tests {
     use crate::{
         app_error::AppError,
         config::{AppColors, Keymap},
+        docker_data::{ConnectAttempt, ConnectDiagnostics, ConnectFailure},
         ui::draw_blocks::tests::{get_result, test_setup},
     };
     use crossterm::event::KeyCode;
@@ -144,9 +169,16 @@ mod tests {
     }
 
     #[test]
-    /// Test that the error popup is centered, red background, white border, white text, and displays the correct text with the custom docker host address
+    /// Test that the error popup is centered, red background, white border, white text, and displays the diagnostics of the custom docker host address, with a hint, and the env vars
     fn test_draw_blocks_error_docker_connect_error_custom_host() {
-        let mut setup = test_setup(60, 11, true, true);
+        let mut setup = test_setup(96, 15, true, true);
+        let diagnostics = ConnectDiagnostics {
+            attempts: vec![ConnectAttempt {
+                host: "/test/host.sock".to_owned(),
+                failure: ConnectFailure::PermissionDenied,
+            }],
+            env: vec![("DOCKER_HOST".to_owned(), None)],
+        };
 
         setup
             .terminal
@@ -155,7 +187,7 @@ mod tests {
                     AppColors::new(),
                     &AppError::DockerConnect,
                     f,
-                    Some("/test/host.sock".to_owned()),
+                    Some(&diagnostics),
                     &Keymap::new(),
                     Some(4),
                 );
@@ -165,7 +197,7 @@ mod tests {
         for (row_index, result_row) in get_result(&setup) {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
-                    (0 | 10, _) | (_, 0 | 59) => {
+                    (0 | 14, _) | (_, 0 | 95) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
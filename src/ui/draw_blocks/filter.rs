@@ -15,7 +15,7 @@ use crate::{
 };
 
 /// Create the filter_by by spans, coloured dependant on which one is selected
-fn filter_by_spans(colors: AppColors, fd: &'_ FrameData) -> [Span<'_>; 4] {
+fn filter_by_spans(colors: AppColors, fd: &'_ FrameData) -> [Span<'_>; 5] {
     let selected = Style::default()
         .bg(colors.filter.selected_filter_background)
         .fg(colors.filter.selected_filter_text);
@@ -23,20 +23,22 @@ fn filter_by_spans(colors: AppColors, fd: &'_ FrameData) -> [Span<'_>; 4] {
         .bg(colors.filter.background)
         .fg(colors.filter.text);
 
-    let name = [" Name ", " Image ", " Status ", " All "];
+    let name = [" Name ", " Image ", " Status ", " Host ", " All "];
 
     let mut filter_spans = [
         Span::styled(name[0], not_selected),
         Span::styled(name[1], not_selected),
         Span::styled(name[2], not_selected),
         Span::styled(name[3], not_selected),
+        Span::styled(name[4], not_selected),
     ];
 
     match fd.filter_by {
         FilterBy::Name => filter_spans[0] = Span::styled(name[0], selected),
         FilterBy::Image => filter_spans[1] = Span::styled(name[1], selected),
         FilterBy::Status => filter_spans[2] = Span::styled(name[2], selected),
-        FilterBy::All => filter_spans[3] = Span::styled(name[3], selected),
+        FilterBy::Host => filter_spans[3] = Span::styled(name[3], selected),
+        FilterBy::All => filter_spans[4] = Span::styled(name[4], selected),
     }
     filter_spans
 }
@@ -115,7 +117,7 @@ mod tests {
                         assert_eq!(result_cell.bg, Color::Magenta);
                         assert_eq!(result_cell.fg, Color::Black);
                     }
-                    5..=11 | 27..=46 => {
+                    5..=11 | 27..=52 => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Gray);
                     }
@@ -123,7 +125,7 @@ mod tests {
                         assert_eq!(result_cell.bg, Color::Gray);
                         assert_eq!(result_cell.fg, Color::Black);
                     }
-                    47..=60 => {
+                    53..=66 => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Magenta);
                         assert_eq!(result_cell.modifier, Modifier::BOLD);
@@ -173,7 +175,7 @@ mod tests {
                         assert_eq!(result_cell.bg, Color::Magenta);
                         assert_eq!(result_cell.fg, Color::Black);
                     }
-                    5..=11 | 27..=46 | 61..=62 => {
+                    5..=11 | 27..=52 | 67..=68 => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Gray);
                     }
@@ -181,7 +183,7 @@ mod tests {
                         assert_eq!(result_cell.bg, Color::Gray);
                         assert_eq!(result_cell.fg, Color::Black);
                     }
-                    47..=60 => {
+                    53..=66 => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Magenta);
                         assert_eq!(result_cell.modifier, Modifier::BOLD);
@@ -221,7 +223,7 @@ mod tests {
                         assert_eq!(result_cell.bg, Color::Magenta);
                         assert_eq!(result_cell.fg, Color::Black);
                     }
-                    5..=11 | 27..=46 => {
+                    5..=11 | 27..=52 => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Gray);
                     }
@@ -229,7 +231,7 @@ mod tests {
                         assert_eq!(result_cell.bg, Color::Gray);
                         assert_eq!(result_cell.fg, Color::Black);
                     }
-                    47..=60 => {
+                    53..=66 => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Magenta);
                         assert_eq!(result_cell.modifier, Modifier::BOLD);
@@ -280,7 +282,7 @@ mod tests {
                         assert_eq!(result_cell.bg, Color::Blue);
                         assert_eq!(result_cell.fg, Color::Yellow);
                     }
-                    5..=11 | 27..=46 | 61..=62 => {
+                    5..=11 | 27..=52 | 67..=68 => {
                         assert_eq!(result_cell.bg, Color::White);
                         assert_eq!(result_cell.fg, Color::Magenta);
                     }
@@ -288,7 +290,7 @@ mod tests {
                         assert_eq!(result_cell.bg, Color::Red);
                         assert_eq!(result_cell.fg, Color::Yellow);
                     }
-                    47..=60 => {
+                    53..=66 => {
                         assert_eq!(result_cell.bg, Color::White);
                         assert_eq!(result_cell.fg, Color::Blue);
                         assert_eq!(result_cell.modifier, Modifier::BOLD);
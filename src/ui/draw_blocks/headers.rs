@@ -79,6 +79,31 @@ fn gen_help_text(fd: &FrameData, keymap: &Keymap) -> String {
     }
 }
 
+/// Generate the text to display when a Docker host has been disconnected, hosts are only named when monitoring multiple hosts
+fn gen_disconnected_text(fd: &FrameData) -> Option<String> {
+    if fd.disconnected.is_empty() {
+        None
+    } else if fd.columns.host.is_some() {
+        let hosts = fd
+            .disconnected
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(format!("✖ {hosts} disconnected, retrying{MARGIN}"))
+    } else {
+        Some(format!("✖ disconnected, retrying{MARGIN}"))
+    }
+}
+
+/// Draw the disconnected section, to the left of the help section
+fn draw_disconnected(colors: AppColors, f: &mut Frame, text: String, rect: Rect) {
+    let paragraph = Paragraph::new(text)
+        .style(gen_style(None, colors.headers_bar.text_selected))
+        .alignment(Alignment::Right);
+    f.render_widget(paragraph, rect);
+}
+
 /// Draw the show/hide help section
 fn draw_help(
     colors: AppColors,
@@ -86,7 +111,7 @@ fn draw_help(
     fd: &FrameData,
     help_text: String,
     gui_state: &Arc<Mutex<GuiState>>,
-    split_bar: &Rc<[Rect]>,
+    rect: Rect,
 ) {
     let help_text_color = if fd.status.contains(&Status::Help) {
         colors.headers_bar.text
@@ -98,12 +123,8 @@ fn draw_help(
         .style(gen_style(None, help_text_color))
         .alignment(Alignment::Right);
 
-    // If no containers, don't display the headers, could maybe do this first?
-    let help_index = if fd.has_containers { 2 } else { 0 };
-    gui_state
-        .lock()
-        .update_region_map(Region::HelpPanel, split_bar[help_index]);
-    f.render_widget(help_paragraph, split_bar[help_index]);
+    gui_state.lock().update_region_map(Region::HelpPanel, rect);
+    f.render_widget(help_paragraph, rect);
 }
 
 // Draw loading icon, or not, and a prefix with a single space
@@ -128,7 +149,7 @@ fn draw_columns(
         let mut counter = 0;
 
         // Meta data to iterate over to create blocks with correct widths
-        let header_meta = [
+        let mut header_meta = vec![
             (Header::Name, fd.columns.name.1),
             (Header::State, fd.columns.state.1),
             (Header::Status, fd.columns.status.1),
@@ -139,6 +160,15 @@ fn draw_columns(
             (Header::Rx, fd.columns.net_rx.1),
             (Header::Tx, fd.columns.net_tx.1),
         ];
+        // The host column is only present when monitoring multiple hosts, and sits next to the name column
+        if let Some(host) = fd.columns.host {
+            header_meta.insert(1, host);
+        }
+        // Optional columns, only present when enabled in the config
+        header_meta.extend(fd.columns.disk_read);
+        header_meta.extend(fd.columns.disk_write);
+        header_meta.extend(fd.columns.pids);
+        header_meta.extend(fd.columns.throttled);
 
         // Only show a header if the header cumulative header width is less than the header section width
         let header_data = header_meta
@@ -192,18 +222,27 @@ pub fn draw(
 
     let help_text = gen_help_text(fd, keymap);
     let help_width = help_text.chars().count();
+    let disconnected_text = gen_disconnected_text(fd);
+    let disconnected_width = disconnected_text.as_ref().map_or(0, |i| i.chars().count());
 
-    let column_width = usize::from(area.width).saturating_sub(help_width);
+    let column_width = usize::from(area.width).saturating_sub(help_width + disconnected_width);
     let column_width = if column_width > 0 { column_width } else { 1 };
 
     let split_bar = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints(if fd.has_containers {
-            vec![
+        .constraints(if fd.has_containers || disconnected_text.is_some() {
+            let mut constraints = vec![
                 Constraint::Max(4),
                 Constraint::Max(column_width.try_into().unwrap_or_default()),
                 Constraint::Max(help_width.try_into().unwrap_or_default()),
-            ]
+            ];
+            if disconnected_text.is_some() {
+                constraints.insert(
+                    2,
+                    Constraint::Max(disconnected_width.try_into().unwrap_or_default()),
+                );
+            }
+            constraints
         } else {
             CONSTRAINT_100.to_vec()
         })
@@ -211,7 +250,13 @@ pub fn draw(
 
     draw_loading_spinner(colors, f, fd, split_bar[0]);
     draw_columns(colors, f, fd, gui_state, &split_bar);
-    draw_help(colors, f, fd, help_text, gui_state, &split_bar);
+    if let Some(text) = disconnected_text {
+        draw_disconnected(colors, f, text, split_bar[2]);
+    }
+    // If no containers, don't display the headers, could maybe do this first?
+    if let Some(rect) = split_bar.last() {
+        draw_help(colors, f, fd, help_text, gui_state, *rect);
+    }
 }
 
 #[cfg(test)]
@@ -225,7 +270,7 @@ mod tests {
     use uuid::Uuid;
 
     use crate::{
-        app_data::{Header, SortedOrder, StatefulList},
+        app_data::{ContainerHost, Header, SortedOrder, StatefulList},
         config::{AppColors, Keymap},
         ui::{
             FrameData, Status,
@@ -332,6 +377,130 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Optional pids & throttled headers are shown when enabled in the config
+    fn test_draw_blocks_headers_some_containers_pids_throttled() {
+        let mut setup = test_setup(180, 1, true, true);
+        setup.app_data.lock().config.show_pids = true;
+        setup.app_data.lock().config.show_throttling = true;
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    setup.area,
+                    AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// Optional disk read & write headers are shown when enabled in the config
+    fn test_draw_blocks_headers_some_containers_disk_io() {
+        let mut setup = test_setup(160, 1, true, true);
+        setup.app_data.lock().config.show_disk_io = true;
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    setup.area,
+                    AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// Host column is shown after the name column when monitoring multiple hosts
+    fn test_draw_blocks_headers_some_containers_hosts() {
+        let mut setup = test_setup(180, 1, true, true);
+        setup.app_data.lock().config.hosts = vec!["host_a".to_owned(), "host_b".to_owned()];
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    setup.area,
+                    AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// A disconnected host is shown to the left of the help section, and named when monitoring multiple hosts
+    fn test_draw_blocks_headers_disconnected() {
+        let mut setup = test_setup(180, 1, true, true);
+        setup
+            .app_data
+            .lock()
+            .set_host_connected(&ContainerHost::default(), false);
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    setup.area,
+                    AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+        for (_, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if (139..=162).contains(&result_cell_index) {
+                    assert_eq!(result_cell.fg, Color::Gray);
+                }
+            }
+        }
+
+        setup.app_data.lock().config.hosts = vec!["host_a".to_owned(), "host_b".to_owned()];
+        setup
+            .app_data
+            .lock()
+            .set_host_connected(&ContainerHost::default(), true);
+        setup
+            .app_data
+            .lock()
+            .set_host_connected(&ContainerHost::from("host_b"), false);
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    setup.area,
+                    AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
     #[test]
     /// Only show the headings that fit the reduced-in-size header section
     fn test_draw_blocks_headers_some_containers_reduced_width() {
@@ -23,16 +23,26 @@ macro_rules! to_u16 {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum KeyDescriptions {
+    BulkAction,
+    Changes,
     Clear,
     Command,
+    ContextPicker,
+    Copy,
+    DiskUsage,
     Exec,
     FilterMode,
+    GroupProjects,
     Help,
+    Images,
     InspectMode,
     LogHeight,
     LogVisibility,
+    Mark,
     MouseCapture,
+    Networks,
     Panel,
+    Processes,
     Quit,
     Redraw,
     Save,
@@ -43,16 +53,22 @@ enum KeyDescriptions {
     ScrollV,
     SearchMode,
     SortCpu,
+    SortDiskRead,
+    SortDiskWrite,
     SortHeader,
+    SortHost,
     SortId,
     SortImage,
     SortMem,
     SortName,
+    SortPids,
     SortRX,
     SortState,
     SortStatus,
     SortStop,
     SortTX,
+    SortThrottled,
+    Volumes,
 }
 
 type Column = Vec<(Vec<Option<String>>, KeyDescriptions)>;
@@ -129,7 +145,10 @@ impl KeymapColumns {
                     ],
                     KeyDescriptions::LogHeight,
                 ),
-                (vec![Some("1 ~ 9".to_owned())], KeyDescriptions::SortHeader),
+                (
+                    vec![Some("1 ~ 9 [ ] ; , .".to_owned())],
+                    KeyDescriptions::SortHeader,
+                ),
                 (
                     vec![
                         Some(keymap.select_next_panel.0.to_string()),
@@ -150,6 +169,41 @@ impl KeymapColumns {
                     ],
                     KeyDescriptions::Save,
                 ),
+                (
+                    vec![
+                        Some(keymap.mark.0.to_string()),
+                        keymap.mark.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Mark,
+                ),
+                (
+                    vec![
+                        Some(keymap.group_projects.0.to_string()),
+                        keymap.group_projects.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::GroupProjects,
+                ),
+                (
+                    vec![
+                        Some(keymap.volumes.0.to_string()),
+                        keymap.volumes.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Volumes,
+                ),
+                (
+                    vec![
+                        Some(keymap.disk_usage.0.to_string()),
+                        keymap.disk_usage.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::DiskUsage,
+                ),
+                (
+                    vec![
+                        Some(keymap.changes.0.to_string()),
+                        keymap.changes.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Changes,
+                ),
             ],
             right: vec![
                 (
@@ -209,6 +263,50 @@ impl KeymapColumns {
                     ],
                     KeyDescriptions::MouseCapture,
                 ),
+                (
+                    vec![
+                        Some(keymap.context_picker.0.to_string()),
+                        keymap.context_picker.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::ContextPicker,
+                ),
+                (
+                    vec![
+                        Some(keymap.bulk_action.0.to_string()),
+                        keymap.bulk_action.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::BulkAction,
+                ),
+                (
+                    vec![
+                        Some(keymap.images.0.to_string()),
+                        keymap.images.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Images,
+                ),
+                (
+                    vec![
+                        Some(keymap.networks.0.to_string()),
+                        keymap.networks.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Networks,
+                ),
+                (
+                    vec![
+                        Some(keymap.processes.0.to_string()),
+                        keymap.processes.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Processes,
+                ),
+                (
+                    vec![
+                        Some(keymap.copy_from.0.to_string()),
+                        Some(keymap.copy_to.0.to_string()),
+                        keymap.copy_from.1.as_ref().map(|i| i.to_string()),
+                        keymap.copy_to.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Copy,
+                ),
             ],
         }
     }
@@ -333,6 +431,25 @@ impl KeymapColumns {
                     ],
                     KeyDescriptions::SortTX,
                 ),
+                (
+                    vec![
+                        Some(config.keymap.sort_by_disk_read.0.to_string()),
+                        config
+                            .keymap
+                            .sort_by_disk_read
+                            .1
+                            .as_ref()
+                            .map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::SortDiskRead,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.sort_by_pids.0.to_string()),
+                        config.keymap.sort_by_pids.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::SortPids,
+                ),
                 (
                     vec![
                         Some(config.keymap.select_next_panel.0.to_string()),
@@ -359,6 +476,46 @@ impl KeymapColumns {
                     ],
                     KeyDescriptions::Save,
                 ),
+                (
+                    vec![
+                        Some(config.keymap.mark.0.to_string()),
+                        config.keymap.mark.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Mark,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.group_projects.0.to_string()),
+                        config
+                            .keymap
+                            .group_projects
+                            .1
+                            .as_ref()
+                            .map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::GroupProjects,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.volumes.0.to_string()),
+                        config.keymap.volumes.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Volumes,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.disk_usage.0.to_string()),
+                        config.keymap.disk_usage.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::DiskUsage,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.changes.0.to_string()),
+                        config.keymap.changes.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Changes,
+                ),
             ],
 
             right: vec![
@@ -462,6 +619,37 @@ impl KeymapColumns {
                     ],
                     KeyDescriptions::SortRX,
                 ),
+                (
+                    vec![
+                        Some(config.keymap.sort_by_disk_write.0.to_string()),
+                        config
+                            .keymap
+                            .sort_by_disk_write
+                            .1
+                            .as_ref()
+                            .map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::SortDiskWrite,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.sort_by_host.0.to_string()),
+                        config.keymap.sort_by_host.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::SortHost,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.sort_by_throttled.0.to_string()),
+                        config
+                            .keymap
+                            .sort_by_throttled
+                            .1
+                            .as_ref()
+                            .map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::SortThrottled,
+                ),
                 (
                     vec![
                         Some(config.keymap.sort_reset.0.to_string()),
@@ -481,6 +669,55 @@ impl KeymapColumns {
                     ],
                     KeyDescriptions::MouseCapture,
                 ),
+                (
+                    vec![
+                        Some(config.keymap.context_picker.0.to_string()),
+                        config
+                            .keymap
+                            .context_picker
+                            .1
+                            .as_ref()
+                            .map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::ContextPicker,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.bulk_action.0.to_string()),
+                        config.keymap.bulk_action.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::BulkAction,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.images.0.to_string()),
+                        config.keymap.images.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Images,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.networks.0.to_string()),
+                        config.keymap.networks.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Networks,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.processes.0.to_string()),
+                        config.keymap.processes.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Processes,
+                ),
+                (
+                    vec![
+                        Some(config.keymap.copy_from.0.to_string()),
+                        Some(config.keymap.copy_to.0.to_string()),
+                        config.keymap.copy_from.1.as_ref().map(|i| i.to_string()),
+                        config.keymap.copy_to.1.as_ref().map(|i| i.to_string()),
+                    ],
+                    KeyDescriptions::Copy,
+                ),
             ],
         }
     }
@@ -531,16 +768,26 @@ impl KeymapColumns {
 impl KeyDescriptions {
     fn as_str(&self) -> &'static str {
         match self {
+            Self::BulkAction => "bulk action on filtered containers",
+            Self::Changes => "filesystem changes view",
             Self::Clear => "close dialog",
             Self::Command => "send docker command",
+            Self::ContextPicker => "select docker context",
+            Self::Copy => "copy a path from or to container",
+            Self::DiskUsage => "disk usage view",
             Self::Exec => "exec into a container",
             Self::FilterMode => "filter mode",
             Self::Help => "toggle this panel",
+            Self::Images => "images view",
             Self::InspectMode => "container inspect mode",
             Self::LogHeight => "change log section height",
             Self::LogVisibility => "toggle of section visibility",
+            Self::Mark => "mark or unmark container",
+            Self::GroupProjects => "group by compose project",
             Self::MouseCapture => "toggle mouse capture - allows text selection",
+            Self::Networks => "networks view",
             Self::Panel => "change panel",
+            Self::Processes => "container processes view",
             Self::Quit => "quit",
             Self::Redraw => "force clear screen and redraw",
             Self::Save => "save logs to file",
@@ -553,14 +800,20 @@ impl KeyDescriptions {
             Self::SortHeader => "sort by header - or click header",
             Self::SortStop => "stop sort",
             Self::SortCpu => "sort by CPU",
+            Self::SortDiskRead => "sort by disk read",
+            Self::SortDiskWrite => "sort by disk write",
+            Self::SortHost => "sort by host",
             Self::SortId => "sort by ID",
             Self::SortImage => "sort by Image",
             Self::SortMem => "sort by memory",
             Self::SortName => "sort by name",
+            Self::SortPids => "sort by PIDs",
             Self::SortRX => "sort by RX",
             Self::SortState => "sort by state",
             Self::SortStatus => "sort by status",
             Self::SortTX => "sort by TX",
+            Self::SortThrottled => "sort by throttled",
+            Self::Volumes => "volumes view",
         }
     }
 }
@@ -896,7 +1149,7 @@ mod tests {
     /// This test is incredibly annoying
     /// println!("{} {} {} {} {}", row_index, result_cell_index, result_cell.symbol(), result_cell.bg, result_cell.fg);
     fn test_draw_blocks_help() {
-        let mut setup = test_setup(118, 25, true, true);
+        let mut setup = test_setup(118, 30, true, true);
         setup.app_data.lock().config.dir_save = Some(PathBuf::from("/test_dir"));
         setup.app_data.lock().config.dir_config =
             Some(PathBuf::from("/home/user/.config/oxker/config.toml"));
@@ -915,9 +1168,9 @@ mod tests {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
                     // The space around the popup
-                    (0|24, _) | (_, 0|117) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Reset, Color::Reset)),
+                    (0|29, _) | (_, 0|117) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Reset, Color::Reset)),
                     // The borders
-                    (1|23, 1..=23) | (_, 1|116) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
+                    (1|28, 1..=23) | (_, 1|116) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
                     // The oxker logo
                     // The description
                     (2..=10, 3..=58)|
@@ -930,9 +1183,9 @@ mod tests {
                     //url
                     (10, 69..=104) |
                     // Left column
-                    (13..=22, 4..=24) |
+                    (13..=27, 4..=24) |
                     // Right Column
-                    (13..=21,59..=69)
+                    (13..=27,59..=69)
                      => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::White)),
                     _ =>  assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
                 };
@@ -942,7 +1195,7 @@ mod tests {
 
     #[test]
     fn test_draw_blocks_help_no_config() {
-        let mut setup = test_setup(116, 25, true, true);
+        let mut setup = test_setup(116, 30, true, true);
         setup.app_data.lock().config.dir_save = Some(PathBuf::from("/test_dir"));
         setup.app_data.lock().config.show_timestamp = true;
 
@@ -959,9 +1212,9 @@ mod tests {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
                     // The space around the popup
-                    (0|24, _) | (_, 0|115) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Reset, Color::Reset)),
+                    (0|29, _) | (_, 0|115) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Reset, Color::Reset)),
                     // The borders
-                    (1|23, 1..=23) | (_, 1|114) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
+                    (1|28, 1..=23) | (_, 1|114) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
                     // The oxker logo
                     // The description
                     (2..=10, 3..=57)|
@@ -972,9 +1225,9 @@ mod tests {
                     //url
                     (10, 67..=102) |
                     // Left column
-                    (13..=22, 3..=23) |
+                    (13..=27, 3..=23) |
                     // Right Column
-                    (13..=21,58..=68)
+                    (13..=27,58..=68)
                      => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::White)),
                     _ =>  assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
             };
@@ -984,7 +1237,7 @@ mod tests {
 
     #[test]
     fn test_draw_blocks_help_no_save() {
-        let mut setup = test_setup(118, 25, true, true);
+        let mut setup = test_setup(118, 30, true, true);
         setup.app_data.lock().config.dir_config =
             Some(PathBuf::from("/home/user/.config/oxker/config.toml"));
         setup.app_data.lock().config.show_timestamp = true;
@@ -1002,9 +1255,9 @@ mod tests {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
                     // The space around the popup
-                    (0|24, _) | (_, 0|117) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Reset, Color::Reset)),
+                    (0|29, _) | (_, 0|117) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Reset, Color::Reset)),
                     // The borders
-                    (1|23, 1..=23) | (_, 1|116) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
+                    (1|28, 1..=23) | (_, 1|116) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
                     // The oxker logo
                     // The description
                     (2..=10, 3..=58)|
@@ -1015,9 +1268,9 @@ mod tests {
                     //url
                     (10, 69..=104) |
                     // Left column
-                    (13..=22, 4..=24) |
+                    (13..=27, 4..=24) |
                     // Right Column
-                    (13..=21,59..=69)
+                    (13..=27,59..=69)
                      => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::White)),
                     _ =>  assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
                 };
@@ -1027,7 +1280,7 @@ mod tests {
 
     #[test]
     fn test_draw_blocks_help_no_timezone() {
-        let mut setup = test_setup(118, 25, true, true);
+        let mut setup = test_setup(118, 30, true, true);
         setup.app_data.lock().config.dir_save = Some(PathBuf::from("/test_dir"));
         setup.app_data.lock().config.dir_config =
             Some(PathBuf::from("/home/user/.config/oxker/config.toml"));
@@ -1045,9 +1298,9 @@ mod tests {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
                     // The space around the popup
-                    (0|24, _) | (_, 0|117) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Reset, Color::Reset)),
+                    (0|29, _) | (_, 0|117) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Reset, Color::Reset)),
                     // The borders
-                    (1|23, 1..=23) | (_, 1|116) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
+                    (1|28, 1..=23) | (_, 1|116) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
                     // The oxker logo
                     // The description
                     (2..=10, 3..=58)|
@@ -1058,9 +1311,9 @@ mod tests {
                     //url
                     (10, 69..=104) |
                     // Left column
-                    (13..=22, 4..=24) |
+                    (13..=27, 4..=24) |
                     // Right Column
-                    (13..=21,59..=69)
+                    (13..=27,59..=69)
                      => assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::White)),
                     _ =>  assert_eq!((result_cell.bg, result_cell.fg), (Color::Magenta, Color::Black)),
                 };
@@ -1070,7 +1323,7 @@ mod tests {
 
     #[test]
     fn test_draw_blocks_help_custom_color() {
-        let mut setup = test_setup(118, 25, true, true);
+        let mut setup = test_setup(118, 30, true, true);
         setup.app_data.lock().config.dir_save = Some(PathBuf::from("/test_dir"));
         setup.app_data.lock().config.dir_config =
             Some(PathBuf::from("/home/user/.config/oxker/config.toml"));
@@ -1096,9 +1349,9 @@ mod tests {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
                     // The space around the popup
-                    (0|24, _) | (_, 0|117) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Reset, Color::Reset)),
+                    (0|29, _) | (_, 0|117) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Reset, Color::Reset)),
                     // The borders
-                    (1|23, 1..=23) | (_, 1|116) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Black, Color::Red)),
+                    (1|28, 1..=23) | (_, 1|116) => assert_eq!((result_cell.bg, result_cell.fg), (Color::Black, Color::Red)),
                     // The oxker logo
                     // The description
                     (2..=10, 3..=58)|
@@ -1111,9 +1364,9 @@ mod tests {
                     //url
                     (10, 69..=104) |
                     // Left column
-                    (13..=22, 4..=24) |
+                    (13..=27, 4..=24) |
                     // Right Column
-                    (13..=21,59..=69)
+                    (13..=27,59..=69)
                      => assert_eq!((result_cell.bg, result_cell.fg), (Color::Black, Color::Yellow)),
                     _ =>  assert_eq!((result_cell.bg, result_cell.fg), (Color::Black, Color::Red)),
                 };
@@ -1124,7 +1377,7 @@ mod tests {
     #[test]
     /// Help panel will show custom keymap if in use, with one definition for each entry
     fn test_draw_blocks_help_custom_keymap_one_definition() {
-        let mut setup = test_setup(118, 25, true, true);
+        let mut setup = test_setup(118, 31, true, true);
 
         setup.app_data.lock().config.dir_save = Some(PathBuf::from("/test_dir"));
         setup.app_data.lock().config.dir_config =
@@ -1135,6 +1388,8 @@ mod tests {
             clear: (KeyCode::Char('a'), None),
             delete_confirm: (KeyCode::Char('b'), None),
             delete_deny: (KeyCode::Char('c'), None),
+            delete_force: (KeyCode::F(11), None),
+            delete_volumes: (KeyCode::F(12), None),
             exec: (KeyCode::Char('d'), None),
             inspect: (KeyCode::Char('e'), None),
             filter_mode: (KeyCode::Char('f'), None),
@@ -1145,6 +1400,21 @@ mod tests {
             log_section_height_decrease: (KeyCode::Char('k'), None),
             log_section_height_increase: (KeyCode::Char('l'), None),
             log_section_toggle: (KeyCode::Char('m'), None),
+            mark: (KeyCode::Insert, None),
+            bulk_action: (KeyCode::F(13), None),
+            group_projects: (KeyCode::F(14), None),
+            images: (KeyCode::F(15), None),
+            prune: (KeyCode::F(16), None),
+            remove: (KeyCode::F(17), None),
+            volumes: (KeyCode::F(18), None),
+            networks: (KeyCode::F(19), None),
+            create: (KeyCode::F(20), None),
+            connect: (KeyCode::F(21), None),
+            disk_usage: (KeyCode::F(22), None),
+            changes: (KeyCode::F(24), None),
+            processes: (KeyCode::F(23), None),
+            copy_from: (KeyCode::F(25), None),
+            copy_to: (KeyCode::F(26), None),
             quit: (KeyCode::Char('n'), None),
             save_logs: (KeyCode::Char('o'), None),
             scroll_down: (KeyCode::Char('p'), None),
@@ -1155,10 +1425,16 @@ mod tests {
             select_next_panel: (KeyCode::Char('t'), None),
             select_previous_panel: (KeyCode::Char('u'), None),
             sort_by_cpu: (KeyCode::Char('v'), None),
+            sort_by_disk_read: (KeyCode::Char('7'), None),
+            sort_by_disk_write: (KeyCode::Char('8'), None),
+            sort_by_host: (KeyCode::Char('9'), None),
+            context_picker: (KeyCode::Char('C'), None),
             sort_by_id: (KeyCode::Char('w'), None),
             sort_by_image: (KeyCode::Char('x'), None),
             sort_by_memory: (KeyCode::Char('y'), None),
             sort_by_name: (KeyCode::Char('z'), None),
+            sort_by_pids: (KeyCode::F(27), None),
+            sort_by_throttled: (KeyCode::F(28), None),
             sort_by_rx: (KeyCode::Char('0'), None),
             sort_by_state: (KeyCode::Char('1'), None),
             sort_by_status: (KeyCode::Char('2'), None),
@@ -1183,7 +1459,7 @@ mod tests {
     #[test]
     /// Help panel will show custom keymap if in use, with two definitions for each entry
     fn test_draw_blocks_help_custom_keymap_two_definition() {
-        let mut setup = test_setup(124, 30, true, true);
+        let mut setup = test_setup(124, 32, true, true);
 
         setup.app_data.lock().config.dir_save = Some(PathBuf::from("/test_dir"));
         setup.app_data.lock().config.dir_config =
@@ -1194,6 +1470,8 @@ mod tests {
             clear: (KeyCode::Char('a'), Some(KeyCode::Char('b'))),
             delete_confirm: (KeyCode::Char('c'), Some(KeyCode::Char('d'))),
             delete_deny: (KeyCode::Char('e'), Some(KeyCode::Char('f'))),
+            delete_force: (KeyCode::F(11), None),
+            delete_volumes: (KeyCode::F(12), None),
             exec: (KeyCode::Char('g'), Some(KeyCode::Char('h'))),
             inspect: (KeyCode::Char('i'), Some(KeyCode::Char('j'))),
             filter_mode: (KeyCode::Char('k'), Some(KeyCode::Char('l'))),
@@ -1204,6 +1482,21 @@ mod tests {
             log_section_height_decrease: (KeyCode::Char('u'), Some(KeyCode::Char('v'))),
             log_section_height_increase: (KeyCode::Char('w'), Some(KeyCode::Char('x'))),
             log_section_toggle: (KeyCode::Char('y'), Some(KeyCode::Char('z'))),
+            mark: (KeyCode::Insert, None),
+            bulk_action: (KeyCode::F(13), None),
+            group_projects: (KeyCode::F(14), None),
+            images: (KeyCode::F(15), None),
+            prune: (KeyCode::F(16), None),
+            remove: (KeyCode::F(17), None),
+            volumes: (KeyCode::F(18), None),
+            networks: (KeyCode::F(19), None),
+            create: (KeyCode::F(20), None),
+            connect: (KeyCode::F(21), None),
+            disk_usage: (KeyCode::F(22), None),
+            changes: (KeyCode::F(24), None),
+            processes: (KeyCode::F(23), None),
+            copy_from: (KeyCode::F(25), None),
+            copy_to: (KeyCode::F(26), None),
             quit: (KeyCode::Char('0'), Some(KeyCode::Char('1'))),
             save_logs: (KeyCode::Char('2'), Some(KeyCode::Char('3'))),
             scroll_down: (KeyCode::Char('4'), Some(KeyCode::Char('5'))),
@@ -1214,10 +1507,16 @@ mod tests {
             select_next_panel: (KeyCode::PrintScreen, Some(KeyCode::Right)),
             select_previous_panel: (KeyCode::Left, Some(KeyCode::Up)),
             sort_by_cpu: (KeyCode::Down, Some(KeyCode::Delete)),
+            sort_by_disk_read: (KeyCode::F(9), Some(KeyCode::F(10))),
+            sort_by_disk_write: (KeyCode::F(11), Some(KeyCode::F(12))),
+            sort_by_host: (KeyCode::Char('A'), Some(KeyCode::Char('B'))),
+            context_picker: (KeyCode::Char('C'), Some(KeyCode::Char('D'))),
             sort_by_id: (KeyCode::BackTab, Some(KeyCode::Backspace)),
             sort_by_image: (KeyCode::End, Some(KeyCode::Esc)),
             sort_by_memory: (KeyCode::Home, Some(KeyCode::Insert)),
             sort_by_name: (KeyCode::KeypadBegin, Some(KeyCode::Menu)),
+            sort_by_pids: (KeyCode::F(27), None),
+            sort_by_throttled: (KeyCode::F(28), None),
             sort_by_rx: (KeyCode::NumLock, Some(KeyCode::PageDown)),
             sort_by_state: (KeyCode::PageUp, Some(KeyCode::Pause)),
             sort_by_status: (KeyCode::PrintScreen, Some(KeyCode::Tab)),
@@ -1242,7 +1541,7 @@ mod tests {
     #[test]
     /// Help panel will show custom keymap if in use, with one or two definitions for each entry
     fn test_draw_blocks_help_custom_keymap_one_two_definition() {
-        let mut setup = test_setup(124, 30, true, true);
+        let mut setup = test_setup(124, 32, true, true);
 
         setup.app_data.lock().config.dir_save = Some(PathBuf::from("/test_dir"));
         setup.app_data.lock().config.dir_config =
@@ -1253,6 +1552,8 @@ mod tests {
             clear: (KeyCode::Char('a'), Some(KeyCode::Char('b'))),
             delete_confirm: (KeyCode::Char('c'), None),
             delete_deny: (KeyCode::Char('e'), Some(KeyCode::Char('f'))),
+            delete_force: (KeyCode::F(11), None),
+            delete_volumes: (KeyCode::F(12), None),
             exec: (KeyCode::Char('g'), None),
             inspect: (KeyCode::Char('i'), Some(KeyCode::Char('j'))),
             filter_mode: (KeyCode::Char('k'), None),
@@ -1263,6 +1564,21 @@ mod tests {
             log_section_height_decrease: (KeyCode::Char('u'), Some(KeyCode::Char('v'))),
             log_section_height_increase: (KeyCode::Char('w'), None),
             log_section_toggle: (KeyCode::Char('y'), Some(KeyCode::Char('z'))),
+            mark: (KeyCode::Insert, None),
+            bulk_action: (KeyCode::F(13), None),
+            group_projects: (KeyCode::F(14), None),
+            images: (KeyCode::F(15), None),
+            prune: (KeyCode::F(16), None),
+            remove: (KeyCode::F(17), None),
+            volumes: (KeyCode::F(18), None),
+            networks: (KeyCode::F(19), None),
+            create: (KeyCode::F(20), None),
+            connect: (KeyCode::F(21), None),
+            disk_usage: (KeyCode::F(22), None),
+            changes: (KeyCode::F(24), None),
+            processes: (KeyCode::F(23), None),
+            copy_from: (KeyCode::F(25), None),
+            copy_to: (KeyCode::F(26), None),
             quit: (KeyCode::Char('0'), None),
             save_logs: (KeyCode::Char('2'), Some(KeyCode::Char('3'))),
             scroll_down: (KeyCode::Char('4'), None),
@@ -1273,10 +1589,16 @@ mod tests {
             select_next_panel: (KeyCode::PrintScreen, None),
             select_previous_panel: (KeyCode::Left, Some(KeyCode::Up)),
             sort_by_cpu: (KeyCode::Down, None),
+            sort_by_disk_read: (KeyCode::F(9), Some(KeyCode::F(10))),
+            sort_by_disk_write: (KeyCode::F(11), None),
+            sort_by_host: (KeyCode::Char('A'), None),
+            context_picker: (KeyCode::Char('C'), None),
             sort_by_id: (KeyCode::BackTab, None),
             sort_by_image: (KeyCode::End, Some(KeyCode::Esc)),
             sort_by_memory: (KeyCode::Home, None),
             sort_by_name: (KeyCode::KeypadBegin, Some(KeyCode::Menu)),
+            sort_by_pids: (KeyCode::F(27), None),
+            sort_by_throttled: (KeyCode::F(28), None),
             sort_by_rx: (KeyCode::NumLock, None),
             sort_by_state: (KeyCode::PageUp, Some(KeyCode::Pause)),
             sort_by_status: (KeyCode::PrintScreen, None),
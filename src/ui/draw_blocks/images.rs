@@ -0,0 +1,255 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+};
+
+use super::{CIRCLE, MARGIN, key_text};
+use crate::{
+    app_data::{AppData, ImageItem},
+    config::{AppColors, Keymap},
+};
+
+/// The widths of each column, calculated from the longest value in each column
+#[derive(Debug, Clone, Copy)]
+struct Widths {
+    name: usize,
+    host: Option<usize>,
+    size: usize,
+    created: usize,
+    containers: usize,
+}
+
+impl Widths {
+    fn new(items: &[(&ImageItem, String)], show_host: bool) -> Self {
+        let max = |header: &str, f: &dyn Fn(&(&ImageItem, String)) -> usize| {
+            items
+                .iter()
+                .map(f)
+                .max()
+                .unwrap_or_default()
+                .max(header.chars().count())
+        };
+        Self {
+            name: max("repository:tag", &|i| i.0.get_name().chars().count()),
+            host: show_host.then(|| max("host", &|i| i.0.host.to_string().chars().count())),
+            size: max("size", &|i| i.0.size.to_string().chars().count()),
+            created: max("created", &|i| i.1.chars().count()),
+            containers: max("containers", &|i| {
+                i.0.containers.to_string().chars().count()
+            }),
+        }
+    }
+}
+
+/// Format a single image, padded to the column widths
+fn format_image<'a>(colors: AppColors, image: &ImageItem, created: String, w: Widths) -> Line<'a> {
+    let mut spans = vec![
+        Span::from(format!(
+            "{:<width$}{MARGIN}",
+            image.get_name(),
+            width = w.name
+        )),
+        Span::from(format!("{:<8}{MARGIN}", image.id.get_short())),
+        Span::from(format!("{:>width$}{MARGIN}", image.size, width = w.size)),
+        Span::from(format!("{created:<width$}{MARGIN}", width = w.created)),
+        Span::from(format!(
+            "{:>width$}",
+            image.containers,
+            width = w.containers
+        )),
+    ];
+    if let Some(width) = w.host {
+        spans.insert(
+            1,
+            Span::from(format!("{:<width$}{MARGIN}", image.host.to_string())),
+        );
+    }
+    Line::from(spans).fg(colors.containers.text)
+}
+
+/// Format the column headers, indented to line up with the list items
+fn format_header<'a>(colors: AppColors, w: Widths) -> Line<'a> {
+    let mut text = format!(
+        "{}{:<name$}{MARGIN}",
+        " ".repeat(Span::from(CIRCLE).width()),
+        "repository:tag",
+        name = w.name
+    );
+    if let Some(width) = w.host {
+        text.push_str(&format!("{:<width$}{MARGIN}", "host"));
+    }
+    text.push_str(&format!(
+        "{:<8}{MARGIN}{:>size$}{MARGIN}{:<created$}{MARGIN}{:>containers$}",
+        "id",
+        "size",
+        "created",
+        "containers",
+        size = w.size,
+        created = w.created,
+        containers = w.containers
+    ));
+    Line::from(text)
+        .fg(colors.containers.text)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Draw the images view to the entire screen
+pub fn draw(app_data: &Arc<Mutex<AppData>>, colors: AppColors, f: &mut Frame, keymap: &Keymap) {
+    let mut app_data = app_data.lock();
+    let tz = app_data.config.timezone.clone();
+    let show_host = app_data.config.hosts.len() > 1;
+    let title = format!(" Images{} ", app_data.get_image_title());
+
+    let items = app_data.get_image_items().to_vec();
+    let items = items
+        .iter()
+        .map(|i| (i, i.get_created(tz.as_ref())))
+        .collect::<Vec<_>>();
+    let widths = Widths::new(&items, show_host);
+
+    let keys = format!(
+        " ( {} ) inspect  ( {} ) remove  ( {} ) prune  ( {} ) close ",
+        key_text(keymap.inspect),
+        key_text(keymap.remove),
+        key_text(keymap.prune),
+        key_text(keymap.images),
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(colors.borders.selected))
+        .title(Line::from(title).left_aligned())
+        .title_bottom(Line::from(keys).right_aligned())
+        .bg(colors.containers.background);
+
+    let area = f.area();
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if items.is_empty() {
+        let paragraph = Paragraph::new("no images")
+            .fg(colors.containers.text)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, inner);
+        return;
+    }
+
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)])
+        .split(inner);
+
+    f.render_widget(Paragraph::new(format_header(colors, widths)), split[0]);
+
+    let list = List::new(
+        items
+            .into_iter()
+            .map(|(image, created)| ListItem::new(format_image(colors, image, created, widths)))
+            .collect::<Vec<_>>(),
+    )
+    .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+    .highlight_symbol(CIRCLE);
+
+    f.render_stateful_widget(list, split[1], app_data.get_image_state());
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::secret::ImageSummary;
+    use insta::assert_snapshot;
+    use ratatui::style::Modifier;
+
+    use crate::{
+        app_data::{ContainerHost, ImageItem},
+        ui::draw_blocks::tests::{TuiTestSetup, get_result, test_setup},
+    };
+
+    /// Insert two images, one tagged and in use, and one dangling
+    fn insert_images(setup: &TuiTestSetup) {
+        let host = ContainerHost::default();
+        let images = [
+            (
+                "sha256:aaaaaaaa11111111",
+                vec!["nginx:latest"],
+                1_700_000_000,
+                2,
+            ),
+            (
+                "sha256:bbbbbbbb22222222",
+                vec!["<none>:<none>"],
+                1_600_000_000,
+                0,
+            ),
+        ]
+        .into_iter()
+        .map(|(id, tags, created, containers)| {
+            ImageItem::new(
+                ImageSummary {
+                    id: id.to_owned(),
+                    repo_tags: tags.into_iter().map(ToOwned::to_owned).collect(),
+                    created,
+                    size: 1_000_000,
+                    ..Default::default()
+                },
+                containers,
+                &host,
+            )
+        })
+        .collect();
+        setup.app_data.lock().update_images(&host, images);
+    }
+
+    #[test]
+    /// Images view is drawn with a header, and the selected image is highlighted
+    fn test_draw_blocks_images() {
+        let mut setup = test_setup(100, 8, true, true);
+        insert_images(&setup);
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if (1..=2).contains(&row_index) && (4..=20).contains(&result_cell_index) {
+                    assert_eq!(result_cell.fg, colors.containers.text);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+                if row_index == 3 && (4..=20).contains(&result_cell_index) {
+                    assert!(!result_cell.modifier.contains(Modifier::BOLD));
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Images view without any images
+    fn test_draw_blocks_images_empty() {
+        let mut setup = test_setup(100, 8, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
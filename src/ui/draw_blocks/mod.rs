@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use crossterm::event::KeyCode;
 use parking_lot::Mutex;
 use ratatui::{
     layout::{Constraint, Rect},
@@ -11,21 +12,34 @@ use crate::config::AppColors;
 
 use super::{FrameData, GuiState, SelectablePanel, Status, gui_state::Region};
 
+pub mod bulk;
+pub mod changes;
 pub mod chart_bandwidth;
 pub mod chart_cpu_mem;
+pub mod chart_disk;
 pub mod commands;
+pub mod confirm;
 pub mod containers;
+pub mod context;
 pub mod delete_confirm;
+pub mod disk_usage;
 pub mod error;
 pub mod filter;
 pub mod headers;
 pub mod help;
+pub mod images;
 pub mod info;
 pub mod inspect;
 pub mod logs;
+pub mod network_picker;
+pub mod networks;
 pub mod popup;
 pub mod ports;
+pub mod processes;
+pub mod prompt;
 pub mod search_logs;
+pub mod signal;
+pub mod volumes;
 
 pub const NAME_TEXT: &str = r#"                         88                              
                          88                              
@@ -54,10 +68,12 @@ pub const VERSION: &str = "0.00.000";
 pub const CONSTRAINT_50_50: [Constraint; 2] =
     [Constraint::Percentage(50), Constraint::Percentage(50)];
 pub const CONSTRAINT_100: [Constraint; 1] = [Constraint::Percentage(100)];
-pub const CONSTRAINT_POPUP: [Constraint; 5] = [
+pub const CONSTRAINT_POPUP: [Constraint; 7] = [
     Constraint::Min(2),
     Constraint::Max(1),
     Constraint::Max(1),
+    Constraint::Max(1),
+    Constraint::Max(1),
     Constraint::Max(3),
     Constraint::Min(1),
 ];
@@ -70,6 +86,14 @@ pub const CONSTRAINT_BUTTONS: [Constraint; 5] = [
     Constraint::Percentage(10),
 ];
 
+/// Display a key, with its secondary definition if set
+pub fn key_text(keys: (KeyCode, Option<KeyCode>)) -> String {
+    keys.1.map_or_else(
+        || keys.0.to_string(),
+        |secondary| format!("{} | {secondary}", keys.0),
+    )
+}
+
 /// From a given &str, return the maximum number of chars on a single line
 pub fn max_line_width(text: &str) -> usize {
     text.lines()
@@ -129,6 +153,7 @@ fn generate_block<'a>(
 pub mod tests {
 
     use std::{
+        collections::BTreeMap,
         net::{IpAddr, Ipv4Addr},
         sync::Arc,
     };
@@ -138,7 +163,9 @@ pub mod tests {
     use ratatui::{Terminal, backend::TestBackend, layout::Rect, style::Color};
 
     use crate::{
-        app_data::{AppData, ContainerId, ContainerImage, ContainerName, ContainerPorts},
+        app_data::{
+            AppData, ContainerId, ContainerImage, ContainerName, ContainerPorts, CpuThrottling,
+        },
         app_error::AppError,
         tests::{gen_appdata, gen_containers},
         ui::{GuiState, Rerender, Status, draw_frame},
@@ -158,6 +185,8 @@ pub mod tests {
     pub const BORDER_CHARS: [&str; 6] = ["╭", "╮", "─", "│", "╰", "╯"];
     pub const COLOR_RX: Color = Color::Rgb(255, 233, 193);
     pub const COLOR_TX: Color = Color::Rgb(205, 140, 140);
+    pub const COLOR_DISK_READ: Color = Color::Rgb(193, 225, 255);
+    pub const COLOR_DISK_WRITE: Color = Color::Rgb(190, 160, 220);
     pub const COLOR_ORANGE: Color = Color::Rgb(255, 178, 36);
 
     /// Create a FrameData struct from two Arc<mutex>'s, instead of from UI
@@ -167,11 +196,17 @@ pub mod tests {
 
             let (filter_by, filter_term) = app_data.get_filter();
             Self {
+                bulk_commands: gui_data.get_bulk_commands(),
+                bulk_containers: gui_data.get_bulk_containers(),
                 chart_data: app_data.get_chart_data(),
                 color_logs: app_data.config.color_logs,
                 columns: app_data.get_width(),
+                confirm: gui_data.get_confirm(),
                 container_title: app_data.get_container_title(),
-                delete_confirm: gui_data.get_delete_container(),
+                context: app_data.config.context.clone(),
+                contexts: gui_data.get_contexts(),
+                delete_confirm: gui_data.get_delete_containers(),
+                disconnected: app_data.get_disconnected(),
                 filter_by,
                 filter_term: filter_term.cloned(),
                 has_containers: app_data.get_container_len() > 0,
@@ -179,14 +214,19 @@ pub mod tests {
                 show_logs: gui_data.get_show_logs(),
                 info_text: gui_data.info_box_text.clone(),
                 is_loading: gui_data.is_loading(),
+                kill_containers: gui_data.get_kill_containers(),
+                kill_process: gui_data.get_kill_process(),
                 log_search: app_data.gen_log_search(),
                 loading_icon: gui_data.get_loading().to_string(),
                 log_height: gui_data.get_log_height(),
                 log_title: app_data.get_log_title(),
+                network_containers: gui_data.get_network_containers(),
                 scroll_title: app_data.get_scroll_title(gui_data.get_screen_width()),
                 port_max_lens: app_data.get_longest_port(),
                 ports: app_data.get_selected_ports(),
+                prompt: gui_data.get_prompt(),
                 selected_panel: gui_data.get_selected_panel(),
+                signals: gui_data.get_signals(),
                 sorted_by: app_data.get_sorted(),
                 status: gui_data.get_status(),
             }
@@ -253,8 +293,10 @@ pub mod tests {
                 Some(i as f64),
                 Some(i * 10000),
                 i * 10000,
-                i,
-                i,
+                BTreeMap::from([("eth0".to_owned(), (i, i))]),
+                (i, i),
+                None,
+                CpuThrottling::default(),
             );
         }
         for i in 1..=3 {
@@ -263,8 +305,10 @@ pub mod tests {
                 Some(i as f64),
                 Some(i * 10000),
                 i * 10000,
-                i,
-                i,
+                BTreeMap::from([("eth0".to_owned(), (i, i))]),
+                (i, i),
+                None,
+                CpuThrottling::default(),
             );
         }
     }
@@ -510,10 +554,14 @@ pub mod tests {
             });
         let colors = setup.app_data.lock().config.app_colors;
         let keymap = setup.app_data.lock().config.keymap.clone();
-        setup
-            .gui_state
-            .lock()
-            .set_delete_container(setup.app_data.lock().get_selected_container_id());
+        setup.gui_state.lock().set_delete_containers(
+            setup
+                .app_data
+                .lock()
+                .get_selected_container_id()
+                .into_iter()
+                .collect(),
+        );
 
         let fd = FrameData::from((&setup.app_data, &setup.gui_state));
         setup
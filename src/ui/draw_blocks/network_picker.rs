@@ -0,0 +1,115 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Modifier, Style},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem},
+};
+
+use super::{SELECT_ARROW, popup};
+use crate::{
+    config::AppColors,
+    ui::{FrameData, gui_state::BoxLocation},
+};
+
+/// Draw the network container picker in the centre of the screen, containers attached to the network are marked with a tick
+pub fn draw(
+    colors: AppColors,
+    f: &mut Frame,
+    fd: &FrameData,
+    network: &str,
+    items: &[(String, bool)],
+) {
+    let items = items
+        .iter()
+        .map(|(name, attached)| {
+            if *attached {
+                format!("{name} ✓")
+            } else {
+                name.to_owned()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let title = format!(" Connect|Disconnect {network} ");
+    let max_line_width = items
+        .iter()
+        .map(|i| i.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(title.chars().count())
+        + SELECT_ARROW.chars().count()
+        + 4;
+    let lines = items.len() + 2;
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(colors.popup_help.background)
+                .fg(colors.popup_help.text),
+        );
+
+    let list = List::new(items.into_iter().map(ListItem::new).collect::<Vec<_>>())
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .fg(colors.popup_help.text_highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(SELECT_ARROW);
+
+    let area = popup::draw(lines, max_line_width, f.area(), BoxLocation::MiddleCentre);
+    let mut state = fd.network_containers.state;
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Modifier;
+
+    use crate::ui::{
+        FrameData,
+        draw_blocks::tests::{get_result, test_setup},
+    };
+
+    #[test]
+    /// Network container picker is drawn in the centre of the screen, with the attached containers marked, and the first container selected
+    fn test_draw_blocks_network_picker() {
+        let mut setup = test_setup(44, 9, true, true);
+        let ids = setup
+            .app_data
+            .lock()
+            .get_host_container_ids(&setup.ids[0].host().clone());
+        setup.gui_state.lock().set_network_containers(ids);
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+        let items = [
+            ("container_1".to_owned(), true),
+            ("container_2".to_owned(), false),
+            ("container_3".to_owned(), false),
+        ];
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(colors, f, &fd, "oxker_network", &items);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if row_index == 3 && (3..=40).contains(&result_cell_index) {
+                    assert_eq!(result_cell.fg, colors.popup_help.text_highlight);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+    }
+}
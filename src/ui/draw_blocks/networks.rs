@@ -0,0 +1,358 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+};
+
+use super::{CIRCLE, MARGIN, key_text};
+use crate::{
+    app_data::{AppData, NetworkContainer, NetworkItem},
+    config::{AppColors, Keymap},
+};
+
+/// Displayed when a network, or an attached container, doesn't have a given value
+const UNKNOWN: &str = "-";
+
+/// Replace an empty value with UNKNOWN
+fn or_unknown(value: &str) -> &str {
+    if value.is_empty() { UNKNOWN } else { value }
+}
+
+/// The widths of each network column, calculated from the longest value in each column
+#[derive(Debug, Clone, Copy)]
+struct Widths {
+    name: usize,
+    host: Option<usize>,
+    driver: usize,
+    subnet: usize,
+}
+
+impl Widths {
+    fn new(items: &[NetworkItem], show_host: bool) -> Self {
+        let max = |header: &str, f: &dyn Fn(&NetworkItem) -> usize| {
+            items
+                .iter()
+                .map(f)
+                .max()
+                .unwrap_or_default()
+                .max(header.chars().count())
+        };
+        Self {
+            name: max("name", &|i| i.name.chars().count()),
+            host: show_host.then(|| max("host", &|i| i.host.to_string().chars().count())),
+            driver: max("driver", &|i| i.driver.chars().count()),
+            subnet: max("subnet", &|i| or_unknown(&i.subnet).chars().count()),
+        }
+    }
+}
+
+/// Format a single network, padded to the column widths, networks without any attached containers are dimmed
+fn format_network<'a>(colors: AppColors, network: &NetworkItem, w: Widths) -> Line<'a> {
+    let mut spans = vec![
+        Span::from(format!("{:<width$}{MARGIN}", network.name, width = w.name)),
+        Span::from(format!(
+            "{:<width$}{MARGIN}",
+            network.driver,
+            width = w.driver
+        )),
+        Span::from(format!(
+            "{:<width$}{MARGIN}",
+            or_unknown(&network.subnet),
+            width = w.subnet
+        )),
+        Span::from(or_unknown(&network.gateway).to_owned()),
+    ];
+    if let Some(width) = w.host {
+        spans.insert(
+            1,
+            Span::from(format!("{:<width$}{MARGIN}", network.host.to_string())),
+        );
+    }
+    let color = if network.containers.is_empty() {
+        colors.containers.text_disconnected
+    } else {
+        colors.containers.text
+    };
+    Line::from(spans).fg(color)
+}
+
+/// Format the network column headers, indented to line up with the list items
+fn format_header<'a>(colors: AppColors, w: Widths) -> Line<'a> {
+    let mut text = format!(
+        "{}{:<name$}{MARGIN}",
+        " ".repeat(Span::from(CIRCLE).width()),
+        "name",
+        name = w.name
+    );
+    if let Some(width) = w.host {
+        text.push_str(&format!("{:<width$}{MARGIN}", "host"));
+    }
+    text.push_str(&format!(
+        "{:<driver$}{MARGIN}{:<subnet$}{MARGIN}gateway",
+        "driver",
+        "subnet",
+        driver = w.driver,
+        subnet = w.subnet
+    ));
+    Line::from(text)
+        .fg(colors.containers.text)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Draw the containers attached to the selected network, with their addresses and aliases
+fn draw_attached(area: Rect, colors: AppColors, f: &mut Frame, network: Option<&NetworkItem>) {
+    let title = network.map_or_else(String::new, |i| format!(" Attached to {} ", i.name));
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(colors.borders.unselected))
+        .title(title);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let containers = network.map(|i| i.containers.as_slice()).unwrap_or_default();
+    if containers.is_empty() {
+        let paragraph = Paragraph::new("no attached containers")
+            .fg(colors.containers.text)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, inner);
+        return;
+    }
+
+    let text =
+        |f: &dyn Fn(&NetworkContainer) -> String| containers.iter().map(f).collect::<Vec<_>>();
+    let columns = [
+        ("name", text(&|i| i.name.clone())),
+        (
+            "ipv4",
+            text(&|i| i.ipv4.as_deref().unwrap_or(UNKNOWN).to_owned()),
+        ),
+        (
+            "ipv6",
+            text(&|i| i.ipv6.as_deref().unwrap_or(UNKNOWN).to_owned()),
+        ),
+        (
+            "aliases",
+            text(&|i| or_unknown(&i.aliases.join(", ")).to_owned()),
+        ),
+    ];
+    let widths = columns.each_ref().map(|(header, values)| {
+        values
+            .iter()
+            .map(|i| i.chars().count())
+            .max()
+            .unwrap_or_default()
+            .max(header.chars().count())
+    });
+
+    let format_row = |values: [&str; 4]| {
+        values
+            .iter()
+            .zip(widths)
+            .map(|(value, width)| format!("{value:<width$}"))
+            .collect::<Vec<_>>()
+            .join(MARGIN)
+    };
+
+    let mut lines = vec![
+        Line::from(format_row(columns.each_ref().map(|i| i.0)))
+            .fg(colors.containers.text)
+            .add_modifier(Modifier::BOLD),
+    ];
+    lines.extend((0..containers.len()).map(|index| {
+        Line::from(format_row(
+            columns
+                .each_ref()
+                .map(|i| i.1.get(index).map_or("", String::as_str)),
+        ))
+        .fg(colors.containers.text)
+    }));
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Draw the networks view to the entire screen
+pub fn draw(app_data: &Arc<Mutex<AppData>>, colors: AppColors, f: &mut Frame, keymap: &Keymap) {
+    let mut app_data = app_data.lock();
+    let show_host = app_data.config.hosts.len() > 1;
+    let title = format!(" Networks{} ", app_data.get_network_title());
+
+    let items = app_data.get_network_items().to_vec();
+    let selected = app_data.get_selected_network().cloned();
+    let widths = Widths::new(&items, show_host);
+
+    let keys = format!(
+        " ( {} ) create  ( {} ) remove  ( {} ) prune  ( {} ) connect|disconnect  ( {} ) close ",
+        key_text(keymap.create),
+        key_text(keymap.remove),
+        key_text(keymap.prune),
+        key_text(keymap.connect),
+        key_text(keymap.networks),
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(colors.borders.selected))
+        .title(Line::from(title).left_aligned())
+        .title_bottom(Line::from(keys).right_aligned())
+        .bg(colors.containers.background);
+
+    let area = f.area();
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if items.is_empty() {
+        let paragraph = Paragraph::new("no networks")
+            .fg(colors.containers.text)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, inner);
+        return;
+    }
+
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Max(1),
+            Constraint::Min(1),
+            Constraint::Percentage(50),
+        ])
+        .split(inner);
+
+    f.render_widget(Paragraph::new(format_header(colors, widths)), split[0]);
+
+    let list = List::new(
+        items
+            .iter()
+            .map(|network| ListItem::new(format_network(colors, network, widths)))
+            .collect::<Vec<_>>(),
+    )
+    .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+    .highlight_symbol(CIRCLE);
+
+    f.render_stateful_widget(list, split[1], app_data.get_network_state());
+    draw_attached(split[2], colors, f, selected.as_ref());
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::secret::{ContainerSummary, EndpointSettings, Ipam, IpamConfig, Network};
+    use insta::assert_snapshot;
+    use ratatui::style::Modifier;
+
+    use crate::{
+        app_data::{ContainerHost, NetworkContainer, NetworkItem},
+        ui::draw_blocks::tests::{TuiTestSetup, get_result, test_setup},
+    };
+
+    /// Insert two networks, one with two attached containers, and one without any containers
+    fn insert_networks(setup: &TuiTestSetup) {
+        let host = ContainerHost::default();
+        let container = |id: &str, ip: &str, aliases: Vec<String>| {
+            NetworkContainer::new(
+                &ContainerSummary {
+                    id: Some(id.to_owned()),
+                    names: Some(vec![format!("/container_{id}")]),
+                    ..Default::default()
+                },
+                &EndpointSettings {
+                    ip_address: Some(ip.to_owned()),
+                    aliases: Some(aliases),
+                    ..Default::default()
+                },
+                &host,
+            )
+        };
+        let networks = vec![
+            NetworkItem::new(
+                Network {
+                    name: Some("backend".to_owned()),
+                    id: Some("1".to_owned()),
+                    driver: Some("bridge".to_owned()),
+                    ipam: Some(Ipam {
+                        config: Some(vec![IpamConfig {
+                            subnet: Some("172.18.0.0/16".to_owned()),
+                            gateway: Some("172.18.0.1".to_owned()),
+                            ..Default::default()
+                        }]),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                vec![
+                    container("1", "172.18.0.2", vec!["api".to_owned()]),
+                    container("2", "172.18.0.3", vec![]),
+                ],
+                &host,
+            ),
+            NetworkItem::new(
+                Network {
+                    name: Some("none".to_owned()),
+                    id: Some("2".to_owned()),
+                    driver: Some("null".to_owned()),
+                    ..Default::default()
+                },
+                vec![],
+                &host,
+            ),
+        ];
+        setup.app_data.lock().update_networks(&host, networks);
+    }
+
+    #[test]
+    /// Networks view is drawn with a header, the selected network is highlighted, and its attached containers are listed
+    fn test_draw_blocks_networks() {
+        let mut setup = test_setup(100, 14, true, true);
+        insert_networks(&setup);
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if (4..=10).contains(&result_cell_index) {
+                    match row_index {
+                        1 | 2 => {
+                            assert_eq!(result_cell.fg, colors.containers.text);
+                            assert_eq!(result_cell.modifier, Modifier::BOLD);
+                        }
+                        3 => {
+                            assert_eq!(result_cell.fg, colors.containers.text_disconnected);
+                            assert!(!result_cell.modifier.contains(Modifier::BOLD));
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Networks view without any networks
+    fn test_draw_blocks_networks_empty() {
+        let mut setup = test_setup(100, 8, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
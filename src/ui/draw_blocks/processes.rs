@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+};
+
+use super::{CIRCLE, MARGIN, key_text};
+use crate::{
+    app_data::{AppData, ProcessHeader, ProcessItem, SortedOrder},
+    config::{AppColors, Keymap},
+};
+
+/// The text of each column, for a single process
+struct Row {
+    pid: String,
+    user: String,
+    cpu: String,
+    memory: String,
+    command: String,
+}
+
+impl Row {
+    fn new(process: &ProcessItem) -> Self {
+        Self {
+            pid: process.pid.to_string(),
+            user: process.user.clone(),
+            cpu: format!("{:.1}%", process.cpu),
+            memory: format!("{:.1}%", process.memory),
+            command: process.command.clone(),
+        }
+    }
+}
+
+/// The header text, with an arrow if the processes are currently sorted by that header
+fn header_text(header: ProcessHeader, sorted_by: Option<(ProcessHeader, SortedOrder)>) -> String {
+    let text = match header {
+        ProcessHeader::Pid => "pid",
+        ProcessHeader::User => "host user",
+        ProcessHeader::Cpu => "cpu",
+        ProcessHeader::Memory => "mem",
+        ProcessHeader::Command => "command",
+    };
+    match sorted_by {
+        Some((h, SortedOrder::Asc)) if h == header => format!("{text} ▲"),
+        Some((h, SortedOrder::Desc)) if h == header => format!("{text} ▼"),
+        _ => text.to_owned(),
+    }
+}
+
+/// The widths of each column, calculated from the longest value in each column, the command column is never padded
+#[derive(Debug, Clone, Copy)]
+struct Widths {
+    pid: usize,
+    user: usize,
+    cpu: usize,
+    memory: usize,
+}
+
+impl Widths {
+    fn new(rows: &[Row], sorted_by: Option<(ProcessHeader, SortedOrder)>) -> Self {
+        let max = |header: ProcessHeader, f: &dyn Fn(&Row) -> &str| {
+            rows.iter()
+                .map(|i| f(i).chars().count())
+                .max()
+                .unwrap_or_default()
+                .max(header_text(header, sorted_by).chars().count())
+        };
+        Self {
+            pid: max(ProcessHeader::Pid, &|i| &i.pid),
+            user: max(ProcessHeader::User, &|i| &i.user),
+            cpu: max(ProcessHeader::Cpu, &|i| &i.cpu),
+            memory: max(ProcessHeader::Memory, &|i| &i.memory),
+        }
+    }
+}
+
+/// Format a single process, padded to the column widths
+fn format_process<'a>(colors: AppColors, row: Row, w: Widths) -> Line<'a> {
+    Line::from(format!(
+        "{:>pid$}{MARGIN}{:<user$}{MARGIN}{:>cpu$}{MARGIN}{:>memory$}{MARGIN}{}",
+        row.pid,
+        row.user,
+        row.cpu,
+        row.memory,
+        row.command,
+        pid = w.pid,
+        user = w.user,
+        cpu = w.cpu,
+        memory = w.memory
+    ))
+    .fg(colors.containers.text)
+}
+
+/// Format the column headers, indented to line up with the list items
+fn format_header<'a>(
+    colors: AppColors,
+    w: Widths,
+    sorted_by: Option<(ProcessHeader, SortedOrder)>,
+) -> Line<'a> {
+    let text = |header| header_text(header, sorted_by);
+    Line::from(format!(
+        "{}{:>pid$}{MARGIN}{:<user$}{MARGIN}{:>cpu$}{MARGIN}{:>memory$}{MARGIN}{}",
+        " ".repeat(Span::from(CIRCLE).width()),
+        text(ProcessHeader::Pid),
+        text(ProcessHeader::User),
+        text(ProcessHeader::Cpu),
+        text(ProcessHeader::Memory),
+        text(ProcessHeader::Command),
+        pid = w.pid,
+        user = w.user,
+        cpu = w.cpu,
+        memory = w.memory
+    ))
+    .fg(colors.containers.text)
+    .add_modifier(Modifier::BOLD)
+}
+
+/// Draw the processes view, for the selected container, to the entire screen
+pub fn draw(app_data: &Arc<Mutex<AppData>>, colors: AppColors, f: &mut Frame, keymap: &Keymap) {
+    let mut app_data = app_data.lock();
+    let name = app_data
+        .get_processes_container()
+        .cloned()
+        .and_then(|id| app_data.get_container_name_by_id(&id).cloned())
+        .map_or_else(String::new, |i| format!(" {}", i.get()));
+    let title = format!(" Processes{name}{} ", app_data.get_process_title());
+
+    let sorted_by = app_data.get_process_sorted();
+    let rows = app_data
+        .get_process_items()
+        .iter()
+        .map(Row::new)
+        .collect::<Vec<_>>();
+    let widths = Widths::new(&rows, sorted_by);
+
+    let keys = format!(
+        " ( enter ) signal  ( {} ) close ",
+        key_text(keymap.processes)
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(colors.borders.selected))
+        .title(Line::from(title).left_aligned())
+        .title_bottom(Line::from(keys).right_aligned())
+        .bg(colors.containers.background);
+
+    let area = f.area();
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if rows.is_empty() {
+        let paragraph = Paragraph::new("no processes")
+            .fg(colors.containers.text)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, inner);
+        return;
+    }
+
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)])
+        .split(inner);
+
+    f.render_widget(
+        Paragraph::new(format_header(colors, widths, sorted_by)),
+        split[0],
+    );
+
+    let list = List::new(
+        rows.into_iter()
+            .map(|row| ListItem::new(format_process(colors, row, widths)))
+            .collect::<Vec<_>>(),
+    )
+    .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+    .highlight_symbol(CIRCLE);
+
+    f.render_stateful_widget(list, split[1], app_data.get_process_state());
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Modifier;
+
+    use crate::{
+        app_data::{ContainerId, ProcessHeader, ProcessItem, SortedOrder},
+        ui::draw_blocks::tests::{TuiTestSetup, get_result, test_setup},
+    };
+
+    /// Show the processes view for the first container, with three processes
+    fn insert_processes(setup: &TuiTestSetup) {
+        let id = ContainerId::from("1");
+        let process = |pid: u64, cpu: f64, command: &str| ProcessItem {
+            command: command.to_owned(),
+            cpu,
+            memory: 0.2,
+            pid,
+            user: "root".to_owned(),
+        };
+        let mut app_data = setup.app_data.lock();
+        app_data.set_processes_container(Some(id.clone()));
+        app_data.update_processes(
+            &id,
+            vec![
+                process(1100, 0.0, "nginx: master process nginx"),
+                process(1202, 12.5, "nginx: worker process"),
+                process(40, 1.0, "sh"),
+            ],
+        );
+        app_data.processes_start();
+    }
+
+    #[test]
+    /// Processes view is drawn with the container name in the title, a header, and the selected process highlighted
+    fn test_draw_blocks_processes() {
+        let mut setup = test_setup(80, 8, true, true);
+        insert_processes(&setup);
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if (4..=10).contains(&result_cell_index) {
+                    match row_index {
+                        1 | 2 => {
+                            assert_eq!(result_cell.fg, colors.containers.text);
+                            assert_eq!(result_cell.modifier, Modifier::BOLD);
+                        }
+                        3 => assert!(!result_cell.modifier.contains(Modifier::BOLD)),
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// The sorted header has an arrow to show the sort order
+    fn test_draw_blocks_processes_sorted() {
+        let mut setup = test_setup(80, 8, true, true);
+        insert_processes(&setup);
+        setup
+            .app_data
+            .lock()
+            .set_process_sorted(Some((ProcessHeader::Cpu, SortedOrder::Desc)));
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// Processes view before any processes have been received
+    fn test_draw_blocks_processes_empty() {
+        let mut setup = test_setup(80, 6, true, true);
+        setup
+            .app_data
+            .lock()
+            .set_processes_container(Some(ContainerId::from("1")));
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
@@ -0,0 +1,152 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::popup;
+use crate::{
+    config::AppColors,
+    ui::{Prompt, gui_state::BoxLocation},
+};
+
+/// The minimum width of the prompt, so that there's room to type into
+const MIN_WIDTH: usize = 48;
+
+/// Draw a single line text prompt in the centre of the screen, with a block cursor at the end of the text
+pub fn draw(colors: AppColors, f: &mut Frame, prompt: &Prompt) {
+    let (title, label) = prompt.action.get_text();
+    let style = Style::default()
+        .bg(colors.popup_help.background)
+        .fg(colors.popup_help.text);
+
+    let block = Block::default()
+        .title(title)
+        .border_type(BorderType::Rounded)
+        .style(style)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let input = Line::from(vec![
+        Span::from(label),
+        Span::styled(
+            format!("{}█", prompt.text),
+            Style::default()
+                .fg(colors.popup_help.text_highlight)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    let keys = Line::from("( Enter ) confirm  ( Esc ) cancel");
+    let hint = prompt.action.get_hint().map(Line::from);
+
+    let max_line_width = input
+        .width()
+        .max(keys.width())
+        .max(hint.as_ref().map_or(0, Line::width))
+        .max(MIN_WIDTH)
+        + 8;
+    let mut lines = vec![Line::default(), input, Line::default()];
+    if let Some(hint) = hint {
+        lines.extend([hint, Line::default()]);
+    }
+    lines.push(keys);
+    let area = popup::draw(
+        lines.len() + 2,
+        max_line_width,
+        f.area(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center);
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Modifier;
+
+    use crate::{
+        app_data::{ContainerHost, ContainerId},
+        ui::{
+            Prompt, PromptAction,
+            draw_blocks::tests::{get_result, test_setup},
+        },
+    };
+
+    #[test]
+    /// Create network prompt is drawn with the entered text highlighted
+    fn test_draw_blocks_prompt_create_network() {
+        let mut setup = test_setup(70, 10, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+        let prompt = Prompt {
+            action: PromptAction::CreateNetwork(ContainerHost::default()),
+            text: "backend".to_owned(),
+        };
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(colors, f, &prompt);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if row_index == 4 && (34..=41).contains(&result_cell_index) {
+                    assert_eq!(result_cell.fg, colors.popup_help.text_highlight);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+    }
+    #[test]
+    /// The copy to container host path prompt explains how the path is resolved
+    fn test_draw_blocks_prompt_copy_to_container_host_path() {
+        let mut setup = test_setup(90, 10, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+        let prompt = Prompt {
+            action: PromptAction::CopyToContainer(ContainerId::from("1")),
+            text: "~/report.html".to_owned(),
+        };
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(colors, f, &prompt);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// The copy to container prompt widens to fit a long path
+    fn test_draw_blocks_prompt_copy_to_container() {
+        let mut setup = test_setup(80, 10, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+        let prompt = Prompt {
+            action: PromptAction::CopyToContainerDir((
+                ContainerId::from("1"),
+                "/home/user/report.html".into(),
+            )),
+            text: "/usr/share/nginx/html/reports".to_owned(),
+        };
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(colors, f, &prompt);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
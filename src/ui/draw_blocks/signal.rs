@@ -0,0 +1,107 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Modifier, Style},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem},
+};
+
+use super::{SELECT_ARROW, popup};
+use crate::{
+    app_data::ContainerName,
+    config::AppColors,
+    ui::{FrameData, gui_state::BoxLocation},
+};
+
+/// Draw the signal picker in the centre of the screen, the selected signal will be sent to the container(s) with the kill command, or to a single process in a container
+pub fn draw(colors: AppColors, f: &mut Frame, fd: &FrameData, names: &[ContainerName]) {
+    let items = fd
+        .signals
+        .items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    let title = match (fd.kill_process.as_ref(), names) {
+        (Some((_, process)), [name]) => format!(" Kill pid {} in {} ", process.pid, name.get()),
+        (_, [name]) => format!(" Kill {} ", name.get()),
+        _ => format!(" Kill {} containers ", names.len()),
+    };
+    let max_line_width = items
+        .iter()
+        .map(|i| i.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(title.chars().count())
+        + SELECT_ARROW.chars().count()
+        + 4;
+    let lines = items.len() + 2;
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .border_type(BorderType::Rounded)
+        .borders(Borders::ALL)
+        .style(
+            Style::default()
+                .bg(colors.popup_help.background)
+                .fg(colors.popup_help.text),
+        );
+
+    let list = List::new(items.into_iter().map(ListItem::new).collect::<Vec<_>>())
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .fg(colors.popup_help.text_highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(SELECT_ARROW);
+
+    let area = popup::draw(lines, max_line_width, f.area(), BoxLocation::MiddleCentre);
+    let mut state = fd.signals.state;
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Modifier;
+
+    use crate::{
+        app_data::{ContainerId, ContainerName},
+        ui::{
+            FrameData,
+            draw_blocks::tests::{get_result, test_setup},
+        },
+    };
+
+    #[test]
+    /// Signal picker is drawn in the centre of the screen, with the container name in the title, and the first signal selected
+    fn test_draw_blocks_signal_picker() {
+        let mut setup = test_setup(40, 12, true, true);
+        setup
+            .gui_state
+            .lock()
+            .set_kill_containers(vec![ContainerId::from("1")]);
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(colors, f, &fd, &[ContainerName::from("container_1")]);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if row_index == 3 && (11..=28).contains(&result_cell_index) {
+                    assert_eq!(result_cell.fg, colors.popup_help.text_highlight);
+                    assert_eq!(result_cell.modifier, Modifier::BOLD);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+};
+
+use super::{CIRCLE, MARGIN, key_text};
+use crate::{
+    app_data::{AppData, VolumeItem},
+    config::{AppColors, Keymap},
+};
+
+/// Displayed when the size of a volume isn't known, or the volume isn't referenced by any container
+const UNKNOWN: &str = "-";
+
+/// The text of each column, for a single volume
+struct Row<'a> {
+    volume: &'a VolumeItem,
+    size: String,
+    containers: String,
+}
+
+impl<'a> Row<'a> {
+    fn new(volume: &'a VolumeItem) -> Self {
+        let containers = volume.get_containers();
+        Self {
+            volume,
+            size: volume
+                .size
+                .map_or_else(|| UNKNOWN.to_owned(), |i| i.to_string()),
+            containers: if containers.is_empty() {
+                UNKNOWN.to_owned()
+            } else {
+                containers
+            },
+        }
+    }
+}
+
+/// The widths of each column, calculated from the longest value in each column
+#[derive(Debug, Clone, Copy)]
+struct Widths {
+    name: usize,
+    host: Option<usize>,
+    driver: usize,
+    size: usize,
+    containers: usize,
+}
+
+impl Widths {
+    fn new(rows: &[Row], show_host: bool) -> Self {
+        let max = |header: &str, f: &dyn Fn(&Row) -> usize| {
+            rows.iter()
+                .map(f)
+                .max()
+                .unwrap_or_default()
+                .max(header.chars().count())
+        };
+        Self {
+            name: max("name", &|i| i.volume.get_name().chars().count()),
+            host: show_host.then(|| max("host", &|i| i.volume.host.to_string().chars().count())),
+            driver: max("driver", &|i| i.volume.driver.chars().count()),
+            size: max("size", &|i| i.size.chars().count()),
+            containers: max("containers", &|i| i.containers.chars().count()),
+        }
+    }
+}
+
+/// Format a single volume, padded to the column widths, volumes not referenced by any container are dimmed
+fn format_volume<'a>(colors: AppColors, row: Row, w: Widths) -> Line<'a> {
+    let mut spans = vec![
+        Span::from(format!(
+            "{:<width$}{MARGIN}",
+            row.volume.get_name(),
+            width = w.name
+        )),
+        Span::from(format!(
+            "{:<width$}{MARGIN}",
+            row.volume.driver,
+            width = w.driver
+        )),
+        Span::from(format!("{:>width$}{MARGIN}", row.size, width = w.size)),
+        Span::from(format!(
+            "{:<width$}{MARGIN}",
+            row.containers,
+            width = w.containers
+        )),
+        Span::from(row.volume.mountpoint.clone()),
+    ];
+    if let Some(width) = w.host {
+        spans.insert(
+            1,
+            Span::from(format!("{:<width$}{MARGIN}", row.volume.host.to_string())),
+        );
+    }
+    let color = if row.volume.containers.is_empty() {
+        colors.containers.text_disconnected
+    } else {
+        colors.containers.text
+    };
+    Line::from(spans).fg(color)
+}
+
+/// Format the column headers, indented to line up with the list items
+fn format_header<'a>(colors: AppColors, w: Widths) -> Line<'a> {
+    let mut text = format!(
+        "{}{:<name$}{MARGIN}",
+        " ".repeat(Span::from(CIRCLE).width()),
+        "name",
+        name = w.name
+    );
+    if let Some(width) = w.host {
+        text.push_str(&format!("{:<width$}{MARGIN}", "host"));
+    }
+    text.push_str(&format!(
+        "{:<driver$}{MARGIN}{:>size$}{MARGIN}{:<containers$}{MARGIN}mountpoint",
+        "driver",
+        "size",
+        "containers",
+        driver = w.driver,
+        size = w.size,
+        containers = w.containers
+    ));
+    Line::from(text)
+        .fg(colors.containers.text)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Draw the volumes view to the entire screen
+pub fn draw(app_data: &Arc<Mutex<AppData>>, colors: AppColors, f: &mut Frame, keymap: &Keymap) {
+    let mut app_data = app_data.lock();
+    let show_host = app_data.config.hosts.len() > 1;
+    let title = format!(" Volumes{} ", app_data.get_volume_title());
+
+    let items = app_data.get_volume_items().to_vec();
+    let rows = items.iter().map(Row::new).collect::<Vec<_>>();
+    let widths = Widths::new(&rows, show_host);
+
+    let keys = format!(
+        " ( {} ) remove  ( {} ) prune  ( {} ) close ",
+        key_text(keymap.remove),
+        key_text(keymap.prune),
+        key_text(keymap.volumes),
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(colors.borders.selected))
+        .title(Line::from(title).left_aligned())
+        .title_bottom(Line::from(keys).right_aligned())
+        .bg(colors.containers.background);
+
+    let area = f.area();
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if rows.is_empty() {
+        let paragraph = Paragraph::new("no volumes")
+            .fg(colors.containers.text)
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, inner);
+        return;
+    }
+
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1), Constraint::Min(1)])
+        .split(inner);
+
+    f.render_widget(Paragraph::new(format_header(colors, widths)), split[0]);
+
+    let list = List::new(
+        rows.into_iter()
+            .map(|row| ListItem::new(format_volume(colors, row, widths)))
+            .collect::<Vec<_>>(),
+    )
+    .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+    .highlight_symbol(CIRCLE);
+
+    f.render_stateful_widget(list, split[1], app_data.get_volume_state());
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::secret::Volume;
+    use insta::assert_snapshot;
+    use ratatui::style::Modifier;
+
+    use crate::{
+        app_data::{ByteStats, ContainerHost, VolumeItem},
+        ui::draw_blocks::tests::{TuiTestSetup, get_result, test_setup},
+    };
+
+    /// Insert two volumes, one named and in use, and one anonymous and unused
+    fn insert_volumes(setup: &TuiTestSetup) {
+        let host = ContainerHost::default();
+        let anonymous = "0123456789abcdef".repeat(4);
+        let volumes = [
+            (
+                "postgres_data",
+                vec!["postgres".to_owned()],
+                Some(ByteStats::new(1_000_000)),
+            ),
+            (anonymous.as_str(), vec![], None),
+        ]
+        .into_iter()
+        .map(|(name, containers, size)| {
+            VolumeItem::new(
+                Volume {
+                    name: name.to_owned(),
+                    driver: "local".to_owned(),
+                    mountpoint: format!("/var/lib/docker/volumes/{}/_data", &name[..8]),
+                    ..Default::default()
+                },
+                containers,
+                size,
+                &host,
+            )
+        })
+        .collect();
+        setup.app_data.lock().update_volumes(&host, volumes);
+    }
+
+    #[test]
+    /// Volumes view is drawn with a header, the selected volume is highlighted, and unused volumes are dimmed
+    fn test_draw_blocks_volumes() {
+        let mut setup = test_setup(100, 8, true, true);
+        insert_volumes(&setup);
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if (4..=16).contains(&result_cell_index) {
+                    match row_index {
+                        1 | 2 => {
+                            assert_eq!(result_cell.fg, colors.containers.text);
+                            assert_eq!(result_cell.modifier, Modifier::BOLD);
+                        }
+                        3 => {
+                            assert_eq!(result_cell.fg, colors.containers.text_disconnected);
+                            assert!(!result_cell.modifier.contains(Modifier::BOLD));
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Volumes view without any volumes
+    fn test_draw_blocks_volumes_empty() {
+        let mut setup = test_setup(100, 8, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+        let keymap = setup.app_data.lock().config.keymap.clone();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&setup.app_data, colors, f, &keymap);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
@@ -2,6 +2,7 @@ use parking_lot::Mutex;
 use ratatui::layout::{Constraint, Rect};
 use std::{
     collections::{HashMap, HashSet},
+    path::PathBuf,
     sync::Arc,
     time::Instant,
 };
@@ -9,7 +10,11 @@ use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 use crate::{
-    app_data::{AppData, ContainerId, Header, ScrollDirection},
+    app_data::{
+        AppData, ContainerHost, ContainerId, DockerCommand, Header, ImageItem, NetworkItem,
+        ProcessItem, PrunePreview, ScrollDirection, Signal, StatefulList, VolumeItem,
+    },
+    docker_data::ConnectDiagnostics,
     exec::ExecMode,
 };
 
@@ -59,6 +64,100 @@ pub enum Region {
 pub enum DeleteButton {
     Confirm,
     Cancel,
+    Force,
+    Volumes,
+}
+
+/// Options used when deleting a container, defaults are set from the config, and can be toggled in the delete popup
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct DeleteOptions {
+    pub force: bool,
+    pub volumes: bool,
+}
+
+/// An action, other than deleting a container, that needs to be confirmed before being sent to Docker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Confirm {
+    Prune(PrunePreview),
+    RemoveImage(ImageItem),
+    RemoveNetwork(NetworkItem),
+    RemoveVolume(VolumeItem),
+}
+
+impl Confirm {
+    /// The title of the confirm popup, and the description of the action, split so that the target can be highlighted
+    pub fn get_text(&self) -> (&'static str, &'static str, String) {
+        match self {
+            Self::Prune(preview) => (
+                " Confirm Prune ",
+                "Are you sure you want to prune ",
+                preview.reclaimable.map_or_else(
+                    || format!("{} {}", preview.items.len(), preview.kind),
+                    |reclaimable| {
+                        format!(
+                            "{} {}, reclaiming {reclaimable}",
+                            preview.items.len(),
+                            preview.kind
+                        )
+                    },
+                ),
+            ),
+            Self::RemoveImage(image) => (
+                " Confirm Remove ",
+                "Are you sure you want to remove image: ",
+                image.get_name().to_owned(),
+            ),
+            Self::RemoveNetwork(network) => (
+                " Confirm Remove ",
+                "Are you sure you want to remove network: ",
+                network.name.clone(),
+            ),
+            Self::RemoveVolume(volume) => (
+                " Confirm Remove ",
+                "Are you sure you want to remove volume: ",
+                volume.get_name().to_owned(),
+            ),
+        }
+    }
+}
+
+/// An action that needs a line of text from the user, before being sent to Docker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PromptAction {
+    CopyFromContainer(ContainerId),
+    CopyToContainer(ContainerId),
+    /// The host path has been entered, now needs the directory in the container to extract it into
+    CopyToContainerDir((ContainerId, PathBuf)),
+    CreateNetwork(ContainerHost),
+}
+
+impl PromptAction {
+    /// The title of the prompt popup, and the label in front of the user input
+    pub const fn get_text(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::CopyFromContainer(_) => (" Copy From Container ", "container path: "),
+            Self::CopyToContainer(_) => (" Copy To Container ", "host path: "),
+            Self::CopyToContainerDir(_) => (" Copy To Container ", "container directory: "),
+            Self::CreateNetwork(_) => (" Create Network ", "name: "),
+        }
+    }
+
+    /// An explanation of how the entered text is used, shown below the user input
+    pub const fn get_hint(&self) -> Option<&'static str> {
+        match self {
+            Self::CopyToContainer(_) => {
+                Some("~ is the home directory, relative paths start from oxker's directory")
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The text entered so far, for a given PromptAction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Prompt {
+    pub action: PromptAction,
+    pub text: String,
 }
 
 #[allow(unused)]
@@ -162,16 +261,28 @@ const FRAMES_LEN: u8 = 9;
 /// Various functions (e.g input handler), operate differently depending upon current Status
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Status {
+    BulkAction,
+    Changes,
+    Confirm,
+    ContextPicker,
     DeleteConfirm,
-    DockerConnect(Option<String>),
+    DiskUsage,
+    DockerConnect(ConnectDiagnostics),
     Error,
     Exec,
     Filter,
     Help,
+    Images,
     Init,
     Inspect,
     Logs,
+    NetworkPicker,
+    Networks,
+    Processes,
+    Prompt,
     SearchLogs,
+    SignalPicker,
+    Volumes,
 }
 
 #[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
@@ -183,20 +294,34 @@ pub struct ScrollOffset {
 /// Global gui_state, stored in an Arc<Mutex>
 #[derive(Debug)]
 pub struct GuiState {
-    delete_container_id: Option<ContainerId>,
+    bulk_commands: StatefulList<DockerCommand>,
+    bulk_containers: Vec<ContainerId>,
+    confirm: Option<Confirm>,
+    confirm_offset: usize,
+    confirm_offset_max: usize,
+    contexts: StatefulList<String>,
+    delete_containers: Vec<ContainerId>,
+    delete_down: bool,
+    delete_options: DeleteOptions,
     exec_mode: Option<ExecMode>,
+    init_hosts: HashSet<ContainerHost>,
     intersect_delete: HashMap<DeleteButton, Rect>,
     intersect_heading: HashMap<Header, Rect>,
     intersect_help: Option<Rect>,
     intersect_panel: HashMap<SelectablePanel, Rect>,
+    kill_containers: Vec<ContainerId>,
+    kill_process: Option<(ContainerId, ProcessItem)>,
     loading_handle: Option<JoinHandle<()>>,
     loading_index: u8,
     loading_set: HashSet<Uuid>,
     log_height: u16,
+    network_containers: StatefulList<ContainerId>,
+    prompt: Option<Prompt>,
     rerender: Arc<Rerender>,
     selected_panel: SelectablePanel,
     screen_width: u16,
     show_logs: bool,
+    signals: StatefulList<Signal>,
     inspect_offset: ScrollOffset,
     inspect_offset_max: ScrollOffset,
     status: HashSet<Status>,
@@ -205,23 +330,37 @@ pub struct GuiState {
 impl GuiState {
     pub fn new(redraw: &Arc<Rerender>, show_logs: bool) -> Self {
         Self {
-            delete_container_id: None,
+            bulk_commands: StatefulList::new(vec![]),
+            bulk_containers: vec![],
+            confirm: None,
+            confirm_offset: 0,
+            confirm_offset_max: 0,
+            contexts: StatefulList::new(vec![]),
+            delete_containers: vec![],
+            delete_down: false,
+            delete_options: DeleteOptions::default(),
             exec_mode: None,
             info_box_text: None,
+            init_hosts: HashSet::new(),
             intersect_delete: HashMap::new(),
             intersect_heading: HashMap::new(),
             intersect_help: None,
             intersect_panel: HashMap::new(),
             inspect_offset: ScrollOffset::default(),
             inspect_offset_max: ScrollOffset::default(),
+            kill_containers: vec![],
+            kill_process: None,
             loading_handle: None,
             loading_index: 0,
             loading_set: HashSet::new(),
             log_height: 75,
+            network_containers: StatefulList::new(vec![]),
+            prompt: None,
             screen_width: 0,
             rerender: Arc::clone(redraw),
             selected_panel: SelectablePanel::default(),
             show_logs,
+            signals: StatefulList::new(vec![]),
             status: HashSet::new(),
         }
     }
@@ -406,24 +545,273 @@ impl GuiState {
         }
     }
 
-    /// Check if an ContainerId is set in the delete_container field
-    pub fn get_delete_container(&self) -> Option<ContainerId> {
-        self.delete_container_id.clone()
+    /// Get the ContainerIds set in the delete_containers field
+    pub fn get_delete_containers(&self) -> Vec<ContainerId> {
+        self.delete_containers.clone()
     }
 
-    /// Set either a ContainerId, or None, to the delete_container field
-    /// If Some, will also insert the DeleteConfirm status into self.status
-    pub fn set_delete_container(&mut self, id: Option<ContainerId>) {
-        if id.is_some() {
+    /// Set the ContainerIds that the delete popup will delete, an empty Vec closes the popup
+    /// If not empty, will also insert the DeleteConfirm status into self.status
+    pub fn set_delete_containers(&mut self, ids: Vec<ContainerId>) {
+        if ids.is_empty() {
+            self.delete_down = false;
+            self.intersect_delete.clear();
+            self.status_del(Status::DeleteConfirm);
+        } else {
             self.status.insert(Status::DeleteConfirm);
+        }
+        self.delete_containers = ids;
+        self.rerender.update_draw();
+    }
+
+    /// Get the options that the containers in the delete_containers field will be deleted with
+    pub const fn get_delete_options(&self) -> DeleteOptions {
+        self.delete_options
+    }
+
+    /// Check if the delete popup was opened by a project Down command, rather than a delete
+    pub const fn is_delete_down(&self) -> bool {
+        self.delete_down
+    }
+
+    /// Set the delete popup to remove the project networks as well as the containers, reset when the popup is closed
+    pub const fn set_delete_down(&mut self) {
+        self.delete_down = true;
+    }
+
+    /// Set the options used by the delete popup, should be called before the delete popup is shown
+    pub const fn set_delete_options(&mut self, options: DeleteOptions) {
+        self.delete_options = options;
+    }
+
+    /// Toggle either the force, or volumes, option in the delete popup
+    pub fn toggle_delete_option(&mut self, button: DeleteButton) {
+        match button {
+            DeleteButton::Force => self.delete_options.force = !self.delete_options.force,
+            DeleteButton::Volumes => self.delete_options.volumes = !self.delete_options.volumes,
+            DeleteButton::Cancel | DeleteButton::Confirm => (),
+        }
+        self.rerender.update_draw();
+    }
+
+    /// Get the ContainerIds set in the kill_containers field
+    pub fn get_kill_containers(&self) -> Vec<ContainerId> {
+        self.kill_containers.clone()
+    }
+
+    /// Set the ContainerIds that the signal picker will send a signal to, an empty Vec closes the picker
+    /// If not empty, will also fill the signals list, with the first signal selected, and insert the SignalPicker status into self.status
+    pub fn set_kill_containers(&mut self, ids: Vec<ContainerId>) {
+        if ids.is_empty() {
+            self.status_del(Status::SignalPicker);
+        } else {
+            self.signals = StatefulList::new(Signal::ALL.to_vec());
+            self.signals.start();
+            self.kill_containers = ids;
+            self.status.insert(Status::SignalPicker);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Get the process, and the container it is running in, set in the kill_process field
+    pub fn get_kill_process(&self) -> Option<(ContainerId, ProcessItem)> {
+        self.kill_process.clone()
+    }
+
+    /// Set the process that the signal picker will send a signal to, None closes the picker
+    /// If Some, will also fill the signals list, with the first signal selected, and insert the SignalPicker status into self.status
+    pub fn set_kill_process(&mut self, process: Option<(ContainerId, ProcessItem)>) {
+        if process.is_none() {
+            self.status_del(Status::SignalPicker);
+        } else {
+            self.signals = StatefulList::new(Signal::ALL.to_vec());
+            self.signals.start();
+            self.kill_process = process;
+            self.status.insert(Status::SignalPicker);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Return a copy of the signals list, used by the signal picker
+    pub fn get_signals(&self) -> StatefulList<Signal> {
+        self.signals.clone()
+    }
+
+    /// Get the currently selected signal in the signal picker
+    pub fn get_selected_signal(&self) -> Option<Signal> {
+        self.signals
+            .state
+            .selected()
+            .and_then(|i| self.signals.items.get(i).copied())
+    }
+
+    /// Change the selected signal in the signal picker
+    pub fn signals_scroll(&mut self, scroll: &ScrollDirection) {
+        self.signals.scroll(scroll);
+        self.rerender.update_draw();
+    }
+
+    /// Set the containers that can be connected to, or disconnected from, the selected network, an empty Vec closes the picker
+    /// If not empty, the first container is selected, and the NetworkPicker status is inserted into self.status
+    pub fn set_network_containers(&mut self, ids: Vec<ContainerId>) {
+        if ids.is_empty() {
+            self.status_del(Status::NetworkPicker);
+        } else {
+            self.network_containers = StatefulList::new(ids);
+            self.network_containers.start();
+            self.status.insert(Status::NetworkPicker);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Return a copy of the network containers list, used by the network container picker
+    pub fn get_network_containers(&self) -> StatefulList<ContainerId> {
+        self.network_containers.clone()
+    }
+
+    /// Get the currently selected container in the network container picker
+    pub fn get_selected_network_container(&self) -> Option<ContainerId> {
+        self.network_containers
+            .state
+            .selected()
+            .and_then(|i| self.network_containers.items.get(i).cloned())
+    }
+
+    /// Change the selected container in the network container picker
+    pub fn network_containers_scroll(&mut self, scroll: &ScrollDirection) {
+        self.network_containers.scroll(scroll);
+        self.rerender.update_draw();
+    }
+
+    /// Get the ContainerIds set in the bulk_containers field
+    pub fn get_bulk_containers(&self) -> Vec<ContainerId> {
+        self.bulk_containers.clone()
+    }
+
+    /// Set the ContainerIds that a bulk action will be applied to, an empty Vec closes the bulk action popup
+    /// If not empty, will also fill the bulk commands list, with the first command selected, and insert the BulkAction status into self.status
+    pub fn set_bulk_containers(&mut self, ids: Vec<ContainerId>) {
+        if ids.is_empty() {
+            self.status_del(Status::BulkAction);
+        } else {
+            self.bulk_commands = StatefulList::new(DockerCommand::BULK.to_vec());
+            self.bulk_commands.start();
+            self.bulk_containers = ids;
+            self.status.insert(Status::BulkAction);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Return a copy of the bulk commands list, used by the bulk action popup
+    pub fn get_bulk_commands(&self) -> StatefulList<DockerCommand> {
+        self.bulk_commands.clone()
+    }
+
+    /// Get the currently selected command in the bulk action popup
+    pub fn get_selected_bulk_command(&self) -> Option<DockerCommand> {
+        self.bulk_commands
+            .state
+            .selected()
+            .and_then(|i| self.bulk_commands.items.get(i).copied())
+    }
+
+    /// Change the selected command in the bulk action popup
+    pub fn bulk_commands_scroll(&mut self, scroll: &ScrollDirection) {
+        self.bulk_commands.scroll(scroll);
+        self.rerender.update_draw();
+    }
+
+    /// Get the action that is waiting to be confirmed
+    pub fn get_confirm(&self) -> Option<Confirm> {
+        self.confirm.clone()
+    }
+
+    /// Set the action that the confirm popup will ask to confirm, None closes the popup
+    pub fn set_confirm(&mut self, confirm: Option<Confirm>) {
+        if confirm.is_some() {
+            self.status.insert(Status::Confirm);
         } else {
             self.intersect_delete.clear();
-            self.status_del(Status::DeleteConfirm);
+            self.status.remove(&Status::Confirm);
+        }
+        self.confirm = confirm;
+        self.confirm_offset = 0;
+        self.confirm_offset_max = 0;
+        self.rerender.update_draw();
+    }
+
+    /// Scroll the list of items shown in the confirm popup, limited to the max offset set when drawn
+    pub fn set_confirm_offset(&mut self, sd: &ScrollDirection) {
+        match sd {
+            ScrollDirection::Up => self.confirm_offset = self.confirm_offset.saturating_sub(1),
+            ScrollDirection::Down => {
+                self.confirm_offset = self
+                    .confirm_offset
+                    .saturating_add(1)
+                    .min(self.confirm_offset_max);
+            }
+            ScrollDirection::Left | ScrollDirection::Right => return,
         }
-        self.delete_container_id = id;
         self.rerender.update_draw();
     }
 
+    pub const fn get_confirm_offset(&self) -> usize {
+        self.confirm_offset
+    }
+
+    pub const fn set_confirm_offset_max(&mut self, offset: usize) {
+        self.confirm_offset_max = offset;
+    }
+
+    /// Return a copy of the current prompt, and the text entered so far
+    pub fn get_prompt(&self) -> Option<Prompt> {
+        self.prompt.clone()
+    }
+
+    /// Open the prompt popup, with empty text, for a given action, None closes the popup
+    pub fn set_prompt(&mut self, action: Option<PromptAction>) {
+        if action.is_some() {
+            self.status.insert(Status::Prompt);
+        } else {
+            self.status.remove(&Status::Prompt);
+        }
+        self.prompt = action.map(|action| Prompt {
+            action,
+            text: String::new(),
+        });
+        self.rerender.update_draw();
+    }
+
+    /// Append a char to the text of the prompt
+    pub fn prompt_push(&mut self, c: char) {
+        if let Some(prompt) = self.prompt.as_mut() {
+            prompt.text.push(c);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Remove the last char from the text of the prompt
+    pub fn prompt_pop(&mut self) {
+        if let Some(prompt) = self.prompt.as_mut() {
+            prompt.text.pop();
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Mark a host as loading its initial data, sets Status::Init
+    pub fn init_start(&mut self, host: &ContainerHost) {
+        self.init_hosts.insert(host.clone());
+        self.status_push(Status::Init);
+    }
+
+    /// Mark a host as having loaded its initial data, Status::Init is only removed once every host has reported
+    pub fn init_end(&mut self, host: &ContainerHost) {
+        self.init_hosts.remove(host);
+        if self.init_hosts.is_empty() {
+            self.status_del(Status::Init);
+        }
+    }
+
     /// Return a copy of the Status HashSet
     pub fn get_status(&self) -> HashSet<Status> {
         self.status.clone()
@@ -440,6 +828,28 @@ impl GuiState {
             Status::Exec => {
                 self.exec_mode = None;
             }
+            Status::ContextPicker => {
+                self.contexts = StatefulList::new(vec![]);
+            }
+            Status::BulkAction => {
+                self.bulk_containers = vec![];
+                self.bulk_commands = StatefulList::new(vec![]);
+            }
+            Status::Confirm => {
+                self.confirm = None;
+                self.confirm_offset = 0;
+            }
+            Status::NetworkPicker => {
+                self.network_containers = StatefulList::new(vec![]);
+            }
+            Status::Prompt => {
+                self.prompt = None;
+            }
+            Status::SignalPicker => {
+                self.kill_containers = vec![];
+                self.kill_process = None;
+                self.signals = StatefulList::new(vec![]);
+            }
             _ => (),
         }
         self.rerender.update_draw();
@@ -458,6 +868,36 @@ impl GuiState {
         self.exec_mode.clone()
     }
 
+    /// Set the Docker contexts to pick from, and show the context picker, with the current context selected
+    pub fn set_contexts(&mut self, contexts: Vec<String>, current: Option<&str>) {
+        let index = current
+            .and_then(|current| contexts.iter().position(|i| i == current))
+            .unwrap_or_default();
+        self.contexts = StatefulList::new(contexts);
+        self.contexts.state.select(Some(index));
+        self.status.insert(Status::ContextPicker);
+        self.rerender.update_draw();
+    }
+
+    /// Return a copy of the Docker contexts list, used by the context picker
+    pub fn get_contexts(&self) -> StatefulList<String> {
+        self.contexts.clone()
+    }
+
+    /// Get the name of the currently selected context in the context picker
+    pub fn get_selected_context(&self) -> Option<String> {
+        self.contexts
+            .state
+            .selected()
+            .and_then(|i| self.contexts.items.get(i).cloned())
+    }
+
+    /// Change the selected context in the context picker
+    pub fn contexts_scroll(&mut self, scroll: &ScrollDirection) {
+        self.contexts.scroll(scroll);
+        self.rerender.update_draw();
+    }
+
     /// Insert a gui_status into the current gui_status HashSet
     /// If the status is Exec, it won't get inserted, set_exec_mode() should be used instead
     pub fn status_push(&mut self, status: Status) {
@@ -27,19 +27,26 @@ mod redraw;
 pub use redraw::Rerender;
 
 pub use self::color_match::*;
-pub use self::gui_state::{DeleteButton, GuiState, SelectablePanel, Status};
+pub use self::gui_state::{
+    Confirm, DeleteButton, DeleteOptions, GuiState, Prompt, PromptAction, SelectablePanel, Status,
+};
 use crate::{
     app_data::{
-        AppData, ChartsData, Columns, ContainerId, ContainerPorts, FilterBy, Header, LogSearch,
-        SortedOrder, State,
+        AppData, ChartsData, Columns, ContainerHost, ContainerId, ContainerName, ContainerPorts,
+        DockerCommand, FilterBy, Header, LogSearch, ProcessItem, Signal, SortedOrder, State,
+        StatefulList,
     },
     app_error::AppError,
     config::{AppColors, Keymap},
+    docker_data::ConnectDiagnostics,
     input_handler::InputMessages,
 };
 
 const POLL_RATE: Duration = std::time::Duration::from_millis(50);
 
+/// Seconds to display the connection diagnostics for, before closing
+const ERROR_COUNTDOWN: u8 = 30;
+
 // could have a render struct, which takes in poll rate, and docker
 
 pub struct Ui {
@@ -130,9 +137,9 @@ impl Ui {
         Ok(self.terminal.show_cursor()?)
     }
 
-    /// Draw the the error message ui, for 5 seconds, with a countdown
-    async fn err_loop(&mut self, host: Option<String>) -> Result<(), AppError> {
-        let mut seconds = 5;
+    /// Draw the the connection diagnostics ui, for ERROR_COUNTDOWN seconds, with a countdown
+    async fn err_loop(&mut self, diagnostics: ConnectDiagnostics) -> Result<(), AppError> {
+        let mut seconds = ERROR_COUNTDOWN;
         let colors = self.app_data.lock().config.app_colors;
         let keymap = self.app_data.lock().config.keymap.clone();
         let mut redraw = true;
@@ -154,7 +161,7 @@ impl Ui {
                             colors,
                             &AppError::DockerConnect,
                             f,
-                            host.clone(),
+                            Some(&diagnostics),
                             &keymap,
                             Some(seconds),
                         );
@@ -284,11 +291,11 @@ impl Ui {
     /// Draw either the Error, or main oxker ui, to the terminal
     async fn draw_ui(&mut self) -> Result<(), AppError> {
         let status = self.gui_state.lock().get_status();
-        if let Some(Status::DockerConnect(msg)) = status
+        if let Some(Status::DockerConnect(diagnostics)) = status
             .iter()
             .find(|s| matches!(s, Status::DockerConnect(_)))
         {
-            self.err_loop(msg.clone()).await?;
+            self.err_loop(diagnostics.clone()).await?;
         } else {
             self.gui_loop().await?;
         }
@@ -301,12 +308,18 @@ impl Ui {
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct FrameData {
+    bulk_commands: StatefulList<DockerCommand>,
+    bulk_containers: Vec<ContainerId>,
     chart_data: Option<ChartsData>,
     color_logs: bool,
     columns: Columns,
+    confirm: Option<Confirm>,
     container_title: String,
+    context: Option<String>,
+    contexts: StatefulList<String>,
     log_search: Option<LogSearch>,
-    delete_confirm: Option<ContainerId>,
+    delete_confirm: Vec<ContainerId>,
+    disconnected: Vec<ContainerHost>,
     filter_by: FilterBy,
     filter_term: Option<String>,
     has_containers: bool,
@@ -315,12 +328,17 @@ pub struct FrameData {
     has_error: Option<AppError>,
     info_text: Option<(String, Instant)>,
     is_loading: bool,
+    kill_containers: Vec<ContainerId>,
+    kill_process: Option<(ContainerId, ProcessItem)>,
     loading_icon: String,
     log_title: String,
+    network_containers: StatefulList<ContainerId>,
     port_max_lens: (usize, usize, usize),
     ports: Option<(Vec<ContainerPorts>, State)>,
+    prompt: Option<Prompt>,
     selected_panel: SelectablePanel,
     scroll_title: Option<String>,
+    signals: StatefulList<Signal>,
     sorted_by: Option<(Header, SortedOrder)>,
     status: HashSet<Status>,
 }
@@ -331,11 +349,17 @@ impl From<&Ui> for FrameData {
 
         let (filter_by, filter_term) = app_data.get_filter();
         Self {
+            bulk_commands: gui_data.get_bulk_commands(),
+            bulk_containers: gui_data.get_bulk_containers(),
             chart_data: app_data.get_chart_data(),
             color_logs: app_data.config.color_logs,
             columns: app_data.get_width(),
+            confirm: gui_data.get_confirm(),
             container_title: app_data.get_container_title(),
-            delete_confirm: gui_data.get_delete_container(),
+            context: app_data.config.context.clone(),
+            contexts: gui_data.get_contexts(),
+            delete_confirm: gui_data.get_delete_containers(),
+            disconnected: app_data.get_disconnected(),
             filter_by,
             filter_term: filter_term.cloned(),
             has_containers: app_data.get_container_len() > 0,
@@ -343,20 +367,33 @@ impl From<&Ui> for FrameData {
             has_error: app_data.get_error(),
             info_text: gui_data.info_box_text.clone(),
             is_loading: gui_data.is_loading(),
+            kill_containers: gui_data.get_kill_containers(),
+            kill_process: gui_data.get_kill_process(),
             show_logs: gui_data.get_show_logs(),
             loading_icon: gui_data.get_loading().to_string(),
             log_height: gui_data.get_log_height(),
             log_title: app_data.get_log_title(),
+            network_containers: gui_data.get_network_containers(),
             port_max_lens: app_data.get_longest_port(),
             ports: app_data.get_selected_ports(),
+            prompt: gui_data.get_prompt(),
             scroll_title: app_data.get_scroll_title(gui_data.get_screen_width()),
             selected_panel: gui_data.get_selected_panel(),
+            signals: gui_data.get_signals(),
             sorted_by: app_data.get_sorted(),
             status: gui_data.get_status(),
         }
     }
 }
 
+/// Get the names of the given containers, any containers that no longer exist are skipped
+fn get_names(app_data: &Arc<Mutex<AppData>>, ids: &[ContainerId]) -> Vec<ContainerName> {
+    let mut app_data = app_data.lock();
+    ids.iter()
+        .filter_map(|id| app_data.get_container_name_by_id(id).cloned())
+        .collect()
+}
+
 /// Draw the main ui to a frame of the terminal
 fn draw_frame(
     app_data: &Arc<Mutex<AppData>>,
@@ -372,8 +409,23 @@ fn draw_frame(
     let contains_inspect = fd.status.contains(&Status::Inspect);
 
     let inspect_data = app_data.lock().get_inspect_data();
+    let changes_data = app_data.lock().get_changes_data();
     if contains_inspect && let Some(inspect_data) = inspect_data {
         draw_blocks::inspect::draw(f, colors, inspect_data, gui_state, keymap);
+    } else if fd.status.contains(&Status::Changes)
+        && let Some(changes_data) = changes_data
+    {
+        draw_blocks::changes::draw(f, colors, &changes_data, gui_state, keymap);
+    } else if fd.status.contains(&Status::DiskUsage) {
+        draw_blocks::disk_usage::draw(app_data, colors, f, keymap);
+    } else if fd.status.contains(&Status::Images) {
+        draw_blocks::images::draw(app_data, colors, f, keymap);
+    } else if fd.status.contains(&Status::Networks) {
+        draw_blocks::networks::draw(app_data, colors, f, keymap);
+    } else if fd.status.contains(&Status::Processes) {
+        draw_blocks::processes::draw(app_data, colors, f, keymap);
+    } else if fd.status.contains(&Status::Volumes) {
+        draw_blocks::volumes::draw(app_data, colors, f, keymap);
     } else {
         let whole_layout = Layout::default()
             .direction(Direction::Vertical)
@@ -435,17 +487,15 @@ fn draw_frame(
             );
         }
 
-        if let Some(id) = fd.delete_confirm.as_ref() {
-            app_data.lock().get_container_name_by_id(id).map_or_else(
-                || {
-                    // If a container is deleted outside of oxker but whilst the Delete Confirm dialog is open, it can get caught in kind of a dead lock situation
-                    // so if in that unique situation, just clear the delete_container id
-                    gui_state.lock().set_delete_container(None);
-                },
-                |name| {
-                    draw_blocks::delete_confirm::draw(colors, f, gui_state, keymap, name);
-                },
-            );
+        if !fd.delete_confirm.is_empty() {
+            let names = get_names(app_data, &fd.delete_confirm);
+            if names.is_empty() {
+                // If a container is deleted outside of oxker but whilst the Delete Confirm dialog is open, it can get caught in kind of a dead lock situation
+                // so if in that unique situation, just clear the delete_containers ids
+                gui_state.lock().set_delete_containers(vec![]);
+            } else {
+                draw_blocks::delete_confirm::draw(colors, f, gui_state, keymap, &names);
+            }
         }
 
         // only draw commands + charts if there are containers
@@ -462,13 +512,26 @@ fn draw_frame(
                 .constraints([Constraint::Min(1), Constraint::Max(ports_len)])
                 .split(upper_main[1]);
 
+            // The disk chart, as with the disk columns, is only shown when enabled in the config
+            let show_disk_io = fd.columns.disk_read.is_some();
             let charts_rect = Layout::default()
                 .direction(Direction::Horizontal)
-                .constraints([Constraint::Percentage(66), Constraint::Percentage(33)])
+                .constraints(if show_disk_io {
+                    vec![
+                        Constraint::Percentage(50),
+                        Constraint::Percentage(25),
+                        Constraint::Percentage(25),
+                    ]
+                } else {
+                    vec![Constraint::Percentage(66), Constraint::Percentage(33)]
+                })
                 .split(lower[0]);
 
             draw_blocks::chart_cpu_mem::draw(charts_rect[0], colors, f, fd);
             draw_blocks::chart_bandwidth::draw(charts_rect[1], colors, f, fd);
+            if show_disk_io {
+                draw_blocks::chart_disk::draw(charts_rect[2], colors, f, fd);
+            }
 
             draw_blocks::ports::draw(lower[1], colors, f, fd);
         }
@@ -478,6 +541,63 @@ fn draw_frame(
             let config = app_data.lock().config.clone();
             draw_blocks::help::draw(&config, f);
         }
+
+        if fd.status.contains(&Status::ContextPicker) {
+            draw_blocks::context::draw(colors, f, fd);
+        }
+
+        if !fd.kill_containers.is_empty() {
+            let names = get_names(app_data, &fd.kill_containers);
+            if names.is_empty() {
+                // Same as the Delete Confirm dialog, if the containers have been removed whilst the signal picker is open, just close it
+                gui_state.lock().set_kill_containers(vec![]);
+            } else {
+                draw_blocks::signal::draw(colors, f, fd, &names);
+            }
+        }
+
+        if !fd.bulk_containers.is_empty() {
+            let names = get_names(app_data, &fd.bulk_containers);
+            if names.is_empty() {
+                gui_state.lock().set_bulk_containers(vec![]);
+            } else {
+                draw_blocks::bulk::draw(colors, f, fd, &names);
+            }
+        }
+    }
+
+    // The process signal picker is drawn above the processes view, rather than the main layout
+    if let Some((id, _)) = fd.kill_process.as_ref() {
+        let names = get_names(app_data, std::slice::from_ref(id));
+        if names.is_empty() {
+            gui_state.lock().set_kill_process(None);
+        } else {
+            draw_blocks::signal::draw(colors, f, fd, &names);
+        }
+    }
+
+    // The network container picker is drawn above the networks view, and closed if the selected network no longer exists
+    if fd.status.contains(&Status::NetworkPicker) {
+        let network = app_data
+            .lock()
+            .get_selected_network()
+            .map(|i| i.name.clone());
+        if let Some(network) = network {
+            let items = app_data
+                .lock()
+                .get_network_picker_items(&fd.network_containers.items);
+            draw_blocks::network_picker::draw(colors, f, fd, &network, &items);
+        } else {
+            gui_state.lock().set_network_containers(vec![]);
+        }
+    }
+
+    if !contains_inspect && let Some(confirm) = fd.confirm.as_ref() {
+        draw_blocks::confirm::draw(colors, confirm, f, gui_state, keymap);
+    }
+
+    if let Some(prompt) = fd.prompt.as_ref() {
+        draw_blocks::prompt::draw(colors, f, prompt);
     }
 
     if let Some((text, instant)) = fd.info_text.as_ref() {